@@ -0,0 +1,81 @@
+/// How far in front of the player a melee swing reaches
+pub const MELEE_RANGE: f32 = 4.0;
+
+/// Minimum dot product between the player's facing and the target direction
+pub const MELEE_ARC_DOT: f32 = 0.3;
+
+/// Seconds between two melee swings
+pub const MELEE_COOLDOWN: f32 = 0.6;
+
+/// Damage multiplier of the heavy (Shift) fight move
+pub const HEAVY_ATTACK_MULTIPLIER: f32 = 1.5;
+
+/// Health of a standard enemy
+pub const ENEMY_BASE_HEALTH: u32 = 150;
+
+// ===== TURN-BASED BOSS FIGHT =====
+
+/// Base seed of every turn-based battle; mixed with the offering so a run replays exactly
+pub const BATTLE_SEED: u64 = 0x0E15_B055_F167;
+
+/// Damage of the realm's boss before variance
+pub const BOSS_BASE_DAMAGE: f32 = 9.0;
+
+/// Hits deal between `1 - DAMAGE_VARIANCE` and `1 + DAMAGE_VARIANCE` of their base damage
+pub const DAMAGE_VARIANCE: f32 = 0.1;
+
+/// Chance for a hit to be critical, and how much harder critical hits land
+pub const CRIT_CHANCE: f64 = 0.1;
+pub const CRIT_MULTIPLIER: f32 = 1.5;
+
+/// Fraction of incoming damage a defending combatant still takes
+pub const DEFEND_DAMAGE_TAKEN: f32 = 0.5;
+
+/// Damage multiplier of the player's skill and the turns before it can be used again
+pub const SKILL_MULTIPLIER: f32 = 2.2;
+pub const SKILL_COOLDOWN_TURNS: u32 = 3;
+
+/// Below this fraction of its health the boss switches to its enraged pattern
+pub const BOSS_ENRAGE_THRESHOLD: f32 = 0.5;
+
+/// Damage multiplier of the boss while enraged
+pub const BOSS_ENRAGE_MULTIPLIER: f32 = 1.3;
+
+/// Seconds the boss "thinks" before acting, so each turn can be read
+pub const BOSS_TURN_DELAY: f32 = 0.8;
+
+// ===== ENEMY ATTACKS =====
+
+/// Damage of a standard enemy's melee hit before wave difficulty scaling
+pub const ENEMY_ATTACK_DAMAGE: u32 = 6;
+
+/// Seconds between two melee hits of the same enemy
+pub const ENEMY_ATTACK_COOLDOWN: f32 = 1.2;
+
+/// Chargers rush the last stretch of this length towards the player, this much faster
+pub const CHARGE_DISTANCE: f32 = 8.0;
+pub const CHARGE_SPEED_MULTIPLIER: f32 = 2.5;
+
+/// Ranged enemies back off once the player is closer than this fraction of their attack range
+pub const RANGED_RETREAT_FRACTION: f32 = 0.5;
+
+// ===== PROJECTILES =====
+
+/// Speed, radius and lifetime of enemy projectiles
+pub const PROJECTILE_SPEED: f32 = 18.0;
+pub const PROJECTILE_RADIUS: f32 = 0.25;
+pub const PROJECTILE_LIFETIME: f32 = 3.0;
+
+/// Height above an enemy's origin that projectiles are fired from
+pub const PROJECTILE_MUZZLE_HEIGHT: f32 = 3.0;
+
+/// Height above the player's feet that ranged enemies aim at, about the chest
+pub const PROJECTILE_AIM_HEIGHT: f32 = 1.8;
+
+// ===== BOSS ENCOUNTERS =====
+
+/// Distance from the boss at which the adds of a new phase appear
+pub const BOSS_ADD_SPAWN_RADIUS: f32 = 6.0;
+
+/// Telegraphs are drawn this far above the player's feet so the ground does not hide them
+pub const TELEGRAPH_GROUND_OFFSET: f32 = 0.1;
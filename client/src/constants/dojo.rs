@@ -61,3 +61,9 @@ impl Default for DojoConfig {
 pub const CREATE_GAME_SELECTOR: Felt = selector!("create_game");
 #[allow(dead_code)]
 pub const PICKUP_ITEM_SELECTOR: Felt = selector!("pickup_item");
+#[allow(dead_code)]
+pub const USE_ITEM_SELECTOR: Felt = selector!("use_item");
+#[allow(dead_code)]
+pub const SACRIFICE_ITEMS_SELECTOR: Felt = selector!("sacrifice_items");
+#[allow(dead_code)]
+pub const END_GAME_SELECTOR: Felt = selector!("end_game");
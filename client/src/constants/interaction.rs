@@ -0,0 +1,5 @@
+/// Where the realm book sits in the gameplay world
+pub const BOOK_POSITION: [f32; 3] = [90.0, 22.0, -54.0];
+
+/// How close the player must be to use the book
+pub const BOOK_INTERACT_RADIUS: f32 = 5.0;
@@ -0,0 +1,5 @@
+/// Number of stack slots in the player inventory (matches the hotbar UI)
+pub const INVENTORY_SLOTS: usize = 6;
+
+/// Maximum number of items of one type that fit in a single stack
+pub const MAX_STACK_SIZE: u32 = 99;
@@ -0,0 +1,14 @@
+/// Health restored by drinking a health potion
+pub const HEALTH_POTION_HEAL: u32 = 35;
+
+/// Seconds before another health potion can be drunk
+pub const HEALTH_POTION_COOLDOWN: f32 = 2.0;
+
+/// Seconds before another survival kit can be opened
+pub const SURVIVAL_KIT_COOLDOWN: f32 = 20.0;
+
+/// Health regenerated per second while a survival kit's buff is active
+pub const SURVIVAL_KIT_REGEN_PER_SECOND: f32 = 4.0;
+
+/// Duration of a survival kit's regeneration buff in seconds
+pub const SURVIVAL_KIT_BUFF_DURATION: f32 = 15.0;
@@ -0,0 +1,22 @@
+/// Seed for loot rolls; a new run always starts from the same sequence
+pub const LOOT_SEED: u64 = 0x000E_1751_0DE5;
+
+/// Names of the built-in loot tables in `LootTables`
+pub const ENEMY_LOOT_TABLE: &str = "enemy";
+pub const CHEST_LOOT_TABLE: &str = "chest";
+
+/// Extra weight per level above 1, multiplied by how many tiers above Common an entry is
+pub const RARITY_WEIGHT_PER_LEVEL: f32 = 0.15;
+
+/// Radius around the drop origin in which rolled items are scattered
+pub const LOOT_SCATTER_RADIUS: f32 = 1.5;
+
+/// How close the player must be to open a chest
+pub const CHEST_INTERACT_RADIUS: f32 = 3.5;
+
+/// Where loot chests are placed in the gameplay world
+pub const CHEST_POSITIONS: [[f32; 3]; 3] = [
+    [8.0, -1.0, 6.0],
+    [-12.0, -1.0, 10.0],
+    [20.0, -1.0, -15.0],
+];
@@ -1,4 +1,17 @@
 pub mod collectibles;
+pub mod combat;
 pub mod dojo;
+pub mod interaction;
+pub mod inventory;
+pub mod items;
+pub mod loot;
 pub mod movement;
+pub mod objectives;
+pub mod perception;
+pub mod player;
+pub mod sacrifice;
+pub mod save;
+pub mod settings;
+pub mod steering;
+pub mod waves;
 pub mod boundary;
@@ -0,0 +1,2 @@
+/// Distance from a location's centre at which the player counts as having reached it
+pub const LOCATION_TRIGGER_RADIUS: f32 = 8.0;
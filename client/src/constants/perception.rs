@@ -0,0 +1,30 @@
+/// How far enemies can see the player
+pub const SIGHT_RANGE: f32 = 28.0;
+
+/// Half the angle of an enemy's vision cone, in degrees
+pub const VIEW_HALF_ANGLE_DEGREES: f32 = 60.0;
+
+/// Enemies sense a player this close even behind them, if nothing is in between
+pub const PROXIMITY_SENSE_RANGE: f32 = 4.0;
+
+/// Height above an enemy's origin its eyes are at, and above the player's feet they look for
+pub const ENEMY_EYE_HEIGHT: f32 = 3.0;
+pub const PLAYER_VISIBLE_HEIGHT: f32 = 1.8;
+
+/// Seconds an enemy keeps hunting the last known position after losing the player
+pub const PERCEPTION_MEMORY: f32 = 8.0;
+
+/// An enemy this close to the last known position gives up the search
+pub const LAST_KNOWN_REACHED_DISTANCE: f32 = 1.5;
+
+// ===== NOISE =====
+
+/// Distance the player's footsteps carry while walking and while running
+pub const WALKING_NOISE_RADIUS: f32 = 6.0;
+pub const RUNNING_NOISE_RADIUS: f32 = 16.0;
+
+/// Seconds between two footstep noises
+pub const FOOTSTEP_NOISE_INTERVAL: f32 = 0.5;
+
+/// Distance the sound of a hit carries
+pub const COMBAT_NOISE_RADIUS: f32 = 20.0;
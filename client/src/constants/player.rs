@@ -0,0 +1,34 @@
+/// Starting health of a new character (matches `create_game` on chain)
+pub const BASE_MAX_HEALTH: u32 = 100;
+
+/// Melee damage dealt with bare hands
+pub const BASE_DAMAGE: f32 = 10.0;
+
+/// Experience needed per level (contract: every 100 exp is a level up)
+pub const XP_PER_LEVEL: u32 = 100;
+
+/// Max health gained on each level up
+pub const LEVEL_UP_MAX_HEALTH: u32 = 10;
+
+/// Experience granted per picked-up item
+pub const XP_PER_PICKUP: u32 = 10;
+
+/// Experience granted per defeated enemy
+pub const XP_PER_KILL: u32 = 50;
+
+/// Seconds the death sequence plays before the run summary appears
+pub const DEATH_SEQUENCE_DURATION: f32 = 3.0;
+
+/// Seconds the player's body takes to fall over
+pub const DEATH_FALL_DURATION: f32 = 0.8;
+
+/// The death camera circles the body this high above it, this far out and this fast (rad/s)
+pub const DEATH_CAMERA_HEIGHT: f32 = 10.0;
+pub const DEATH_CAMERA_DISTANCE: f32 = 8.0;
+pub const DEATH_CAMERA_ORBIT_SPEED: f32 = 0.25;
+
+/// Enemies this close to the checkpoint withdraw when the player respawns there
+pub const RESPAWN_SAFE_RADIUS: f32 = 15.0;
+
+/// A run scores one point per coin plus this much per kill
+pub const SCORE_PER_KILL: u32 = 10;
@@ -0,0 +1,8 @@
+/// Offering value the book demands before it opens the realm
+pub const SACRIFICE_REQUIRED_VALUE: u32 = 10;
+
+/// Extra enemy health in the realm per point of offering value above the cost
+pub const SACRIFICE_HEALTH_PER_VALUE: f32 = 0.04;
+
+/// Offering value above the cost that raises the realm's loot level by one
+pub const SACRIFICE_VALUE_PER_LOOT_LEVEL: u32 = 10;
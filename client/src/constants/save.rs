@@ -0,0 +1,11 @@
+/// Current version of the save format. Older saves are migrated up to it on load.
+pub const SAVE_VERSION: u32 = 1;
+
+/// Number of save slots offered in the load menu
+pub const SAVE_SLOTS: usize = 3;
+
+/// Directory under the platform's data directory that holds the game's files
+pub const GAME_DATA_DIR: &str = "elysium_descent";
+
+/// Subdirectory of the game's data directory that holds the save slots
+pub const SAVES_DIR: &str = "saves";
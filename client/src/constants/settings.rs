@@ -0,0 +1,32 @@
+/// Name of the settings file in the platform's config directory
+pub const SETTINGS_FILE: &str = "settings.json";
+
+/// Smallest window size the saved settings may ask for
+pub const MIN_WINDOW_WIDTH: u32 = 640;
+pub const MIN_WINDOW_HEIGHT: u32 = 360;
+
+/// Lowest fraction of the window's resolution the 3D scene may render at
+pub const MIN_RENDER_SCALE: f32 = 0.5;
+/// Change in render scale per click in the Graphics tab
+pub const RENDER_SCALE_STEP: f32 = 0.1;
+
+/// Brightest ambient light the override may ask for
+pub const MAX_AMBIENT_BRIGHTNESS: f32 = 1.0;
+/// Change in ambient brightness per click in the Graphics tab
+pub const AMBIENT_BRIGHTNESS_STEP: f32 = 0.1;
+
+/// Resolutions offered when the monitor does not report its video modes
+pub const FALLBACK_RESOLUTIONS: &[(u32, u32)] = &[
+    (1280, 720),
+    (1366, 768),
+    (1600, 900),
+    (1920, 1080),
+    (2560, 1440),
+    (3840, 2160),
+];
+
+/// Bounds of the UI scale override in the Window tab
+pub const MIN_UI_SCALE: f32 = 0.5;
+pub const MAX_UI_SCALE: f32 = 2.0;
+/// Change in UI scale per click in the Window tab
+pub const UI_SCALE_STEP: f32 = 0.1;
@@ -0,0 +1,23 @@
+/// Side of a cell of the crowd grid used to find nearby enemies
+pub const CROWD_CELL_SIZE: f32 = 6.0;
+
+/// Gap enemies try to keep between their colliders
+pub const SEPARATION_MARGIN: f32 = 0.5;
+
+/// Strength of the push apart, as a fraction of an enemy's move speed
+pub const SEPARATION_WEIGHT: f32 = 1.5;
+
+/// How far ahead, and how high above their origin, enemies look for walls
+pub const AVOIDANCE_LOOKAHEAD: f32 = 4.0;
+pub const AVOIDANCE_HEIGHT: f32 = 2.0;
+
+// ===== ATTACK SLOTS =====
+
+/// Attack slots sit at this fraction of an enemy's attack range from the player
+pub const SLOT_RANGE_FRACTION: f32 = 0.8;
+
+/// Rings of slots around the player; enemies without a slot on the first wait on the outer ones
+pub const MAX_SLOT_RINGS: usize = 3;
+
+/// An enemy this close to its slot stops moving
+pub const SLOT_ARRIVAL_DISTANCE: f32 = 0.5;
@@ -0,0 +1,23 @@
+/// Seconds the player must survive the waves to clear the level
+pub const SURVIVAL_DURATION: f32 = 300.0;
+
+/// Grace period before the first wave arrives
+pub const FIRST_WAVE_DELAY: f32 = 8.0;
+
+/// Pause between the last spawn of a wave and the first spawn of the next one
+pub const WAVE_BREAK: f32 = 12.0;
+
+/// Extra enemy health, damage and spawn rate per minute survived
+pub const DIFFICULTY_PER_MINUTE: f32 = 0.2;
+
+/// Most wave enemies alive at once; further spawns wait until one falls
+pub const MAX_ALIVE_WAVE_ENEMIES: usize = 12;
+
+/// Nav points this much closer or further than a wave's spawn radius still count as on the ring
+pub const SPAWN_RING_TOLERANCE: f32 = 0.25;
+
+/// Random points tried on the spawn ring when no nav point fits
+pub const SPAWN_ATTEMPTS: usize = 16;
+
+/// Seed of the spawn point rolls; a new run always starts from the same sequence
+pub const WAVE_SEED: u64 = 0x0000_3A7E_5EED;
@@ -1,20 +1,31 @@
 use bevy::prelude::*;
-use bevy::window::{MonitorSelection, WindowMode};
 use bevy_enhanced_input::prelude::*;
 
+use crate::resources::controls::{ControlAction, ControlSettings};
+use crate::resources::settings::SettingsAppExt;
+use crate::resources::window::{WindowModeSetting, WindowSettings};
 use crate::screens::Screen;
+use crate::ui::focus::{FocusRequestEvent, Focusable};
 
 pub fn plugin(app: &mut App) {
-    app.add_systems(Startup, spawn_system_action)
+    app.add_persistent_settings::<ControlSettings>()
+        .add_systems(Startup, spawn_system_action)
+        .init_resource::<SprintHeld>()
+        .add_systems(Update, (rebuild_bindings, sync_menu_actions))
         .add_plugins(EnhancedInputPlugin)
         .add_input_context::<Player>()
         .add_input_context::<SystemInput>()
         .add_input_context::<DojoInput>()
+        .add_input_context::<MenuInput>()
         .add_observer(handle_toggle_fullscreen)
+        .add_observer(handle_toggle_mute)
         .add_observer(handle_return_to_menu)
         .add_observer(player_binding)
         .add_observer(global_binding)
         .add_observer(dojo_binding)
+        .add_observer(menu_binding)
+        .add_observer(navigate_focus)
+        .add_observer(confirm_focus)
         .add_observer(apply_movement)
         .add_observer(jump)
         .add_observer(sprint_started)
@@ -22,7 +33,8 @@ pub fn plugin(app: &mut App) {
         .add_observer(handle_create_game)
         .add_observer(handle_interact)
         .add_observer(handle_fight_move)
-        .add_observer(handle_go_to_fight_scene);
+        .add_observer(handle_use_health_potion)
+        .add_observer(handle_use_survival_kit);
 }
 
 fn spawn_system_action(mut commands: Commands) {
@@ -30,35 +42,35 @@ fn spawn_system_action(mut commands: Commands) {
     commands.spawn(Actions::<DojoInput>::default());
 }
 
-fn player_binding(trigger: Trigger<Binding<Player>>, mut players: Query<&mut Actions<Player>>) {
+fn player_binding(
+    trigger: Trigger<Binding<Player>>,
+    mut players: Query<&mut Actions<Player>>,
+    controls: Res<ControlSettings>,
+) {
     if let Ok(mut actions) = players.get_mut(trigger.target()) {
-        // Movement (WASD, Arrow Keys, Gamepad Left Stick)
-        actions
-            .bind::<Move>()
-            .to((
-                Cardinal::wasd_keys(),
-                Axial::left_stick(),
-                Cardinal::arrow_keys(),
-            ))
-            .with_modifiers(DeadZone::default());
-        // Jump (Spacebar)
-        actions
-            .bind::<Jump>()
-            .to((KeyCode::Space, GamepadButton::South));
-
-        // Sprint (Shift keys)
-        actions
-            .bind::<Sprint>()
-            .to((KeyCode::ShiftLeft, KeyCode::ShiftRight));
-
-        // Interact (E key)
-        actions.bind::<Interact>().to(KeyCode::KeyE);
-
-        // Fight Move (X key, with or without shift)
-        actions.bind::<FightMove>().to(KeyCode::KeyX);
-
-        // Go to Fight Scene (Comma key)
-        actions.bind::<GoToFightScene>().to(KeyCode::Comma);
+        // Movement (bound keys per direction, Gamepad Left Stick)
+        let movement = actions.bind::<Move>();
+        movement.to(Axial::left_stick());
+        for input in bound_inputs(&controls, ControlAction::MoveForward) {
+            movement.to(input.with_modifiers(SwizzleAxis::YXZ));
+        }
+        for input in bound_inputs(&controls, ControlAction::MoveBack) {
+            movement.to(input.with_modifiers((Negate::all(), SwizzleAxis::YXZ)));
+        }
+        for input in bound_inputs(&controls, ControlAction::MoveLeft) {
+            movement.to(input.with_modifiers(Negate::all()));
+        }
+        for input in bound_inputs(&controls, ControlAction::MoveRight) {
+            movement.to(input);
+        }
+        movement.with_modifiers(DeadZone::default());
+
+        bind_action::<_, Jump>(&mut actions, &controls, ControlAction::Jump);
+        bind_action::<_, Sprint>(&mut actions, &controls, ControlAction::Sprint);
+        bind_action::<_, Interact>(&mut actions, &controls, ControlAction::Interact);
+        bind_action::<_, FightMove>(&mut actions, &controls, ControlAction::FightMove);
+        bind_action::<_, UseHealthPotion>(&mut actions, &controls, ControlAction::UseHealthPotion);
+        bind_action::<_, UseSurvivalKit>(&mut actions, &controls, ControlAction::UseSurvivalKit);
     } else {
         error!(
             "Failed to get player actions for entity {:?}",
@@ -70,14 +82,23 @@ fn player_binding(trigger: Trigger<Binding<Player>>, mut players: Query<&mut Act
 fn global_binding(
     trigger: Trigger<Binding<SystemInput>>,
     mut systems: Query<&mut Actions<SystemInput>>,
+    controls: Res<ControlSettings>,
 ) {
     if let Ok(mut actions) = systems.get_mut(trigger.target()) {
-        // Toggle Fullscreen (F11)
-        actions
-            .bind::<ToggleFullScreen>()
-            .to((KeyCode::F11, (KeyCode::AltLeft, KeyCode::Enter)));
-
-        actions.bind::<ReturnToMainMenu>().to(KeyCode::Escape);
+        // Alt+Enter always toggles fullscreen, next to the rebindable inputs.
+        // Enter is reserved in `ControlSettings`, so no action can take it.
+        bind_action::<_, ToggleFullScreen>(
+            &mut actions,
+            &controls,
+            ControlAction::ToggleFullScreen,
+        )
+        .to(KeyCode::Enter.with_mod_keys(ModKeys::ALT));
+        bind_action::<_, ToggleMute>(&mut actions, &controls, ControlAction::ToggleMute);
+        bind_action::<_, ReturnToMainMenu>(
+            &mut actions,
+            &controls,
+            ControlAction::ReturnToMainMenu,
+        );
     } else {
         error!(
             "Failed to get system actions for entity {:?}",
@@ -89,10 +110,10 @@ fn global_binding(
 fn dojo_binding(
     trigger: Trigger<Binding<DojoInput>>,
     mut dojo_actions: Query<&mut Actions<DojoInput>>,
+    controls: Res<ControlSettings>,
 ) {
     if let Ok(mut actions) = dojo_actions.get_mut(trigger.target()) {
-        // Create Game (G key)
-        actions.bind::<CreateGame>().to(KeyCode::KeyG);
+        bind_action::<_, CreateGame>(&mut actions, &controls, ControlAction::CreateGame);
     } else {
         error!(
             "Failed to get dojo actions for entity {:?}",
@@ -101,6 +122,80 @@ fn dojo_binding(
     }
 }
 
+fn menu_binding(
+    trigger: Trigger<Binding<MenuInput>>,
+    mut menu_actions: Query<&mut Actions<MenuInput>>,
+) {
+    if let Ok(mut actions) = menu_actions.get_mut(trigger.target()) {
+        // Confirming takes Enter from the system context, so Alt+Enter is bound here too.
+        // Actions are evaluated in binding order, so the chord is checked before Enter alone.
+        actions
+            .bind::<ToggleFullScreen>()
+            .to(KeyCode::Enter.with_mod_keys(ModKeys::ALT));
+        // Menus always use the arrow keys, D-pad and left stick, whatever the game's bindings
+        actions
+            .bind::<FocusNavigate>()
+            .to((
+                Cardinal::arrow_keys(),
+                Cardinal::dpad_buttons(),
+                Axial::left_stick(),
+            ))
+            .with_modifiers(DeadZone::default());
+        actions
+            .bind::<FocusConfirm>()
+            .to((KeyCode::Enter, GamepadButton::South));
+    } else {
+        error!(
+            "Failed to get menu actions for entity {:?}",
+            trigger.target()
+        );
+    }
+}
+
+/// Binds every key and gamepad button the player chose for `action` to `A`
+fn bind_action<'a, C: InputContext, A: InputAction>(
+    actions: &'a mut Actions<C>,
+    controls: &ControlSettings,
+    action: ControlAction,
+) -> &'a mut ActionBinding {
+    let binding = actions.bind::<A>();
+    for input in bound_inputs(controls, action) {
+        binding.to(input);
+    }
+    binding
+}
+
+fn bound_inputs(controls: &ControlSettings, action: ControlAction) -> impl Iterator<Item = Input> {
+    let inputs = controls.inputs(action);
+    let keys = inputs.keys.into_iter().flatten().map(Input::from);
+    keys.chain(inputs.gamepad.map(Input::from))
+}
+
+/// Menu actions only exist while a focusable menu is on screen. They take their inputs
+/// from the game's contexts, so confirming a menu choice never also jumps or moves.
+fn sync_menu_actions(
+    mut commands: Commands,
+    focusables: Query<(), With<Focusable>>,
+    menu_actions: Query<Entity, With<Actions<MenuInput>>>,
+) {
+    match (focusables.is_empty(), menu_actions.single()) {
+        (false, Err(_)) => {
+            commands.spawn(Actions::<MenuInput>::default());
+        }
+        (true, Ok(entity)) => {
+            commands.entity(entity).despawn();
+        }
+        _ => {}
+    }
+}
+
+/// Re-runs the binding observers so rebinds in the Controls tab apply immediately
+fn rebuild_bindings(controls: Res<ControlSettings>, mut commands: Commands) {
+    if controls.is_changed() && !controls.is_added() {
+        commands.trigger(RebuildBindings);
+    }
+}
+
 // Forward movement and jump events to the character controller
 fn apply_movement(
     trigger: Trigger<Fired<Move>>,
@@ -131,7 +226,9 @@ fn sprint_started(
         &mut crate::systems::character_controller::AnimationState,
         With<crate::systems::character_controller::CharacterController>,
     >,
+    mut sprint_held: ResMut<SprintHeld>,
 ) {
+    sprint_held.0 = true;
     if let Ok(mut animation_state) = animation_query.single_mut() {
         // Set sprint animation
         animation_state.forward_hold_time = 4.0;
@@ -144,13 +241,19 @@ fn sprint_completed(
         &mut crate::systems::character_controller::AnimationState,
         With<crate::systems::character_controller::CharacterController>,
     >,
+    mut sprint_held: ResMut<SprintHeld>,
 ) {
+    sprint_held.0 = false;
     if let Ok(mut animation_state) = animation_query.single_mut() {
         // Reset to normal movement
         animation_state.forward_hold_time = 0.0;
     }
 }
 
+/// Whether the Sprint action is held, which turns the fight move into the heavy one
+#[derive(Resource, Default)]
+struct SprintHeld(bool);
+
 #[derive(InputContext)]
 pub struct Player;
 
@@ -176,7 +279,11 @@ pub struct FightMove;
 
 #[derive(Debug, InputAction)]
 #[input_action(output = bool)]
-pub struct GoToFightScene;
+pub struct UseHealthPotion;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+pub struct UseSurvivalKit;
 
 /// Input context for the Elysium game
 #[derive(InputContext)]
@@ -187,6 +294,11 @@ pub struct SystemInput;
 #[input_action(output = bool)]
 struct ToggleFullScreen;
 
+/// Action for muting and unmuting all audio
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct ToggleMute;
+
 /// Action for returning to the main menu
 #[derive(Debug, InputAction)]
 #[input_action(output = bool)]
@@ -201,40 +313,66 @@ pub struct DojoInput;
 #[input_action(output = bool)]
 struct CreateGame;
 
+/// Input context for moving the focus through menus. Evaluated before the game's
+/// contexts, whose inputs it consumes while a menu is open.
+#[derive(InputContext)]
+#[input_context(priority = 1)]
+pub struct MenuInput;
+
+/// Action for moving the focus to a neighbouring menu element
+#[derive(Debug, InputAction)]
+#[input_action(output = Vec2)]
+struct FocusNavigate;
+
+/// Action for activating the focused menu element
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct FocusConfirm;
+
 fn handle_toggle_fullscreen(
     trigger: Trigger<Started<ToggleFullScreen>>,
-    mut windows: Query<&mut Window>,
+    mut window_settings: ResMut<WindowSettings>,
 ) {
     if trigger.value {
-        if let Ok(mut window) = windows.single_mut() {
-            window.mode = match window.mode {
-                WindowMode::Windowed => {
-                    WindowMode::BorderlessFullscreen(MonitorSelection::Primary)
-                }
-                _ => {
-                    WindowMode::Windowed
-                }
-            };
-        } else {
-            error!("Failed to get window");
-        }
+        // Goes through the settings so the choice is remembered
+        window_settings.mode = match window_settings.mode {
+            WindowModeSetting::Windowed => WindowModeSetting::Borderless,
+            _ => WindowModeSetting::Windowed,
+        };
+    }
+}
+
+fn handle_toggle_mute(
+    trigger: Trigger<Started<ToggleMute>>,
+    mut audio_settings: ResMut<crate::resources::audio::AudioSettings>,
+) {
+    if trigger.value {
+        audio_settings.muted = !audio_settings.muted;
+        info!("Mute toggled: {}", audio_settings.muted);
     }
 }
 
 fn handle_return_to_menu(
     trigger: Trigger<Started<ReturnToMainMenu>>,
+    screen: Res<State<Screen>>,
     mut next_state: ResMut<NextState<Screen>>,
-    modal_state: Option<Res<crate::ui::modal::ModalState>>,
+    modal_state: Option<ResMut<crate::ui::modal::ModalState>>,
 ) {
     if trigger.value {
-        // Check if modal is open - if so, don't return to main menu
-        if let Some(modal_state) = modal_state {
+        // An open modal is closed first, and only the next press leaves the screen
+        if let Some(mut modal_state) = modal_state {
             if modal_state.visible {
-                return; // Modal is open, let the modal handle ESC
+                modal_state.visible = false;
+                return;
             }
         }
-        
-        next_state.set(Screen::MainMenu);
+
+        // The fight scene backs out to the level rather than to the menu
+        if *screen.get() == Screen::FightScene {
+            next_state.set(Screen::GamePlay);
+        } else {
+            next_state.set(Screen::MainMenu);
+        }
     }
 }
 
@@ -249,35 +387,22 @@ fn handle_create_game(
 
 fn handle_interact(
     trigger: Trigger<Started<Interact>>,
-    player_query: Query<&Transform, With<crate::systems::character_controller::CharacterController>>,
-    book_query: Query<&Transform, With<crate::systems::book_interaction::Book>>,
-    mut next_state: ResMut<NextState<Screen>>,
+    mut interact_events: EventWriter<crate::systems::interaction::InteractRequestEvent>,
 ) {
     if trigger.value {
-        // Check if player is near the book
-        if let (Ok(player_transform), Ok(book_transform)) = (player_query.single(), book_query.single()) {
-            let distance = player_transform.translation.distance(book_transform.translation);
-            let proximity_threshold = 5.0;
-
-            if distance <= proximity_threshold {
-                next_state.set(Screen::FightScene);
-                return;
-            }
-        }
-        
-        // Note: Coins are now automatically collected by physical contact/collision
+        // Coins are collected by contact; everything else goes through `Interactable`
+        interact_events.write(crate::systems::interaction::InteractRequestEvent);
     }
 }
 
 fn handle_fight_move(
     trigger: Trigger<Started<FightMove>>,
     mut movement_events: EventWriter<crate::systems::character_controller::MovementAction>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    sprint_held: Res<SprintHeld>,
 ) {
     if trigger.value {
-        let shift_pressed =
-            keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
-        if shift_pressed {
+        // Holding Sprint turns the move into the heavy one
+        if sprint_held.0 {
             movement_events.write(crate::systems::character_controller::MovementAction::FightMove2);
         } else {
             movement_events.write(crate::systems::character_controller::MovementAction::FightMove1);
@@ -285,11 +410,48 @@ fn handle_fight_move(
     }
 }
 
-fn handle_go_to_fight_scene(
-    trigger: Trigger<Started<GoToFightScene>>,
-    mut next_state: ResMut<NextState<Screen>>,
+fn handle_use_health_potion(
+    trigger: Trigger<Started<UseHealthPotion>>,
+    mut use_item_events: EventWriter<crate::systems::items::UseItemEvent>,
+) {
+    if trigger.value {
+        use_item_events.write(crate::systems::items::UseItemEvent {
+            item_type: crate::systems::collectibles::CollectibleType::HealthPotion,
+        });
+    }
+}
+
+fn handle_use_survival_kit(
+    trigger: Trigger<Started<UseSurvivalKit>>,
+    mut use_item_events: EventWriter<crate::systems::items::UseItemEvent>,
+) {
+    if trigger.value {
+        use_item_events.write(crate::systems::items::UseItemEvent {
+            item_type: crate::systems::collectibles::CollectibleType::SurvivalKit,
+        });
+    }
+}
+
+fn navigate_focus(
+    trigger: Trigger<Started<FocusNavigate>>,
+    mut focus_events: EventWriter<FocusRequestEvent>,
+) {
+    let direction = trigger.value;
+    // Only the dominant axis counts, so a diagonal stick moves one way
+    let step = if direction.x.abs() > direction.y.abs() {
+        IVec2::new(direction.x.signum() as i32, 0)
+    } else {
+        // Up on the stick is a step to the row above
+        IVec2::new(0, -direction.y.signum() as i32)
+    };
+    focus_events.write(FocusRequestEvent::Navigate(step));
+}
+
+fn confirm_focus(
+    trigger: Trigger<Started<FocusConfirm>>,
+    mut focus_events: EventWriter<FocusRequestEvent>,
 ) {
     if trigger.value {
-        next_state.set(Screen::FightScene);
+        focus_events.write(FocusRequestEvent::Confirm);
     }
 }
@@ -43,12 +43,17 @@ fn main() -> AppExit {
         .init_resource::<TokioRuntime>()
         // .add_plugins(DojoPlugin) // Temporarily disabled for testing
         .add_plugins(assets::AssetsPlugin)
+        // Saved settings are loaded before the plugins that apply them
+        .add_plugins(resources::settings::SettingsPlugin)
+        .add_plugins(resources::window::WindowSettingsPlugin)
+        .add_plugins(rendering::graphics::GraphicsSettingsPlugin)
         .add_plugins(GameAudioPlugin)
         .add_plugins(SfxPlugin)
         .add_event::<systems::dojo::pickup_item::PickupItemEvent>()
         .add_event::<systems::dojo::pickup_item::ItemPickedUpEvent>()
         .add_event::<systems::dojo::pickup_item::ItemPickupFailedEvent>()
-        .add_plugins((screens::plugin, keybinding::plugin, /* dojo::plugin, */ ui::modal::ModalPlugin))
+        .add_event::<systems::dojo::PlayerStatsUpdatedEvent>()
+        .add_plugins((screens::plugin, keybinding::plugin, /* dojo::plugin, */ ui::modal::ModalPlugin, ui::focus::FocusPlugin))
         .run()
 }
 
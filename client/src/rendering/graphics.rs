@@ -0,0 +1,432 @@
+use bevy::core_pipeline::smaa::{Smaa, SmaaPreset};
+use bevy::core_pipeline::tonemapping::Tonemapping;
+use bevy::pbr::DirectionalLightShadowMap;
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::settings::{
+    AMBIENT_BRIGHTNESS_STEP, MAX_AMBIENT_BRIGHTNESS, MIN_RENDER_SCALE, RENDER_SCALE_STEP,
+};
+use crate::resources::settings::{PersistentSettings, SettingChoice, SettingsAppExt};
+
+// ===== SETTINGS =====
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShadowQuality {
+    Off,
+    Low,
+    Medium,
+    High,
+}
+
+impl ShadowQuality {
+    /// Resolution of the directional light shadow map
+    pub fn map_size(self) -> usize {
+        match self {
+            ShadowQuality::Off | ShadowQuality::Low => 1024,
+            ShadowQuality::Medium => 2048,
+            ShadowQuality::High => 4096,
+        }
+    }
+}
+
+impl SettingChoice for ShadowQuality {
+    const ALL: &'static [Self] = &[
+        ShadowQuality::Off,
+        ShadowQuality::Low,
+        ShadowQuality::Medium,
+        ShadowQuality::High,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            ShadowQuality::Off => "OFF",
+            ShadowQuality::Low => "LOW",
+            ShadowQuality::Medium => "MEDIUM",
+            ShadowQuality::High => "HIGH",
+        }
+    }
+}
+
+/// Sample counts other than 4 are not supported everywhere, WebGL2 included
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AntiAliasing {
+    Off,
+    Msaa,
+    Smaa,
+}
+
+impl SettingChoice for AntiAliasing {
+    const ALL: &'static [Self] = &[AntiAliasing::Off, AntiAliasing::Msaa, AntiAliasing::Smaa];
+
+    fn label(self) -> &'static str {
+        match self {
+            AntiAliasing::Off => "OFF",
+            AntiAliasing::Msaa => "MSAA 4X",
+            AntiAliasing::Smaa => "SMAA",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TonemappingChoice {
+    None,
+    Reinhard,
+    AcesFitted,
+    AgX,
+    TonyMcMapface,
+    BlenderFilmic,
+}
+
+impl TonemappingChoice {
+    pub fn tonemapping(self) -> Tonemapping {
+        match self {
+            TonemappingChoice::None => Tonemapping::None,
+            TonemappingChoice::Reinhard => Tonemapping::Reinhard,
+            TonemappingChoice::AcesFitted => Tonemapping::AcesFitted,
+            TonemappingChoice::AgX => Tonemapping::AgX,
+            TonemappingChoice::TonyMcMapface => Tonemapping::TonyMcMapface,
+            TonemappingChoice::BlenderFilmic => Tonemapping::BlenderFilmic,
+        }
+    }
+}
+
+impl SettingChoice for TonemappingChoice {
+    const ALL: &'static [Self] = &[
+        TonemappingChoice::None,
+        TonemappingChoice::Reinhard,
+        TonemappingChoice::AcesFitted,
+        TonemappingChoice::AgX,
+        TonemappingChoice::TonyMcMapface,
+        TonemappingChoice::BlenderFilmic,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            TonemappingChoice::None => "NONE",
+            TonemappingChoice::Reinhard => "REINHARD",
+            TonemappingChoice::AcesFitted => "ACES",
+            TonemappingChoice::AgX => "AGX",
+            TonemappingChoice::TonyMcMapface => "TONY MCMAPFACE",
+            TonemappingChoice::BlenderFilmic => "FILMIC",
+        }
+    }
+}
+
+/// How the 3D scenes are rendered. Changes apply live to every 3D camera and shadow casting light.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GraphicsSettings {
+    pub shadow_quality: ShadowQuality,
+    pub anti_aliasing: AntiAliasing,
+    pub tonemapping: TonemappingChoice,
+    pub hdr: bool,
+    /// Fraction of the window's resolution the 3D scene renders at
+    pub render_scale: f32,
+    /// Replaces the scenes' own ambient brightness when set
+    pub ambient_brightness: Option<f32>,
+}
+
+impl Default for GraphicsSettings {
+    fn default() -> Self {
+        Self {
+            shadow_quality: ShadowQuality::Medium,
+            anti_aliasing: AntiAliasing::Msaa,
+            tonemapping: TonemappingChoice::TonyMcMapface,
+            hdr: false,
+            render_scale: 1.0,
+            ambient_brightness: None,
+        }
+    }
+}
+
+impl GraphicsSettings {
+    pub fn step_render_scale(&mut self, steps: i32) {
+        let scale = self.render_scale + steps as f32 * RENDER_SCALE_STEP;
+        // Snap to the step so repeated clicks do not accumulate rounding error
+        self.render_scale = ((scale / RENDER_SCALE_STEP).round() * RENDER_SCALE_STEP).clamp(MIN_RENDER_SCALE, 1.0);
+    }
+
+    /// Steps through "use the scene's brightness" followed by the override levels
+    pub fn step_ambient_brightness(&mut self, steps: i32) {
+        let levels = (MAX_AMBIENT_BRIGHTNESS / AMBIENT_BRIGHTNESS_STEP).round() as i32;
+        let current = self
+            .ambient_brightness
+            .map_or(0, |brightness| (brightness / AMBIENT_BRIGHTNESS_STEP).round() as i32 + 1);
+        let next = (current + steps).clamp(0, levels + 1);
+        self.ambient_brightness = (next > 0).then(|| (next - 1) as f32 * AMBIENT_BRIGHTNESS_STEP);
+    }
+
+    pub fn render_scale_label(&self) -> String {
+        format!("{}%", (self.render_scale * 100.0).round() as u32)
+    }
+
+    pub fn ambient_brightness_label(&self) -> String {
+        match self.ambient_brightness {
+            Some(brightness) => format!("{:.1}", brightness),
+            None => "SCENE".to_string(),
+        }
+    }
+}
+
+impl PersistentSettings for GraphicsSettings {
+    const SECTION: &'static str = "graphics";
+
+    fn sanitize(&mut self) {
+        self.render_scale = if self.render_scale.is_finite() {
+            self.render_scale.clamp(MIN_RENDER_SCALE, 1.0)
+        } else {
+            1.0
+        };
+        self.ambient_brightness = self
+            .ambient_brightness
+            .filter(|brightness| brightness.is_finite())
+            .map(|brightness| brightness.clamp(0.0, MAX_AMBIENT_BRIGHTNESS));
+    }
+}
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// The scene's key light, whose shadows follow the shadow quality setting
+#[derive(Component)]
+pub struct ShadowCaster;
+
+/// Offscreen image the 3D cameras render into while the render scale is below 1
+#[derive(Resource)]
+struct ScaledRenderTarget {
+    image: Handle<Image>,
+    size: UVec2,
+}
+
+/// Full-screen UI image that shows the scaled render behind the rest of the UI
+#[derive(Component)]
+struct ScaledRenderOutput;
+
+// ===== PLUGIN =====
+
+pub struct GraphicsSettingsPlugin;
+
+impl Plugin for GraphicsSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_persistent_settings::<GraphicsSettings>().add_systems(
+            Update,
+            (
+                apply_camera_settings,
+                apply_shadow_settings,
+                apply_ambient_brightness,
+                apply_render_scale,
+            ),
+        );
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn apply_camera_settings(
+    mut commands: Commands,
+    settings: Res<GraphicsSettings>,
+    mut cameras: Query<(Entity, &mut Camera, Ref<Camera3d>)>,
+) {
+    for (entity, mut camera, camera_3d) in &mut cameras {
+        // Scenes spawn their cameras with the defaults, so new ones are brought in line too
+        if !settings.is_changed() && !camera_3d.is_added() {
+            continue;
+        }
+        camera.hdr = settings.hdr;
+
+        let mut entity = commands.entity(entity);
+        entity.insert(settings.tonemapping.tonemapping());
+        match settings.anti_aliasing {
+            AntiAliasing::Off => {
+                entity.insert(Msaa::Off).remove::<Smaa>();
+            }
+            AntiAliasing::Msaa => {
+                entity.insert(Msaa::Sample4).remove::<Smaa>();
+            }
+            AntiAliasing::Smaa => {
+                entity.insert((
+                    Msaa::Off,
+                    Smaa {
+                        preset: SmaaPreset::High,
+                    },
+                ));
+            }
+        }
+    }
+}
+
+fn apply_shadow_settings(
+    settings: Res<GraphicsSettings>,
+    mut shadow_map: ResMut<DirectionalLightShadowMap>,
+    mut lights: Query<(&mut DirectionalLight, Ref<ShadowCaster>)>,
+) {
+    if settings.is_changed() {
+        shadow_map.size = settings.shadow_quality.map_size();
+    }
+    for (mut light, caster) in &mut lights {
+        if settings.is_changed() || caster.is_added() {
+            light.shadows_enabled = settings.shadow_quality != ShadowQuality::Off;
+        }
+    }
+}
+
+/// Scenes insert their own `AmbientLight`; the override replaces its brightness
+/// and turning it off restores the scene's value
+fn apply_ambient_brightness(
+    settings: Res<GraphicsSettings>,
+    ambient: Option<ResMut<AmbientLight>>,
+    mut scene_brightness: Local<f32>,
+    mut applied: Local<Option<f32>>,
+) {
+    let Some(mut ambient) = ambient else {
+        return;
+    };
+    // Anything other than what was applied last came from a scene
+    if Some(ambient.brightness) != *applied {
+        *scene_brightness = ambient.brightness;
+    }
+    let brightness = settings.ambient_brightness.unwrap_or(*scene_brightness);
+    if ambient.brightness != brightness {
+        ambient.brightness = brightness;
+    }
+    *applied = Some(brightness);
+}
+
+/// Below full scale the 3D cameras render into a smaller image that is stretched
+/// over the window, underneath the HUD
+fn apply_render_scale(
+    mut commands: Commands,
+    settings: Res<GraphicsSettings>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut images: ResMut<Assets<Image>>,
+    target: Option<Res<ScaledRenderTarget>>,
+    mut cameras: Query<&mut Camera, With<Camera3d>>,
+    mut outputs: Query<(Entity, &mut ImageNode), With<ScaledRenderOutput>>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+
+    if settings.render_scale >= 1.0 || cameras.is_empty() {
+        if target.is_some() {
+            commands.remove_resource::<ScaledRenderTarget>();
+        }
+        for (output, _) in &outputs {
+            commands.entity(output).despawn();
+        }
+        for mut camera in &mut cameras {
+            if matches!(camera.target, RenderTarget::Image(_)) {
+                camera.target = RenderTarget::default();
+            }
+        }
+        return;
+    }
+
+    let size = (window.physical_size().as_vec2() * settings.render_scale)
+        .as_uvec2()
+        .max(UVec2::ONE);
+    let image = match target {
+        Some(target) if target.size == size => target.image.clone(),
+        _ => {
+            let image = images.add(render_target_image(size));
+            commands.insert_resource(ScaledRenderTarget {
+                image: image.clone(),
+                size,
+            });
+            image
+        }
+    };
+
+    for mut camera in &mut cameras {
+        if !matches!(&camera.target, RenderTarget::Image(target) if target.handle == image) {
+            camera.target = RenderTarget::Image(image.clone().into());
+        }
+    }
+
+    match outputs.single_mut() {
+        Ok((_, mut output)) => {
+            if output.image != image {
+                output.image = image;
+            }
+        }
+        Err(_) => {
+            commands.spawn((
+                Name::new("Scaled Render Output"),
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                ImageNode::new(image),
+                GlobalZIndex(i32::MIN),
+                Pickable::IGNORE,
+                ScaledRenderOutput,
+            ));
+        }
+    }
+}
+
+fn render_target_image(size: UVec2) -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: size.x,
+            height: size.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 255],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn choices_step_without_wrapping() {
+        assert_eq!(ShadowQuality::Off.stepped(-1), ShadowQuality::Off);
+        assert_eq!(ShadowQuality::Off.stepped(2), ShadowQuality::Medium);
+        assert_eq!(AntiAliasing::Smaa.stepped(1), AntiAliasing::Smaa);
+    }
+
+    #[test]
+    fn render_scale_snaps_to_steps_within_bounds() {
+        let mut settings = GraphicsSettings::default();
+        settings.step_render_scale(1);
+        assert_eq!(settings.render_scale, 1.0);
+        for _ in 0..3 {
+            settings.step_render_scale(-1);
+        }
+        assert_eq!(settings.render_scale_label(), "70%");
+        settings.step_render_scale(-10);
+        assert_eq!(settings.render_scale, MIN_RENDER_SCALE);
+    }
+
+    #[test]
+    fn ambient_override_steps_out_of_and_back_to_the_scene_value() {
+        let mut settings = GraphicsSettings::default();
+        settings.step_ambient_brightness(-1);
+        assert_eq!(settings.ambient_brightness, None);
+        settings.step_ambient_brightness(1);
+        assert_eq!(settings.ambient_brightness, Some(0.0));
+        settings.step_ambient_brightness(4);
+        assert_eq!(settings.ambient_brightness_label(), "0.4");
+        settings.step_ambient_brightness(-5);
+        assert_eq!(settings.ambient_brightness, None);
+    }
+}
@@ -1 +1,2 @@
 pub mod cameras;
+pub mod graphics;
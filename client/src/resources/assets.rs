@@ -3,19 +3,26 @@ use bevy_asset_loader::prelude::*;
 use bevy_kira_audio::AudioSource;
 
 use crate::screens::Screen;
+use crate::systems::enemy_archetypes::{EnemyArchetypes, EnemyArchetypesLoader};
+use crate::systems::quests::{QuestData, QuestDataLoader};
 
 pub struct AssetsPlugin;
 
 impl Plugin for AssetsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_loading_state(
-            LoadingState::new(Screen::Loading)
-                .continue_to_state(Screen::MainMenu)
-                .load_collection::<UiAssets>()
-                .load_collection::<AudioAssets>()
-                .load_collection::<FontAssets>()
-                .load_collection::<ModelAssets>(),
-        );
+        app.init_asset::<QuestData>()
+            .init_asset_loader::<QuestDataLoader>()
+            .init_asset::<EnemyArchetypes>()
+            .init_asset_loader::<EnemyArchetypesLoader>()
+            .add_loading_state(
+                LoadingState::new(Screen::Loading)
+                    .continue_to_state(Screen::MainMenu)
+                    .load_collection::<UiAssets>()
+                    .load_collection::<AudioAssets>()
+                    .load_collection::<FontAssets>()
+                    .load_collection::<ModelAssets>()
+                    .load_collection::<DataAssets>(),
+            );
     }
 }
 
@@ -115,6 +122,16 @@ pub struct ModelAssets {
     pub book: Handle<Scene>,
 }
 
+// Game Data Assets
+#[derive(AssetCollection, Resource)]
+pub struct DataAssets {
+    #[asset(path = "data/campaign.quests.json")]
+    pub quests: Handle<QuestData>,
+
+    #[asset(path = "data/campaign.enemies.json")]
+    pub enemies: Handle<EnemyArchetypes>,
+}
+
 // Movie/Video Assets
 // #[derive(AssetCollection, Resource)]
 // pub struct MovieAssets {
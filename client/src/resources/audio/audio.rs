@@ -1,9 +1,11 @@
 use crate::screens::Screen;
 use bevy::prelude::*;
 use bevy_kira_audio::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 use crate::assets::AudioAssets;
+use crate::resources::settings::{PersistentSettings, SettingsAppExt};
 
 #[derive(Resource, Default)]
 pub struct AudioResources {
@@ -18,7 +20,8 @@ pub struct MusicChannel;
 #[derive(Resource)]
 pub struct SfxChannel;
 
-#[derive(Resource)]
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AudioSettings {
     pub master_volume: f64,
     pub music_volume: f64,
@@ -37,22 +40,38 @@ impl Default for AudioSettings {
     }
 }
 
+impl PersistentSettings for AudioSettings {
+    const SECTION: &'static str = "audio";
+
+    fn sanitize(&mut self) {
+        let defaults = Self::default();
+        for (volume, default) in [
+            (&mut self.master_volume, defaults.master_volume),
+            (&mut self.music_volume, defaults.music_volume),
+            (&mut self.sfx_volume, defaults.sfx_volume),
+        ] {
+            *volume = if volume.is_finite() {
+                volume.clamp(0.0, 1.0)
+            } else {
+                default
+            };
+        }
+    }
+}
+
 pub struct GameAudioPlugin;
 
 impl Plugin for GameAudioPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<AudioResources>()
-            .init_resource::<AudioSettings>()
+            // Loaded from the settings file here, before the first `apply_audio_settings`
+            .add_persistent_settings::<AudioSettings>()
             .add_audio_channel::<MusicChannel>()
             .add_audio_channel::<SfxChannel>()
             .add_systems(OnEnter(Screen::MainMenu), setup_audio)
             .add_systems(
                 Update,
-                (
-                    apply_audio_settings,
-                    handle_screen_transitions,
-                    instance_control,
-                ),
+                (apply_audio_settings, handle_screen_transitions),
             );
     }
 }
@@ -119,11 +138,5 @@ fn handle_screen_transitions(
     }
 }
 
-fn instance_control(input: Res<ButtonInput<KeyCode>>, mut settings: ResMut<AudioSettings>) {
-    if input.just_pressed(KeyCode::KeyM) {
-        settings.muted = !settings.muted;
-        info!("Mute toggled: {}", settings.muted);
-    }
-}
 
 
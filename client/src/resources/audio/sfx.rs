@@ -4,10 +4,14 @@ use bevy_kira_audio::prelude::*;
 
 use crate::assets::AudioAssets;
 use crate::resources::audio::{SfxChannel, AudioSettings};
-use crate::systems::character_controller::{AnimationState, CharacterController};
+use crate::systems::character_controller::{AnimationState, CharacterController, MovementInputActive};
 use avian3d::prelude::LinearVelocity;
 
 
+/// Combat sounds are loaded on first use rather than with `AudioAssets`
+const PROJECTILE_FIRE_SOUND: &str = "audio/projectile_fire.ogg";
+const PROJECTILE_IMPACT_SOUND: &str = "audio/projectile_impact.ogg";
+
 #[derive(Event)]
 pub struct PlaySfxEvent {
     pub sfx_type: SfxType,
@@ -29,6 +33,8 @@ pub enum SfxType {
     CoinCollect,
     Walking,
     Running,
+    ProjectileFire,
+    ProjectileImpact,
 }
 
 pub struct SfxPlugin;
@@ -48,6 +54,7 @@ impl Plugin for SfxPlugin {
 fn play_sfx_events(
     mut sfx_events: EventReader<PlaySfxEvent>,
     audio_assets: Option<Res<AudioAssets>>,
+    asset_server: Res<AssetServer>,
     sfx_channel: Res<AudioChannel<SfxChannel>>,
     audio_settings: Res<AudioSettings>,
 ) {
@@ -70,6 +77,12 @@ fn play_sfx_events(
             SfxType::Running => {
                 sfx_channel.play(assets.running_sound.clone()).looped();
             }
+            SfxType::ProjectileFire => {
+                sfx_channel.play(asset_server.load(PROJECTILE_FIRE_SOUND));
+            }
+            SfxType::ProjectileImpact => {
+                sfx_channel.play(asset_server.load(PROJECTILE_IMPACT_SOUND));
+            }
         }
     }
 }
@@ -79,13 +92,9 @@ fn handle_movement_sfx(
     mut stop_events: EventWriter<StopMovementAudioEvent>,
     mut movement_state: ResMut<MovementAudioState>,
     character_query: Query<(&LinearVelocity, &AnimationState), With<CharacterController>>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    movement_input: Res<MovementInputActive>,
 ) {
-    // Check if any movement keys are pressed
-    let is_movement_pressed = keyboard.any_pressed([
-        KeyCode::KeyW, KeyCode::KeyA, KeyCode::KeyS, KeyCode::KeyD,
-        KeyCode::ArrowUp, KeyCode::ArrowDown, KeyCode::ArrowLeft, KeyCode::ArrowRight,
-    ]);
+    let is_movement_pressed = movement_input.0;
 
     // Check if character is moving (based on velocity)
     let is_moving = character_query.iter().any(|(velocity, _)| {
@@ -0,0 +1,376 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::settings::PersistentSettings;
+
+// ===== ACTIONS =====
+
+/// Keys no action can be bound to. Enter is held back for the fixed Alt+Enter fullscreen toggle.
+pub const RESERVED_KEYS: &[KeyCode] = &[KeyCode::Enter];
+
+/// Every action the player can rebind, in the order the Controls tab lists them.
+/// Movement is split into its four directions so each can take its own key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ControlAction {
+    MoveForward,
+    MoveBack,
+    MoveLeft,
+    MoveRight,
+    Jump,
+    Sprint,
+    Interact,
+    FightMove,
+    UseHealthPotion,
+    UseSurvivalKit,
+    ToggleFullScreen,
+    ToggleMute,
+    ReturnToMainMenu,
+    CreateGame,
+}
+
+impl ControlAction {
+    pub const ALL: &'static [Self] = &[
+        ControlAction::MoveForward,
+        ControlAction::MoveBack,
+        ControlAction::MoveLeft,
+        ControlAction::MoveRight,
+        ControlAction::Jump,
+        ControlAction::Sprint,
+        ControlAction::Interact,
+        ControlAction::FightMove,
+        ControlAction::UseHealthPotion,
+        ControlAction::UseSurvivalKit,
+        ControlAction::ToggleFullScreen,
+        ControlAction::ToggleMute,
+        ControlAction::ReturnToMainMenu,
+        ControlAction::CreateGame,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ControlAction::MoveForward => "Move Forward",
+            ControlAction::MoveBack => "Move Back",
+            ControlAction::MoveLeft => "Move Left",
+            ControlAction::MoveRight => "Move Right",
+            ControlAction::Jump => "Jump",
+            ControlAction::Sprint => "Sprint",
+            ControlAction::Interact => "Interact",
+            ControlAction::FightMove => "Fight Move",
+            ControlAction::UseHealthPotion => "Health Potion",
+            ControlAction::UseSurvivalKit => "Survival Kit",
+            ControlAction::ToggleFullScreen => "Fullscreen",
+            ControlAction::ToggleMute => "Mute",
+            ControlAction::ReturnToMainMenu => "Main Menu",
+            ControlAction::CreateGame => "Create Game",
+        }
+    }
+
+    fn default_inputs(self) -> ActionInputs {
+        use GamepadButton as Pad;
+        use KeyCode::*;
+        let (keys, gamepad) = match self {
+            ControlAction::MoveForward => ([Some(KeyW), Some(ArrowUp)], Pad::DPadUp),
+            ControlAction::MoveBack => ([Some(KeyS), Some(ArrowDown)], Pad::DPadDown),
+            ControlAction::MoveLeft => ([Some(KeyA), Some(ArrowLeft)], Pad::DPadLeft),
+            ControlAction::MoveRight => ([Some(KeyD), Some(ArrowRight)], Pad::DPadRight),
+            ControlAction::Jump => ([Some(Space), None], Pad::South),
+            ControlAction::Sprint => ([Some(ShiftLeft), Some(ShiftRight)], Pad::LeftTrigger2),
+            ControlAction::Interact => ([Some(KeyE), None], Pad::East),
+            ControlAction::FightMove => ([Some(KeyX), None], Pad::RightTrigger2),
+            ControlAction::UseHealthPotion => ([Some(Digit1), None], Pad::West),
+            ControlAction::UseSurvivalKit => ([Some(Digit2), None], Pad::North),
+            ControlAction::ToggleFullScreen => ([Some(F11), None], Pad::Select),
+            ControlAction::ToggleMute => ([Some(KeyM), None], Pad::RightThumb),
+            ControlAction::ReturnToMainMenu => ([Some(Escape), None], Pad::Start),
+            ControlAction::CreateGame => ([Some(KeyG), None], Pad::RightTrigger),
+        };
+        ActionInputs {
+            keys,
+            gamepad: Some(gamepad),
+        }
+    }
+}
+
+/// Which of an action's inputs a rebind replaces
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindingSlot {
+    Key(usize),
+    Gamepad,
+}
+
+/// The inputs bound to one action: a primary and an alternate key, and a gamepad button
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActionInputs {
+    pub keys: [Option<KeyCode>; 2],
+    pub gamepad: Option<GamepadButton>,
+}
+
+// ===== RESOURCES =====
+
+/// Inputs bound to every `ControlAction`. Changing this resource rebuilds the input contexts.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ControlSettings {
+    bindings: BTreeMap<ControlAction, ActionInputs>,
+}
+
+impl Default for ControlSettings {
+    fn default() -> Self {
+        Self {
+            bindings: ControlAction::ALL
+                .iter()
+                .map(|&action| (action, action.default_inputs()))
+                .collect(),
+        }
+    }
+}
+
+impl ControlSettings {
+    pub fn inputs(&self, action: ControlAction) -> ActionInputs {
+        self.bindings.get(&action).copied().unwrap_or_default()
+    }
+
+    /// How prompts name an action's inputs: its first bound key and its gamepad button,
+    /// e.g. `E / B`. `None` when nothing is bound.
+    pub fn inputs_label(&self, action: ControlAction) -> Option<String> {
+        let inputs = self.inputs(action);
+        let labels: Vec<String> = inputs
+            .keys
+            .into_iter()
+            .flatten()
+            .take(1)
+            .map(key_label)
+            .chain(inputs.gamepad.map(gamepad_label))
+            .collect();
+        (!labels.is_empty()).then(|| labels.join(" / "))
+    }
+
+    /// Binds `key` to one of `action`'s key slots. The key is taken away from whatever
+    /// else used it, and that action is returned so the player can be told.
+    /// Reserved keys are never bound.
+    pub fn assign_key(
+        &mut self,
+        action: ControlAction,
+        index: usize,
+        key: KeyCode,
+    ) -> Option<ControlAction> {
+        if RESERVED_KEYS.contains(&key) {
+            return None;
+        }
+        let mut displaced = None;
+        for (&other, inputs) in self.bindings.iter_mut() {
+            for slot in inputs.keys.iter_mut().filter(|slot| **slot == Some(key)) {
+                *slot = None;
+                if other != action {
+                    displaced = Some(other);
+                }
+            }
+        }
+        if let Some(slot) = self.bindings.entry(action).or_default().keys.get_mut(index) {
+            *slot = Some(key);
+        }
+        displaced
+    }
+
+    /// Binds `button` to `action`, taking it away from whatever else used it
+    pub fn assign_gamepad(
+        &mut self,
+        action: ControlAction,
+        button: GamepadButton,
+    ) -> Option<ControlAction> {
+        let mut displaced = None;
+        for (&other, inputs) in self.bindings.iter_mut() {
+            if inputs.gamepad == Some(button) {
+                inputs.gamepad = None;
+                if other != action {
+                    displaced = Some(other);
+                }
+            }
+        }
+        self.bindings.entry(action).or_default().gamepad = Some(button);
+        displaced
+    }
+
+    pub fn clear(&mut self, action: ControlAction, slot: BindingSlot) {
+        let inputs = self.bindings.entry(action).or_default();
+        match slot {
+            BindingSlot::Key(index) => {
+                if let Some(key) = inputs.keys.get_mut(index) {
+                    *key = None;
+                }
+            }
+            BindingSlot::Gamepad => inputs.gamepad = None,
+        }
+    }
+
+    /// Actions that share a key or gamepad button with another action, or use a reserved
+    /// key. Rebinding in the Controls tab never leaves any, but a hand-edited settings file can.
+    pub fn conflicts(&self) -> Vec<ControlAction> {
+        let mut conflicts = Vec::new();
+        for (&action, inputs) in &self.bindings {
+            let reserved = inputs
+                .keys
+                .iter()
+                .flatten()
+                .any(|key| RESERVED_KEYS.contains(key));
+            let clashes = reserved
+                || self.bindings.iter().any(|(&other, other_inputs)| {
+                    other != action
+                        && (inputs
+                            .keys
+                            .iter()
+                            .flatten()
+                            .any(|key| other_inputs.keys.contains(&Some(*key)))
+                            || (inputs.gamepad.is_some() && inputs.gamepad == other_inputs.gamepad))
+                });
+            if clashes {
+                conflicts.push(action);
+            }
+        }
+        conflicts
+    }
+}
+
+impl PersistentSettings for ControlSettings {
+    const SECTION: &'static str = "controls";
+
+    fn sanitize(&mut self) {
+        // Actions added since the file was written start with their default inputs
+        for &action in ControlAction::ALL {
+            self.bindings
+                .entry(action)
+                .or_insert_with(|| action.default_inputs());
+        }
+    }
+}
+
+/// Short name of a key for the Controls tab
+pub fn key_label(key: KeyCode) -> String {
+    let name = format!("{:?}", key);
+    let name = name
+        .strip_prefix("Key")
+        .or_else(|| name.strip_prefix("Digit"))
+        .unwrap_or(&name);
+    name.to_uppercase()
+}
+
+/// Name of a gamepad button for the Controls tab, using the common controller layout
+pub fn gamepad_label(button: GamepadButton) -> String {
+    match button {
+        GamepadButton::South => "A".to_string(),
+        GamepadButton::East => "B".to_string(),
+        GamepadButton::West => "X".to_string(),
+        GamepadButton::North => "Y".to_string(),
+        GamepadButton::LeftTrigger => "LB".to_string(),
+        GamepadButton::RightTrigger => "RB".to_string(),
+        GamepadButton::LeftTrigger2 => "LT".to_string(),
+        GamepadButton::RightTrigger2 => "RT".to_string(),
+        GamepadButton::LeftThumb => "L3".to_string(),
+        GamepadButton::RightThumb => "R3".to_string(),
+        GamepadButton::DPadUp => "D-PAD UP".to_string(),
+        GamepadButton::DPadDown => "D-PAD DOWN".to_string(),
+        GamepadButton::DPadLeft => "D-PAD LEFT".to_string(),
+        GamepadButton::DPadRight => "D-PAD RIGHT".to_string(),
+        GamepadButton::Select => "VIEW".to_string(),
+        GamepadButton::Start => "MENU".to_string(),
+        other => format!("{:?}", other).to_uppercase(),
+    }
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_have_no_conflicts() {
+        assert!(ControlSettings::default().conflicts().is_empty());
+    }
+
+    #[test]
+    fn assigning_a_used_key_takes_it_from_the_other_action() {
+        let mut controls = ControlSettings::default();
+        let displaced = controls.assign_key(ControlAction::Jump, 0, KeyCode::KeyE);
+        assert_eq!(displaced, Some(ControlAction::Interact));
+        assert_eq!(
+            controls.inputs(ControlAction::Jump).keys[0],
+            Some(KeyCode::KeyE)
+        );
+        assert_eq!(controls.inputs(ControlAction::Interact).keys, [None, None]);
+        assert!(controls.conflicts().is_empty());
+
+        let displaced = controls.assign_gamepad(ControlAction::Interact, GamepadButton::South);
+        assert_eq!(displaced, Some(ControlAction::Jump));
+        assert_eq!(controls.inputs(ControlAction::Jump).gamepad, None);
+    }
+
+    #[test]
+    fn moving_a_key_between_slots_of_one_action_is_not_a_conflict() {
+        let mut controls = ControlSettings::default();
+        let displaced = controls.assign_key(ControlAction::Sprint, 1, KeyCode::ShiftLeft);
+        assert_eq!(displaced, None);
+        assert_eq!(
+            controls.inputs(ControlAction::Sprint).keys,
+            [None, Some(KeyCode::ShiftLeft)]
+        );
+    }
+
+    #[test]
+    fn input_labels_follow_rebinds() {
+        let mut controls = ControlSettings::default();
+        assert_eq!(
+            controls.inputs_label(ControlAction::Interact).as_deref(),
+            Some("E / B")
+        );
+        controls.assign_key(ControlAction::Interact, 1, KeyCode::KeyF);
+        controls.clear(ControlAction::Interact, BindingSlot::Key(0));
+        controls.clear(ControlAction::Interact, BindingSlot::Gamepad);
+        assert_eq!(
+            controls.inputs_label(ControlAction::Interact).as_deref(),
+            Some("F")
+        );
+        controls.clear(ControlAction::Interact, BindingSlot::Key(1));
+        assert_eq!(controls.inputs_label(ControlAction::Interact), None);
+    }
+
+    #[test]
+    fn reserved_keys_are_never_bound() {
+        let mut controls = ControlSettings::default();
+        assert_eq!(
+            controls.assign_key(ControlAction::Jump, 0, KeyCode::Enter),
+            None
+        );
+        assert_eq!(
+            controls.inputs(ControlAction::Jump).keys[0],
+            Some(KeyCode::Space)
+        );
+
+        let mut hand_edited: ControlSettings =
+            serde_json::from_str(r#"{ "bindings": { "jump": { "keys": ["Enter", null] } } }"#)
+                .unwrap();
+        hand_edited.sanitize();
+        assert_eq!(hand_edited.conflicts(), vec![ControlAction::Jump]);
+    }
+
+    #[test]
+    fn hand_edited_clashes_are_reported_and_missing_actions_restored() {
+        let mut controls: ControlSettings = serde_json::from_str(
+            r#"{ "bindings": { "jump": { "keys": ["KeyE", null] }, "interact": { "keys": ["KeyE", null] } } }"#,
+        )
+        .unwrap();
+        controls.sanitize();
+        assert_eq!(
+            controls.conflicts(),
+            vec![ControlAction::Jump, ControlAction::Interact]
+        );
+        assert_eq!(
+            controls.inputs(ControlAction::Sprint),
+            ControlAction::Sprint.default_inputs()
+        );
+    }
+}
@@ -1,2 +1,5 @@
 pub mod assets;
 pub mod audio;
+pub mod controls;
+pub mod settings;
+pub mod window;
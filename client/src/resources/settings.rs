@@ -0,0 +1,226 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::constants::settings::SETTINGS_FILE;
+use crate::systems::save::platform_dir;
+
+// ===== PERSISTENT SETTINGS =====
+
+/// A settings resource that is loaded from the settings file on startup and
+/// written back whenever it changes. Each one owns a section of the file.
+pub trait PersistentSettings: Resource + Serialize + DeserializeOwned + Default {
+    /// Key of this resource's section in the settings file
+    const SECTION: &'static str;
+
+    /// Brings hand-edited or out of range values back to something usable
+    fn sanitize(&mut self) {}
+}
+
+/// A setting with a fixed list of choices that the settings screen steps through
+pub trait SettingChoice: Copy + PartialEq + 'static {
+    const ALL: &'static [Self];
+
+    fn label(self) -> &'static str;
+
+    /// The choice `step` places away from this one, stopping at either end of the list
+    fn stepped(self, step: isize) -> Self {
+        let index = Self::ALL.iter().position(|choice| *choice == self).unwrap_or(0) as isize;
+        Self::ALL[(index + step).clamp(0, Self::ALL.len() as isize - 1) as usize]
+    }
+}
+
+/// The settings file as loaded, one JSON value per section
+#[derive(Resource, Default, Debug)]
+pub struct SettingsStore {
+    sections: Map<String, Value>,
+    dirty: bool,
+}
+
+impl SettingsStore {
+    /// Parses the settings file. A file that is not a JSON object is ignored as a whole.
+    pub fn parse(text: &str) -> Result<Self, serde_json::Error> {
+        let sections = serde_json::from_str(text)?;
+        Ok(Self {
+            sections,
+            dirty: false,
+        })
+    }
+
+    /// Reads the settings file, falling back to defaults if it is missing or corrupt.
+    /// A corrupt file is set aside rather than overwritten so it can be inspected.
+    pub fn load() -> Self {
+        let Some(path) = settings_path() else {
+            warn!("No config directory, settings will not be saved");
+            return Self::default();
+        };
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Self::default(),
+            Err(err) => {
+                warn!("Could not read {}: {}", path.display(), err);
+                return Self::default();
+            }
+        };
+        match Self::parse(&text) {
+            Ok(store) => store,
+            Err(err) => {
+                warn!("Settings file {} is corrupt, using defaults: {}", path.display(), err);
+                if let Err(err) = fs::rename(&path, path.with_extension("json.corrupt")) {
+                    warn!("Could not set aside the corrupt settings file: {}", err);
+                }
+                Self::default()
+            }
+        }
+    }
+
+    /// The stored section for a settings type. Fields missing from the file take their
+    /// defaults, and a section that does not deserialize is replaced by the defaults.
+    pub fn section<T: PersistentSettings>(&self) -> T {
+        let mut settings = match self.sections.get(T::SECTION) {
+            Some(value) => T::deserialize(value).unwrap_or_else(|err| {
+                warn!("Invalid \"{}\" settings, using defaults: {}", T::SECTION, err);
+                T::default()
+            }),
+            None => T::default(),
+        };
+        settings.sanitize();
+        settings
+    }
+
+    pub fn set_section<T: PersistentSettings>(&mut self, settings: &T) {
+        match serde_json::to_value(settings) {
+            Ok(value) => {
+                self.sections.insert(T::SECTION.to_string(), value);
+                self.dirty = true;
+            }
+            Err(err) => warn!("Could not store \"{}\" settings: {}", T::SECTION, err),
+        }
+    }
+
+    fn write(&self) -> io::Result<()> {
+        let Some(path) = settings_path() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let temp = path.with_extension("json.tmp");
+        fs::write(&temp, serde_json::to_string_pretty(&self.sections)?)?;
+        fs::rename(&temp, &path)
+    }
+}
+
+/// Per-user directory for the game's configuration
+pub fn config_dir() -> Option<PathBuf> {
+    platform_dir("XDG_CONFIG_HOME", ".config")
+}
+
+fn settings_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(SETTINGS_FILE))
+}
+
+// ===== APP EXTENSION =====
+
+pub trait SettingsAppExt {
+    /// Inserts a settings resource with its saved values and keeps the file up to date
+    fn add_persistent_settings<T: PersistentSettings>(&mut self) -> &mut Self;
+}
+
+impl SettingsAppExt for App {
+    fn add_persistent_settings<T: PersistentSettings>(&mut self) -> &mut Self {
+        if !self.world().contains_resource::<SettingsStore>() {
+            self.insert_resource(SettingsStore::load());
+        }
+        let settings = self.world().resource::<SettingsStore>().section::<T>();
+        // Inserted while the app is built, so plugins see the saved values from their first frame
+        self.insert_resource(settings)
+            .add_systems(Last, stage_settings::<T>.before(write_settings))
+    }
+}
+
+// ===== PLUGIN =====
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<SettingsStore>() {
+            app.insert_resource(SettingsStore::load());
+        }
+        app.add_systems(Last, write_settings);
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn stage_settings<T: PersistentSettings>(settings: Res<T>, mut store: ResMut<SettingsStore>) {
+    // Skip the first frame, when the resource counts as changed because it was just inserted
+    if settings.is_changed() && !settings.is_added() {
+        store.set_section(&*settings);
+    }
+}
+
+fn write_settings(mut store: ResMut<SettingsStore>) {
+    if !store.dirty {
+        return;
+    }
+    store.dirty = false;
+    if let Err(err) = store.write() {
+        warn!("Could not save settings: {}", err);
+    }
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::audio::AudioSettings;
+
+    #[test]
+    fn missing_fields_and_sections_take_defaults() {
+        let store = SettingsStore::parse(r#"{ "audio": { "music_volume": 0.4 } }"#).unwrap();
+        let audio = store.section::<AudioSettings>();
+        assert_eq!(audio.music_volume, 0.4);
+        assert_eq!(audio.sfx_volume, AudioSettings::default().sfx_volume);
+
+        let empty = SettingsStore::parse("{}").unwrap();
+        assert!(!empty.section::<AudioSettings>().muted);
+    }
+
+    #[test]
+    fn invalid_sections_fall_back_to_defaults() {
+        let store = SettingsStore::parse(r#"{ "audio": { "muted": "loud" } }"#).unwrap();
+        assert_eq!(store.section::<AudioSettings>().master_volume, AudioSettings::default().master_volume);
+        assert!(SettingsStore::parse("not json").is_err());
+    }
+
+    #[test]
+    fn out_of_range_values_are_sanitized() {
+        let store = SettingsStore::parse(r#"{ "audio": { "master_volume": 7.5, "sfx_volume": -1.0 } }"#).unwrap();
+        let audio = store.section::<AudioSettings>();
+        assert_eq!(audio.master_volume, 1.0);
+        assert_eq!(audio.sfx_volume, 0.0);
+    }
+
+    #[test]
+    fn sections_round_trip_through_the_store() {
+        let mut store = SettingsStore::default();
+        let audio = AudioSettings {
+            music_volume: 0.3,
+            muted: true,
+            ..default()
+        };
+        store.set_section(&audio);
+        let stored = store.section::<AudioSettings>();
+        assert_eq!(stored.music_volume, 0.3);
+        assert!(stored.muted);
+        assert!(store.dirty);
+    }
+}
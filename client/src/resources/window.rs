@@ -0,0 +1,318 @@
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use bevy::window::{
+    Monitor, MonitorSelection, PresentMode, PrimaryMonitor, PrimaryWindow, VideoModeSelection,
+    WindowMode,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::constants::settings::{
+    FALLBACK_RESOLUTIONS, MAX_UI_SCALE, MIN_UI_SCALE, MIN_WINDOW_HEIGHT, MIN_WINDOW_WIDTH,
+    UI_SCALE_STEP,
+};
+use crate::resources::settings::{PersistentSettings, SettingChoice, SettingsAppExt};
+
+// ===== RESOURCES =====
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowModeSetting {
+    #[default]
+    Windowed,
+    Borderless,
+    /// Exclusive fullscreen at the chosen resolution
+    Exclusive,
+}
+
+impl SettingChoice for WindowModeSetting {
+    const ALL: &'static [Self] = &[
+        WindowModeSetting::Windowed,
+        WindowModeSetting::Borderless,
+        WindowModeSetting::Exclusive,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            WindowModeSetting::Windowed => "WINDOWED",
+            WindowModeSetting::Borderless => "BORDERLESS",
+            WindowModeSetting::Exclusive => "FULLSCREEN",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FrameCap {
+    #[default]
+    Off,
+    Fps30,
+    Fps60,
+    Fps120,
+    Fps144,
+    Fps240,
+}
+
+impl FrameCap {
+    pub fn fps(self) -> Option<u32> {
+        match self {
+            FrameCap::Off => None,
+            FrameCap::Fps30 => Some(30),
+            FrameCap::Fps60 => Some(60),
+            FrameCap::Fps120 => Some(120),
+            FrameCap::Fps144 => Some(144),
+            FrameCap::Fps240 => Some(240),
+        }
+    }
+}
+
+impl SettingChoice for FrameCap {
+    const ALL: &'static [Self] = &[
+        FrameCap::Off,
+        FrameCap::Fps30,
+        FrameCap::Fps60,
+        FrameCap::Fps120,
+        FrameCap::Fps144,
+        FrameCap::Fps240,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            FrameCap::Off => "OFF",
+            FrameCap::Fps30 => "30",
+            FrameCap::Fps60 => "60",
+            FrameCap::Fps120 => "120",
+            FrameCap::Fps144 => "144",
+            FrameCap::Fps240 => "240",
+        }
+    }
+}
+
+/// How the primary window is shown. Changing this resource updates the window.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowSettings {
+    pub mode: WindowModeSetting,
+    /// Size of the window while windowed, and the video mode in exclusive fullscreen
+    pub width: u32,
+    pub height: u32,
+    pub vsync: bool,
+    pub frame_cap: FrameCap,
+    /// Multiplier on the size of every UI node
+    pub ui_scale: f32,
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        Self {
+            mode: WindowModeSetting::Windowed,
+            width: 1920,
+            height: 1080,
+            vsync: true,
+            frame_cap: FrameCap::Off,
+            ui_scale: 1.0,
+        }
+    }
+}
+
+impl WindowSettings {
+    pub fn resolution(&self) -> UVec2 {
+        UVec2::new(self.width, self.height)
+    }
+
+    /// Moves to a neighbouring resolution in `available`, which is sorted from smallest to
+    /// largest. A resolution not in the list steps to the nearest one in that direction.
+    pub fn step_resolution(&mut self, available: &[UVec2], steps: isize) {
+        let current = self.resolution();
+        let area = |size: UVec2| size.x as u64 * size.y as u64;
+        let index = match available.iter().position(|size| *size == current) {
+            Some(index) => index as isize + steps,
+            None if steps < 0 => available
+                .iter()
+                .rposition(|size| area(*size) < area(current))
+                .map_or(0, |i| i as isize),
+            None => available
+                .iter()
+                .position(|size| area(*size) > area(current))
+                .map_or(available.len() as isize - 1, |i| i as isize),
+        };
+        if let Some(size) = available.get(index.clamp(0, available.len() as isize - 1) as usize) {
+            self.width = size.x;
+            self.height = size.y;
+        }
+    }
+
+    pub fn step_ui_scale(&mut self, steps: i32) {
+        let scale = self.ui_scale + steps as f32 * UI_SCALE_STEP;
+        self.ui_scale =
+            ((scale / UI_SCALE_STEP).round() * UI_SCALE_STEP).clamp(MIN_UI_SCALE, MAX_UI_SCALE);
+    }
+
+    pub fn resolution_label(&self) -> String {
+        format!("{}x{}", self.width, self.height)
+    }
+
+    pub fn ui_scale_label(&self) -> String {
+        format!("{}%", (self.ui_scale * 100.0).round() as u32)
+    }
+}
+
+impl PersistentSettings for WindowSettings {
+    const SECTION: &'static str = "window";
+
+    fn sanitize(&mut self) {
+        self.width = self.width.max(MIN_WINDOW_WIDTH);
+        self.height = self.height.max(MIN_WINDOW_HEIGHT);
+        self.ui_scale = if self.ui_scale.is_finite() {
+            self.ui_scale.clamp(MIN_UI_SCALE, MAX_UI_SCALE)
+        } else {
+            1.0
+        };
+    }
+}
+
+/// Resolutions the primary monitor supports, smallest first. Falls back to common
+/// sizes when the monitor does not report its video modes.
+pub fn available_resolutions(monitor: Option<&Monitor>) -> Vec<UVec2> {
+    let mut resolutions: Vec<UVec2> = monitor
+        .map(|monitor| {
+            monitor
+                .video_modes
+                .iter()
+                .map(|mode| mode.physical_size)
+                .collect()
+        })
+        .unwrap_or_default();
+    resolutions.retain(|size| size.x >= MIN_WINDOW_WIDTH && size.y >= MIN_WINDOW_HEIGHT);
+    if resolutions.is_empty() {
+        resolutions = FALLBACK_RESOLUTIONS
+            .iter()
+            .map(|&(width, height)| UVec2::new(width, height))
+            .collect();
+    }
+    resolutions.sort_by_key(|size| (size.x as u64 * size.y as u64, size.x));
+    resolutions.dedup();
+    resolutions
+}
+
+// ===== PLUGIN =====
+
+pub struct WindowSettingsPlugin;
+
+impl Plugin for WindowSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_persistent_settings::<WindowSettings>()
+            .add_systems(Update, apply_window_settings)
+            // Last in the frame, so the wait covers everything the frame did
+            .add_systems(Last, limit_frame_rate);
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn apply_window_settings(
+    settings: Res<WindowSettings>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    monitors: Query<&Monitor, With<PrimaryMonitor>>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    let Ok(mut window) = windows.single_mut() else {
+        return;
+    };
+
+    window.mode = match settings.mode {
+        WindowModeSetting::Windowed => WindowMode::Windowed,
+        WindowModeSetting::Borderless => {
+            WindowMode::BorderlessFullscreen(MonitorSelection::Primary)
+        }
+        WindowModeSetting::Exclusive => {
+            // The fastest refresh rate the monitor offers at the chosen resolution
+            let video_mode = monitors.single().ok().and_then(|monitor| {
+                monitor
+                    .video_modes
+                    .iter()
+                    .filter(|mode| mode.physical_size == settings.resolution())
+                    .max_by_key(|mode| mode.refresh_rate_millihertz)
+                    .copied()
+            });
+            WindowMode::Fullscreen(
+                MonitorSelection::Primary,
+                video_mode.map_or(VideoModeSelection::Current, VideoModeSelection::Specific),
+            )
+        }
+    };
+    window
+        .resolution
+        .set(settings.width as f32, settings.height as f32);
+    window.present_mode = if settings.vsync {
+        PresentMode::AutoVsync
+    } else {
+        PresentMode::AutoNoVsync
+    };
+    ui_scale.0 = settings.ui_scale;
+}
+
+/// Sleeps off whatever is left of the frame's time budget under the frame cap
+fn limit_frame_rate(settings: Res<WindowSettings>, mut frame_start: Local<Option<Instant>>) {
+    if let (Some(fps), Some(start)) = (settings.frame_cap.fps(), *frame_start) {
+        let budget = Duration::from_secs_f64(1.0 / fps as f64);
+        let elapsed = start.elapsed();
+        // Sleeping blocks the browser's event loop, so the cap is native only
+        if elapsed < budget && cfg!(not(target_arch = "wasm32")) {
+            std::thread::sleep(budget - elapsed);
+        }
+    }
+    *frame_start = Some(Instant::now());
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolutions_step_through_the_list() {
+        let available = available_resolutions(None);
+        let mut settings = WindowSettings::default();
+        settings.step_resolution(&available, 1);
+        assert!(settings.resolution().x * settings.resolution().y > 1920 * 1080);
+        settings.step_resolution(&available, -1);
+        assert_eq!(settings.resolution(), UVec2::new(1920, 1080));
+        settings.step_resolution(&available, -100);
+        assert_eq!(settings.resolution(), available[0]);
+    }
+
+    #[test]
+    fn unlisted_resolutions_step_to_the_nearest_listed_one() {
+        let available = vec![
+            UVec2::new(1280, 720),
+            UVec2::new(1920, 1080),
+            UVec2::new(2560, 1440),
+        ];
+        let mut settings = WindowSettings {
+            width: 1600,
+            height: 900,
+            ..default()
+        };
+        settings.step_resolution(&available, 1);
+        assert_eq!(settings.resolution(), UVec2::new(1920, 1080));
+
+        settings.width = 1600;
+        settings.height = 900;
+        settings.step_resolution(&available, -1);
+        assert_eq!(settings.resolution(), UVec2::new(1280, 720));
+    }
+
+    #[test]
+    fn ui_scale_stays_within_bounds() {
+        let mut settings = WindowSettings::default();
+        settings.step_ui_scale(2);
+        assert_eq!(settings.ui_scale_label(), "120%");
+        settings.step_ui_scale(-100);
+        assert_eq!(settings.ui_scale, MIN_UI_SCALE);
+    }
+}
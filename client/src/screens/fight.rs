@@ -1,7 +1,11 @@
 use super::{Screen, despawn_scene};
 use crate::assets::ModelAssets;
 use crate::systems::character_controller::CharacterControllerBundle;
-use crate::systems::enemy_ai::EnemyBundle;
+use crate::game::Health;
+use crate::rendering::graphics::ShadowCaster;
+use crate::systems::enemy_archetypes::Bestiary;
+use crate::systems::sacrifice::SacrificeOffering;
+use crate::ui::hud::{HudNotificationEvent, NotificationKind};
 use avian3d::prelude::{
     ColliderConstructor, ColliderConstructorHierarchy, Friction, GravityScale,
     Restitution, RigidBody, CollisionEventsEnabled,
@@ -14,10 +18,9 @@ use bevy_gltf_animation::prelude::GltfSceneRoot;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::FightScene), (spawn_fight_scene, despawn_collectibles))
-        .add_systems(OnExit(Screen::FightScene), despawn_scene::<FightScene>)
         .add_systems(
-            Update,
-            handle_fight_input.run_if(in_state(Screen::FightScene)),
+            OnExit(Screen::FightScene),
+            (despawn_scene::<FightScene>, despawn_collectibles),
         )
         .add_systems(
             Update,
@@ -32,6 +35,9 @@ fn spawn_fight_scene(
     assets: Res<ModelAssets>,
     ui_assets: Res<crate::assets::UiAssets>,
     font_assets: Res<crate::assets::FontAssets>,
+    offering: Res<SacrificeOffering>,
+    bestiary: Bestiary,
+    mut notifications: EventWriter<HudNotificationEvent>,
 ) {
     // Set up ambient light (match gameplay)
     commands.insert_resource(AmbientLight {
@@ -68,6 +74,7 @@ fn spawn_fight_scene(
             std::f32::consts::FRAC_PI_4,
             0.0,
         )),
+        ShadowCaster,
         FightScene,
     ));
 
@@ -133,28 +140,40 @@ fn spawn_fight_scene(
             Restitution::new(0.0),
             GravityScale(1.0),
             CollisionEventsEnabled, // Enable collision events
+            Health {
+                current: crate::constants::player::BASE_MAX_HEALTH,
+                max: crate::constants::player::BASE_MAX_HEALTH,
+            },
+            crate::systems::equipment::Stats::default(),
+            crate::systems::progression::Experience::default(),
+            crate::systems::items::ActiveBuffs::default(),
             Actions::<crate::keybinding::Player>::default(),
             FightScene,
         ))
         .observe(crate::systems::character_controller::setup_idle_animation);
 
-    // Spawn the enemy model with AI and animations
-    commands.spawn((
-        Name::new("Fight Enemy"),
-        GltfSceneRoot::new(assets.enemy.clone()),
-        Transform {
-            translation: Vec3::new(5.0, -1.65, 0.0),
-            rotation: Quat::from_rotation_y(std::f32::consts::PI),
-            scale: Vec3::splat(4.0),
-            ..default()
-        },
-        EnemyBundle::default(),
-        Friction::new(0.5),
-        Restitution::new(0.0),
-        GravityScale(1.0),
-        CollisionEventsEnabled, // Enable collision events
-        FightScene,
-    )).observe(crate::systems::character_controller::setup_idle_animation);
+    // The sacrificed value summons a tougher enemy that drops better loot
+    if offering.value > 0 {
+        let burned: u32 = offering.items.iter().map(|stack| stack.count).sum();
+        notifications.write(HudNotificationEvent::new(
+            format!(
+                "The realm accepts your offering of {} items (worth {})",
+                burned, offering.value
+            ),
+            NotificationKind::Info,
+        ));
+    }
+
+    // Spawn the realm's boss with AI and animations
+    if let Some(mut boss) = bestiary.spawn(
+        &mut commands,
+        "realm_boss",
+        Transform::from_xyz(5.0, -1.65, 0.0).with_rotation(Quat::from_rotation_y(std::f32::consts::PI)),
+        offering.enemy_health_multiplier(),
+        offering.loot_level(),
+    ) {
+        boss.insert((Name::new("Fight Enemy"), FightScene));
+    }
 
     // Add a camera (match gameplay)
     commands.spawn((
@@ -168,7 +187,9 @@ fn spawn_fight_scene(
         FightScene,
     ));
 
-    // Add a simple UI text to show we're in the fight scene
+    crate::ui::hud::spawn_notification_feed::<FightScene>(&mut commands);
+
+    // Player HUD
     commands
         .spawn((
             Node {
@@ -181,48 +202,22 @@ fn spawn_fight_scene(
             FightScene,
         ))
         .with_children(|parent| {
-            // Health bars row
-            // Use player_hud_widget for both player and enemy
+            // The boss shows its health in its own bar above the arena
             parent.spawn(crate::ui::widgets::player_hud_widget(
                 ui_assets.player_avatar.clone(),
                 "Player",
-                2,         // example level
-                (80, 100), // example health
-                (50, 100), // example xp
+                1, // level, health and xp are kept live by `HudPlugin`
+                (
+                    crate::constants::player::BASE_MAX_HEALTH,
+                    crate::constants::player::BASE_MAX_HEALTH,
+                ),
+                (0, crate::constants::player::XP_PER_LEVEL),
                 font_assets.rajdhani_bold.clone(),
                 crate::ui::widgets::HudPosition::Left,
             ));
-            parent.spawn(crate::ui::widgets::player_hud_widget(
-                ui_assets.enemy_avatar.clone(),
-                "Enemy",
-                3,          // example level
-                (120, 150), // example health
-                (90, 100),  // example xp
-                font_assets.rajdhani_medium.clone(),
-                crate::ui::widgets::HudPosition::Right,
-            ));
-            parent.spawn((
-                Text::new("FIGHT SCENE\nPress ESC to return to gameplay\nPress COMMA from gameplay to enter fight"),
-                TextFont {
-                    font_size: 40.0,
-                    ..default()
-                },
-                TextColor::WHITE,
-                FightScene,
-            ));
         });
 }
 
-fn handle_fight_input(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    mut next_state: ResMut<NextState<Screen>>,
-) {
-    // Return to gameplay when ESC is pressed
-    if keyboard_input.just_pressed(KeyCode::Escape) {
-        next_state.set(Screen::GamePlay);
-    }
-}
-
 fn camera_follow_fight_player(
     player_query: Query<
         &Transform,
@@ -270,4 +265,4 @@ fn despawn_collectibles(mut commands: Commands, query: Query<Entity, With<crate:
 // ===== SCENE MARKER =====
 
 #[derive(Component, Default, Clone)]
-struct FightScene;
+pub struct FightScene;
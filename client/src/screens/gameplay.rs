@@ -8,13 +8,34 @@ use super::pregame_loading::EnvironmentPreload;
 use crate::assets::{FontAssets, ModelAssets, UiAssets};
 use crate::constants::collectibles::{MAX_COINS, MIN_DISTANCE_BETWEEN_COINS};
 use crate::keybinding;
+use crate::rendering::graphics::ShadowCaster;
 use crate::systems::character_controller::{
     CharacterController, CharacterControllerBundle, CharacterControllerPlugin, setup_idle_animation,
 };
 use crate::systems::book_interaction::BookInteractionPlugin;
+use crate::systems::boss::BossPlugin;
+use crate::constants::player::{BASE_MAX_HEALTH, XP_PER_LEVEL};
+use crate::game::Health;
+use crate::systems::combat::CombatPlugin;
+use crate::systems::death::{DeathPlugin, DeathSequence};
+use crate::systems::enemy_ai::EnemyAIPlugin;
+use crate::systems::equipment::{EquipmentPlugin, Stats};
+use crate::systems::interaction::InteractionPlugin;
+use crate::systems::inventory::InventoryPlugin;
+use crate::systems::items::{ActiveBuffs, ItemsPlugin};
+use crate::systems::loot::LootPlugin;
+use crate::systems::progression::{Experience, ProgressionPlugin};
+use crate::systems::perception::PerceptionPlugin;
+use crate::systems::projectiles::ProjectilesPlugin;
+use crate::systems::sacrifice::SacrificePlugin;
+use crate::systems::save::SavePlugin;
+use crate::systems::steering::SteeringPlugin;
+use crate::systems::turn_combat::TurnCombatPlugin;
+use crate::systems::waves::WavesPlugin;
 use crate::systems::collectibles::{CollectiblesPlugin, NavigationBasedSpawner, CollectibleSpawner, CoinStreamingManager};
 use crate::systems::objectives::ObjectivesPlugin;
 use crate::ui::dialog::DialogPlugin;
+use crate::ui::hud::{HudPlugin, spawn_notification_feed};
 use crate::ui::inventory::spawn_inventory_ui;
 use crate::ui::styles::ElysiumDescentColorPalette;
 use crate::ui::widgets::{HudPosition, player_hud_widget};
@@ -36,7 +57,8 @@ pub(super) fn plugin(app: &mut App) {
     .add_systems(
         Update,
         (
-            camera_follow_player,
+            // The death sequence takes over the camera while the player is dead
+            camera_follow_player.run_if(not(resource_exists::<DeathSequence>)),
             // Fallback systems that run if preloaded entities weren't found
             fallback_spawn_environment,
             fallback_spawn_collectibles,
@@ -50,9 +72,27 @@ pub(super) fn plugin(app: &mut App) {
     .add_plugins(CharacterControllerPlugin)
     .add_plugins(GltfAnimationPlugin)
     .add_plugins(CollectiblesPlugin)
+    .add_plugins(InventoryPlugin)
+    .add_plugins(ItemsPlugin)
+    .add_plugins(EquipmentPlugin)
+    .add_plugins(CombatPlugin)
+    .add_plugins(DeathPlugin)
+    .add_plugins(SavePlugin)
+    .add_plugins(TurnCombatPlugin)
+    .add_plugins(EnemyAIPlugin)
+    .add_plugins(PerceptionPlugin)
+    .add_plugins(SteeringPlugin)
+    .add_plugins(ProjectilesPlugin)
+    .add_plugins(BossPlugin)
+    .add_plugins(WavesPlugin)
+    .add_plugins(LootPlugin)
+    .add_plugins(ProgressionPlugin)
     .add_plugins(ObjectivesPlugin)
     .add_plugins(DialogPlugin)
+    .add_plugins(HudPlugin)
+    .add_plugins(InteractionPlugin)
     .add_plugins(BookInteractionPlugin)
+    .add_plugins(SacrificePlugin)
     .add_plugins(crate::systems::boundary::BoundaryPlugin);
 }
 
@@ -122,12 +162,12 @@ fn spawn_player_hud(
     font_assets: &Res<FontAssets>,
     ui_assets: &Res<UiAssets>,
 ) {
-    // Example values, replace with actual player data
+    // Level, health and xp are kept live by `HudPlugin`
     let avatar = ui_assets.player_avatar.clone();
     let name = "0XJEHU";
-    let level = 2;
-    let health = (105, 115);
-    let xp = (80, 100);
+    let level = 1;
+    let health = (BASE_MAX_HEALTH, BASE_MAX_HEALTH);
+    let xp = (0, XP_PER_LEVEL);
     let font = font_assets.rajdhani_bold.clone();
 
     commands.spawn((
@@ -233,7 +273,6 @@ impl PlayingScene {
         assets: Res<ModelAssets>,
         font_assets: Res<FontAssets>,
         ui_assets: Res<UiAssets>,
-        windows: Query<&Window>,
     ) {
     
 
@@ -251,6 +290,7 @@ impl PlayingScene {
                 std::f32::consts::FRAC_PI_4,
                 0.0,
             )),
+            ShadowCaster,
             PlayingScene,
         ));
 
@@ -316,6 +356,13 @@ impl PlayingScene {
                 Restitution::new(0.0),
                 GravityScale(1.0),
                 CollisionEventsEnabled, // Enable collision events for coin collection
+                Health {
+                    current: BASE_MAX_HEALTH,
+                    max: BASE_MAX_HEALTH,
+                },
+                Stats::default(),
+                Experience::default(),
+                ActiveBuffs::default(),
                 Actions::<keybinding::Player>::default(),
                 PlayingScene,
             ))
@@ -335,24 +382,9 @@ impl PlayingScene {
 
         spawn_inventory_ui::<PlayingScene>(&mut commands);
         spawn_player_hud(&mut commands, &font_assets, &ui_assets);
+        spawn_notification_feed::<PlayingScene>(&mut commands);
         spawn_objectives_ui(&mut commands, &font_assets, &ui_assets);
         crate::ui::modal::spawn_objectives_modal(&mut commands, &font_assets, &ui_assets);
-        
-        // Spawn the 'Press E to Open' dialog for Mystery Boxes
-        use crate::ui::dialog::{spawn_dialog, DialogConfig, DialogPosition};
-        spawn_dialog(
-            &mut commands,
-            &font_assets,
-            windows,
-            DialogConfig {
-                text: "Press E to Open".to_string(),
-                position: DialogPosition::BottomCenter { bottom_margin: 4.0 },
-                ..Default::default()
-            },
-            PlayingScene,
-        );
-
-    
     }
 }
 
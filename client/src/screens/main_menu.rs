@@ -3,11 +3,17 @@ use bevy_lunex::*;
 
 use super::{Screen, despawn_scene};
 use crate::assets::{FontAssets, UiAssets};
+use crate::systems::save::{ActiveSaveSlot, PendingLoad, fresh_slot, latest_slot, read_all_slots};
+use crate::ui::focus::{Activate, Focusable, Focused, Unfocused};
+use crate::ui::saves::{LoadMenuUI, spawn_load_menu};
 use crate::ui::styles::ElysiumDescentColorPalette;
 
 pub fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::MainMenu), (reset_ui_camera, spawn).chain())
-        .add_systems(OnExit(Screen::MainMenu), despawn_scene::<MainMenuScene>);
+        .add_systems(
+            OnExit(Screen::MainMenu),
+            (despawn_scene::<MainMenuScene>, despawn_scene::<LoadMenuUI>),
+        );
 }
 
 #[derive(Component)]
@@ -82,13 +88,14 @@ fn spawn(mut commands: Commands, ui_assets: Res<UiAssets>, font_assets: Res<Font
                         let gap = 3.0;
                         let size = 14.0;
                         let mut offset = 0.0;
-                        for button in ["Continue", "New Game", "Load Game", "Settings", "Credits", "Quit Game"] {
+                        for (index, button) in ["Continue", "New Game", "Load Game", "Settings", "Credits", "Quit Game"].into_iter().enumerate() {
 
                             // Spawn the button
                             let mut button_entity = ui.spawn((
                                 Name::new(button),
                                 UiLayout::window().y(Rl(offset)).size(Rl((100.0, size))).pack(),
                                 OnHoverSetCursor::new(bevy::window::SystemCursorIcon::Pointer),
+                                Focusable::new(index as i32, 0),
                             ));
                             button_entity.with_children(|ui| {
                                 // Spawn the image
@@ -159,36 +166,53 @@ fn spawn(mut commands: Commands, ui_assets: Res<UiAssets>, font_assets: Res<Font
                                 });
 
                             // Enable the transition on hover
-                            }).observe(hover_set::<Pointer<Over>, true>).observe(hover_set::<Pointer<Out>, false>);
+                            }).observe(hover_set::<Pointer<Over>, true>).observe(hover_set::<Pointer<Out>, false>)
+                            // Keyboard and gamepad focus plays the same transition
+                            .observe(hover_set::<Focused, true>).observe(hover_set::<Unfocused, false>);
 
                             // Assign a functionality to the buttons
                             match button {
                                 "New Game" => {
-                                    button_entity.observe(|_: Trigger<Pointer<Click>>, mut next: ResMut<NextState<Screen>>| {
-                                        // Change the state to settings
+                                    button_entity.observe(|_: Trigger<Activate>, mut commands: Commands, mut next: ResMut<NextState<Screen>>| {
+                                        // Start fresh in an empty slot, or overwrite the oldest
+                                        commands.insert_resource(ActiveSaveSlot(fresh_slot(&read_all_slots())));
+                                        commands.remove_resource::<PendingLoad>();
                                         next.set(Screen::NewGame);
                                     });
                                 },
+                                "Load Game" => {
+                                    button_entity.observe(|_: Trigger<Activate>, mut commands: Commands, font_assets: Res<FontAssets>, menus: Query<(), With<LoadMenuUI>>| {
+                                        if menus.is_empty() {
+                                            spawn_load_menu(&mut commands, font_assets.rajdhani_bold.clone(), read_all_slots());
+                                        }
+                                    });
+                                },
                                 "Settings" => {
-                                    button_entity.observe(|_: Trigger<Pointer<Click>>, mut next: ResMut<NextState<Screen>>| {
+                                    button_entity.observe(|_: Trigger<Activate>, mut next: ResMut<NextState<Screen>>| {
                                         // Change the state to settings
                                         next.set(Screen::Settings);
                                     });
                                 },
                                 "Continue" => {
-                                    button_entity.observe(|_: Trigger<Pointer<Click>>, mut next: ResMut<NextState<Screen>>| {
+                                    button_entity.observe(|_: Trigger<Activate>, mut commands: Commands, mut next: ResMut<NextState<Screen>>| {
+                                        // Resume the most recent save, if there is one
+                                        let slots = read_all_slots();
+                                        if let Some((slot, save)) = latest_slot(&slots) {
+                                            commands.insert_resource(ActiveSaveSlot(slot));
+                                            commands.insert_resource(PendingLoad(save.clone()));
+                                        }
                                         // Change the state to PreGameLoading
                                         next.set(Screen::PreGameLoading);
                                     });
                                 },
                                 "Quit Game" => {
-                                    button_entity.observe(|_: Trigger<Pointer<Click>>, mut exit: EventWriter<AppExit>| {
+                                    button_entity.observe(|_: Trigger<Activate>, mut exit: EventWriter<AppExit>| {
                                         // Close the app
                                         exit.write(AppExit::Success);
                                     });
                                 },
                                 _ => {
-                                    button_entity.observe(|c_trigger: Trigger<Pointer<Click>>, c_button: Query<NameOrEntity, With<UiLayout>>| {
+                                    button_entity.observe(|c_trigger: Trigger<Activate>, c_button: Query<NameOrEntity, With<UiLayout>>| {
                                       info!("Clicked: {}", c_button.get(c_trigger.target()).unwrap());
                                     });
                                 }
@@ -1,12 +1,22 @@
-use bevy::window::WindowResized;
+use bevy::window::{Monitor, PrimaryMonitor, WindowResized};
 use bevy::{prelude::*, sprite::Anchor};
 use bevy_lunex::*;
 
 use super::{MainTrack, Screen};
 use crate::assets::{FontAssets, UiAssets};
 use crate::audio::AudioSettings;
+use crate::rendering::graphics::GraphicsSettings;
+use crate::resources::controls::{
+    BindingSlot, ControlAction, ControlSettings, RESERVED_KEYS, gamepad_label, key_label,
+};
+use crate::resources::settings::SettingChoice;
+use crate::resources::window::{WindowSettings, available_resolutions};
+use crate::ui::focus::{Activate, FocusLock, Focusable, Focused, Unfocused};
 use crate::ui::styles::ElysiumDescentColorPalette;
-use crate::ui::widgets::volume_widget;
+use crate::ui::widgets::{
+    binding_header_widget, binding_row_widget, choice_widget, label_widget, text_button_widget,
+    volume_widget,
+};
 
 #[derive(Component, Clone, Copy, PartialEq, Eq)]
 enum SettingsTab {
@@ -28,6 +38,13 @@ impl Default for SettingsTab {
 #[derive(Resource, Default)]
 struct LastRenderedTab(Option<SettingsTab>);
 
+/// The binding slot waiting for a key or button press, and the outcome of the last rebind
+#[derive(Resource, Default)]
+struct RebindCapture {
+    target: Option<(ControlAction, BindingSlot)>,
+    notice: Option<String>,
+}
+
 #[derive(Component)]
 struct TabContentRoot;
 
@@ -52,7 +69,7 @@ fn update_tab_colors(current: SettingsTab, mut query: Query<(&TabLabel, &mut UiC
 }
 
 fn render_sound_settings(
-    _: Trigger<Pointer<Click>>,
+    _: Trigger<Activate>,
     mut selected_tab: ResMut<SelectedTab>,
     query: Query<(&TabLabel, &mut UiColor)>,
 ) {
@@ -61,7 +78,7 @@ fn render_sound_settings(
 }
 
 fn render_controls_settings(
-    _: Trigger<Pointer<Click>>,
+    _: Trigger<Activate>,
     mut selected_tab: ResMut<SelectedTab>,
     query: Query<(&TabLabel, &mut UiColor)>,
 ) {
@@ -70,7 +87,7 @@ fn render_controls_settings(
 }
 
 fn render_graphics_settings(
-    _: Trigger<Pointer<Click>>,
+    _: Trigger<Activate>,
     mut selected_tab: ResMut<SelectedTab>,
     query: Query<(&TabLabel, &mut UiColor)>,
 ) {
@@ -79,7 +96,7 @@ fn render_graphics_settings(
 }
 
 fn render_windows_settings(
-    _: Trigger<Pointer<Click>>,
+    _: Trigger<Activate>,
     mut selected_tab: ResMut<SelectedTab>,
     query: Query<(&TabLabel, &mut UiColor)>,
 ) {
@@ -87,53 +104,239 @@ fn render_windows_settings(
     update_tab_colors(SettingsTab::Window, query);
 }
 
-fn lower_master_volume(_: Trigger<Pointer<Click>>, mut settings: ResMut<AudioSettings>) {
+fn lower_master_volume(_: Trigger<Activate>, mut settings: ResMut<AudioSettings>) {
     settings.master_volume = (settings.master_volume - 0.1).clamp(0.0, 1.0);
     info!("Lowering master volume");
 }
 
-fn raise_master_volume(_: Trigger<Pointer<Click>>, mut settings: ResMut<AudioSettings>) {
+fn raise_master_volume(_: Trigger<Activate>, mut settings: ResMut<AudioSettings>) {
     settings.master_volume = (settings.master_volume + 0.1).clamp(0.0, 1.0);
     info!("Raise master volume");
 }
 
-fn lower_music_volume(_: Trigger<Pointer<Click>>, mut settings: ResMut<AudioSettings>) {
+fn lower_music_volume(_: Trigger<Activate>, mut settings: ResMut<AudioSettings>) {
     settings.music_volume = (settings.music_volume - 0.1).clamp(0.0, 1.0);
     info!("Lowering music volume");
 }
 
-fn raise_music_volume(_: Trigger<Pointer<Click>>, mut settings: ResMut<AudioSettings>) {
+fn raise_music_volume(_: Trigger<Activate>, mut settings: ResMut<AudioSettings>) {
     settings.music_volume = (settings.music_volume + 0.1).clamp(0.0, 1.0);
     info!("Raise music volume");
 }
 
-fn lower_sfx_volume(_: Trigger<Pointer<Click>>, mut settings: ResMut<AudioSettings>) {
+fn lower_sfx_volume(_: Trigger<Activate>, mut settings: ResMut<AudioSettings>) {
     settings.sfx_volume = (settings.sfx_volume - 0.1).clamp(0.0, 1.0);
     info!("Lowering sfx volume");
 }
 
-fn raise_sfx_volume(_: Trigger<Pointer<Click>>, mut settings: ResMut<AudioSettings>) {
+fn raise_sfx_volume(_: Trigger<Activate>, mut settings: ResMut<AudioSettings>) {
     settings.sfx_volume = (settings.sfx_volume + 0.1).clamp(0.0, 1.0);
     info!("Raise sfx volume");
 }
 
+fn lower_shadow_quality(_: Trigger<Activate>, mut settings: ResMut<GraphicsSettings>) {
+    settings.shadow_quality = settings.shadow_quality.stepped(-1);
+}
+
+fn raise_shadow_quality(_: Trigger<Activate>, mut settings: ResMut<GraphicsSettings>) {
+    settings.shadow_quality = settings.shadow_quality.stepped(1);
+}
+
+fn previous_anti_aliasing(_: Trigger<Activate>, mut settings: ResMut<GraphicsSettings>) {
+    settings.anti_aliasing = settings.anti_aliasing.stepped(-1);
+}
+
+fn next_anti_aliasing(_: Trigger<Activate>, mut settings: ResMut<GraphicsSettings>) {
+    settings.anti_aliasing = settings.anti_aliasing.stepped(1);
+}
+
+fn previous_tonemapping(_: Trigger<Activate>, mut settings: ResMut<GraphicsSettings>) {
+    settings.tonemapping = settings.tonemapping.stepped(-1);
+}
+
+fn next_tonemapping(_: Trigger<Activate>, mut settings: ResMut<GraphicsSettings>) {
+    settings.tonemapping = settings.tonemapping.stepped(1);
+}
+
+fn toggle_hdr(_: Trigger<Activate>, mut settings: ResMut<GraphicsSettings>) {
+    settings.hdr = !settings.hdr;
+}
+
+fn lower_render_scale(_: Trigger<Activate>, mut settings: ResMut<GraphicsSettings>) {
+    settings.step_render_scale(-1);
+}
+
+fn raise_render_scale(_: Trigger<Activate>, mut settings: ResMut<GraphicsSettings>) {
+    settings.step_render_scale(1);
+}
+
+fn lower_ambient_brightness(_: Trigger<Activate>, mut settings: ResMut<GraphicsSettings>) {
+    settings.step_ambient_brightness(-1);
+}
+
+fn raise_ambient_brightness(_: Trigger<Activate>, mut settings: ResMut<GraphicsSettings>) {
+    settings.step_ambient_brightness(1);
+}
+
+fn previous_window_mode(_: Trigger<Activate>, mut settings: ResMut<WindowSettings>) {
+    settings.mode = settings.mode.stepped(-1);
+}
+
+fn next_window_mode(_: Trigger<Activate>, mut settings: ResMut<WindowSettings>) {
+    settings.mode = settings.mode.stepped(1);
+}
+
+fn lower_resolution(
+    _: Trigger<Activate>,
+    mut settings: ResMut<WindowSettings>,
+    monitors: Query<&Monitor, With<PrimaryMonitor>>,
+) {
+    settings.step_resolution(&available_resolutions(monitors.single().ok()), -1);
+}
+
+fn raise_resolution(
+    _: Trigger<Activate>,
+    mut settings: ResMut<WindowSettings>,
+    monitors: Query<&Monitor, With<PrimaryMonitor>>,
+) {
+    settings.step_resolution(&available_resolutions(monitors.single().ok()), 1);
+}
+
+fn toggle_vsync(_: Trigger<Activate>, mut settings: ResMut<WindowSettings>) {
+    settings.vsync = !settings.vsync;
+}
+
+fn lower_frame_cap(_: Trigger<Activate>, mut settings: ResMut<WindowSettings>) {
+    settings.frame_cap = settings.frame_cap.stepped(-1);
+}
+
+fn raise_frame_cap(_: Trigger<Activate>, mut settings: ResMut<WindowSettings>) {
+    settings.frame_cap = settings.frame_cap.stepped(1);
+}
+
+fn lower_ui_scale(_: Trigger<Activate>, mut settings: ResMut<WindowSettings>) {
+    settings.step_ui_scale(-1);
+}
+
+fn raise_ui_scale(_: Trigger<Activate>, mut settings: ResMut<WindowSettings>) {
+    settings.step_ui_scale(1);
+}
+
+fn start_rebind(
+    action: ControlAction,
+    slot: BindingSlot,
+    capture: &mut RebindCapture,
+    focus_lock: &mut FocusLock,
+) {
+    // Clicking the slot being captured again cancels the capture
+    capture.target = if capture.target == Some((action, slot)) {
+        None
+    } else {
+        Some((action, slot))
+    };
+    capture.notice = None;
+    // Menu navigation keys and buttons can be bound too, so they must not move the focus
+    focus_lock.0 = capture.target.is_some();
+}
+
+fn reset_controls(
+    _: Trigger<Activate>,
+    mut controls: ResMut<ControlSettings>,
+    mut capture: ResMut<RebindCapture>,
+    mut focus_lock: ResMut<FocusLock>,
+) {
+    *controls = ControlSettings::default();
+    capture.target = None;
+    focus_lock.0 = false;
+    capture.notice = Some("Controls reset to defaults".to_string());
+}
+
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::Settings), SettingsScene::spawn)
         .add_systems(
             OnExit(Screen::Settings),
-            super::despawn_scene::<SettingsScene>,
+            (super::despawn_scene::<SettingsScene>, cancel_rebind),
         )
         .add_systems(
             Update,
-            render_tab_content.run_if(in_state(Screen::Settings)),
+            (capture_rebind, render_tab_content)
+                .chain()
+                .run_if(in_state(Screen::Settings)),
         )
         .insert_resource(SelectedTab::default())
         .insert_resource(LastRenderedTab::default())
+        .init_resource::<RebindCapture>()
         .init_resource::<MainTrack>();
 }
 
 // ===== SYSTEMS =====
 
+/// Binds the next key or gamepad button pressed to the slot being captured.
+/// Escape stays reserved for leaving the menu, and Backspace clears the slot.
+fn capture_rebind(
+    mut capture: ResMut<RebindCapture>,
+    mut controls: ResMut<ControlSettings>,
+    mut focus_lock: ResMut<FocusLock>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+) {
+    // Skip the frame the capture started, so the press that started it is not captured
+    if capture.is_changed() {
+        return;
+    }
+    let Some((action, slot)) = capture.target else {
+        return;
+    };
+
+    let displaced = if keyboard.just_pressed(KeyCode::Backspace) {
+        controls.clear(action, slot);
+        None
+    } else {
+        match slot {
+            BindingSlot::Key(index) => {
+                let Some(&key) = keyboard
+                    .get_just_pressed()
+                    .find(|&&key| key != KeyCode::Escape)
+                else {
+                    return;
+                };
+                // Keep waiting for a usable key
+                if RESERVED_KEYS.contains(&key) {
+                    capture.notice = Some(format!(
+                        "{} is reserved for Alt+Enter fullscreen",
+                        key_label(key)
+                    ));
+                    return;
+                }
+                controls
+                    .assign_key(action, index, key)
+                    .map(|other| (key_label(key), other))
+            }
+            BindingSlot::Gamepad => {
+                let Some(&button) = gamepads
+                    .iter()
+                    .find_map(|gamepad| gamepad.get_just_pressed().next())
+                else {
+                    return;
+                };
+                controls
+                    .assign_gamepad(action, button)
+                    .map(|other| (gamepad_label(button), other))
+            }
+        }
+    };
+
+    capture.target = None;
+    focus_lock.0 = false;
+    capture.notice =
+        displaced.map(|(input, other)| format!("{} was unbound from {}", input, other.label()));
+}
+
+fn cancel_rebind(mut capture: ResMut<RebindCapture>, mut focus_lock: ResMut<FocusLock>) {
+    *capture = RebindCapture::default();
+    focus_lock.0 = false;
+}
+
 fn render_tab_content(
     selected_tab: Res<SelectedTab>,
     mut last_rendered: ResMut<LastRenderedTab>,
@@ -145,6 +348,10 @@ fn render_tab_content(
     mut resize_events: EventReader<WindowResized>,
     last_height: Local<Option<f32>>,
     audio_settings: Res<AudioSettings>,
+    graphics_settings: Res<GraphicsSettings>,
+    window_settings: Res<WindowSettings>,
+    controls: Res<ControlSettings>,
+    capture: Res<RebindCapture>,
 ) {
     let window = windows.single().unwrap();
     let window_height = window.height();
@@ -154,7 +361,11 @@ fn render_tab_content(
 
     let tab_changed = last_rendered.0 != Some(selected_tab.0);
 
-    let settings_changed = audio_settings.is_changed();
+    let settings_changed = audio_settings.is_changed()
+        || graphics_settings.is_changed()
+        || window_settings.is_changed()
+        || controls.is_changed()
+        || capture.is_changed();
 
     if tab_changed || resized || settings_changed {
         last_rendered.0 = Some(selected_tab.0);
@@ -235,9 +446,238 @@ fn render_tab_content(
                                         );
                                     });
                             }
-                            SettingsTab::Controls => {}
-                            SettingsTab::Graphics => {}
-                            SettingsTab::Window => {}
+                            SettingsTab::Controls => {
+                                content
+                                    .spawn((Node {
+                                        position_type: PositionType::Absolute,
+                                        width: Val::Percent(100.0),
+                                        height: Val::Percent(100.0),
+                                        ..default()
+                                    },))
+                                    .with_children(|content| {
+                                        let font = font_assets.rajdhani_medium.clone();
+                                        binding_header_widget(
+                                            content,
+                                            window_height,
+                                            font.clone(),
+                                            ["ACTION", "KEY", "ALT KEY", "GAMEPAD"],
+                                            1.0,
+                                        );
+                                        let conflicts = controls.conflicts();
+                                        for (row, &action) in ControlAction::ALL.iter().enumerate()
+                                        {
+                                            let inputs = controls.inputs(action);
+                                            let slot_label =
+                                                |slot: BindingSlot, bound: Option<String>| {
+                                                    if capture.target == Some((action, slot)) {
+                                                        "PRESS...".to_string()
+                                                    } else {
+                                                        bound.unwrap_or_else(|| "-".to_string())
+                                                    }
+                                                };
+                                            binding_row_widget(
+                                                content,
+                                                window_height,
+                                                font.clone(),
+                                                action.label(),
+                                                [
+                                                    slot_label(
+                                                        BindingSlot::Key(0),
+                                                        inputs.keys[0].map(key_label),
+                                                    ),
+                                                    slot_label(
+                                                        BindingSlot::Key(1),
+                                                        inputs.keys[1].map(key_label),
+                                                    ),
+                                                    slot_label(
+                                                        BindingSlot::Gamepad,
+                                                        inputs.gamepad.map(gamepad_label),
+                                                    ),
+                                                ],
+                                                6.0 + row as f32 * 5.3,
+                                                conflicts.contains(&action),
+                                                move |column| {
+                                                    let slot = match column {
+                                                        0 | 1 => BindingSlot::Key(column),
+                                                        _ => BindingSlot::Gamepad,
+                                                    };
+                                                    move |_: Trigger<Activate>,
+                                                          mut capture: ResMut<RebindCapture>,
+                                                          mut focus_lock: ResMut<FocusLock>| {
+                                                        start_rebind(
+                                                            action,
+                                                            slot,
+                                                            &mut capture,
+                                                            &mut focus_lock,
+                                                        );
+                                                    }
+                                                },
+                                            );
+                                        }
+                                        text_button_widget(
+                                            content,
+                                            window_height,
+                                            font.clone(),
+                                            "RESET TO DEFAULTS",
+                                            87.0,
+                                            reset_controls,
+                                        );
+                                        if let Some(notice) = &capture.notice {
+                                            content.spawn((
+                                                Node {
+                                                    position_type: PositionType::Absolute,
+                                                    width: Val::Percent(100.0),
+                                                    top: Val::Percent(93.5),
+                                                    ..default()
+                                                },
+                                                Pickable::IGNORE,
+                                                children![label_widget(
+                                                    window_height * 0.022,
+                                                    font,
+                                                    notice.clone(),
+                                                )],
+                                            ));
+                                        }
+                                    });
+                            }
+                            SettingsTab::Graphics => {
+                                content
+                                    .spawn((Node {
+                                        position_type: PositionType::Absolute,
+                                        width: Val::Percent(100.0),
+                                        height: Val::Percent(100.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },))
+                                    .with_children(|content| {
+                                        let font = font_assets.rajdhani_medium.clone();
+                                        choice_widget(
+                                            content,
+                                            window_height,
+                                            font.clone(),
+                                            "Shadows",
+                                            graphics_settings.shadow_quality.label(),
+                                            0.0,
+                                            lower_shadow_quality,
+                                            raise_shadow_quality,
+                                        );
+                                        choice_widget(
+                                            content,
+                                            window_height,
+                                            font.clone(),
+                                            "Anti-Aliasing",
+                                            graphics_settings.anti_aliasing.label(),
+                                            14.0,
+                                            previous_anti_aliasing,
+                                            next_anti_aliasing,
+                                        );
+                                        choice_widget(
+                                            content,
+                                            window_height,
+                                            font.clone(),
+                                            "Tonemapping",
+                                            graphics_settings.tonemapping.label(),
+                                            28.0,
+                                            previous_tonemapping,
+                                            next_tonemapping,
+                                        );
+                                        choice_widget(
+                                            content,
+                                            window_height,
+                                            font.clone(),
+                                            "HDR",
+                                            if graphics_settings.hdr { "ON" } else { "OFF" },
+                                            42.0,
+                                            toggle_hdr,
+                                            toggle_hdr,
+                                        );
+                                        choice_widget(
+                                            content,
+                                            window_height,
+                                            font.clone(),
+                                            "Render Scale",
+                                            graphics_settings.render_scale_label(),
+                                            56.0,
+                                            lower_render_scale,
+                                            raise_render_scale,
+                                        );
+                                        choice_widget(
+                                            content,
+                                            window_height,
+                                            font,
+                                            "Ambient Light",
+                                            graphics_settings.ambient_brightness_label(),
+                                            70.0,
+                                            lower_ambient_brightness,
+                                            raise_ambient_brightness,
+                                        );
+                                    });
+                            }
+                            SettingsTab::Window => {
+                                content
+                                    .spawn((Node {
+                                        position_type: PositionType::Absolute,
+                                        width: Val::Percent(100.0),
+                                        height: Val::Percent(100.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },))
+                                    .with_children(|content| {
+                                        let font = font_assets.rajdhani_medium.clone();
+                                        choice_widget(
+                                            content,
+                                            window_height,
+                                            font.clone(),
+                                            "Mode",
+                                            window_settings.mode.label(),
+                                            0.0,
+                                            previous_window_mode,
+                                            next_window_mode,
+                                        );
+                                        choice_widget(
+                                            content,
+                                            window_height,
+                                            font.clone(),
+                                            "Resolution",
+                                            window_settings.resolution_label(),
+                                            14.0,
+                                            lower_resolution,
+                                            raise_resolution,
+                                        );
+                                        choice_widget(
+                                            content,
+                                            window_height,
+                                            font.clone(),
+                                            "VSync",
+                                            if window_settings.vsync { "ON" } else { "OFF" },
+                                            28.0,
+                                            toggle_vsync,
+                                            toggle_vsync,
+                                        );
+                                        choice_widget(
+                                            content,
+                                            window_height,
+                                            font.clone(),
+                                            "Frame Cap",
+                                            window_settings.frame_cap.label(),
+                                            42.0,
+                                            lower_frame_cap,
+                                            raise_frame_cap,
+                                        );
+                                        choice_widget(
+                                            content,
+                                            window_height,
+                                            font,
+                                            "UI Scale",
+                                            window_settings.ui_scale_label(),
+                                            56.0,
+                                            lower_ui_scale,
+                                            raise_ui_scale,
+                                        );
+                                    });
+                            }
                         }
                     });
             });
@@ -348,6 +788,8 @@ impl SettingsScene {
                                             .x(Rl(pos * i as f32))
                                             .size(Rl((pos, 100.0)))
                                             .pack(),
+                                        // The tabs sit on a row above all tab content
+                                        Focusable::new(-1, i as i32),
                                     ))
                                     .with_children(|ui| {
                                         // Spawn the background
@@ -414,6 +856,8 @@ impl SettingsScene {
                                     })
                                     .observe(hover_set::<Pointer<Over>, true>)
                                     .observe(hover_set::<Pointer<Out>, false>)
+                                    .observe(hover_set::<Focused, true>)
+                                    .observe(hover_set::<Unfocused, false>)
                                     .observe(categories_actions[i]);
                                 }
                             });
@@ -1,7 +1,10 @@
 use bevy::prelude::*;
 use avian3d::prelude::*;
 use crate::assets::ModelAssets;
-use crate::ui::dialog::{spawn_dialog_with_proximity, DialogConfig, DialogPosition, DialogProximity};
+use crate::constants::interaction::{BOOK_INTERACT_RADIUS, BOOK_POSITION};
+use crate::screens::Screen;
+use crate::systems::interaction::{Interactable, Interacted, InteractionRequirement};
+use crate::systems::sacrifice::SacrificeMenu;
 
 // ===== COMPONENTS =====
 
@@ -18,36 +21,22 @@ pub fn spawn_book(
         Name::new("Book"),
         SceneRoot(assets.book.clone()),
         Transform {
-            translation: Vec3::new(90.0, 22.0, -54.0),
+            translation: Vec3::from_array(BOOK_POSITION),
             scale: Vec3::splat(1.0),
             ..default()
         },
         Collider::cuboid(1.0, 1.0, 1.0), // Add collision box
         RigidBody::Static, // Make it static so it doesn't move
+        Interactable::new("book", "to Burn Items to Enter Realm", BOOK_INTERACT_RADIUS)
+            .with_requirement(InteractionRequirement::LevelExitUnlocked),
         Book,
-    ));
+    ))
+    .observe(open_sacrifice_menu);
 }
 
-pub fn spawn_book_modal(
-    mut commands: Commands,
-    font_assets: Res<crate::assets::FontAssets>,
-    windows: Query<&Window>,
-) {
-    spawn_dialog_with_proximity(
-        &mut commands,
-        &font_assets,
-        windows,
-        DialogConfig {
-            text: "Press E to Burn Items to Enter Realm".to_string(),
-            position: DialogPosition::BottomCenter { bottom_margin: 4.0 },
-            ..Default::default()
-        },
-        crate::screens::gameplay::PlayingScene,
-        Some(DialogProximity {
-            target_position: Vec3::new(90.0, 22.0, -54.0),
-            proximity_threshold: 5.0,
-        }),
-    );
+/// The realm only opens after items are burned in the sacrifice menu
+fn open_sacrifice_menu(_trigger: Trigger<Interacted>, mut menu: ResMut<SacrificeMenu>) {
+    menu.open = true;
 }
 
 // ===== PLUGIN =====
@@ -56,7 +45,6 @@ pub struct BookInteractionPlugin;
 
 impl Plugin for BookInteractionPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(crate::screens::Screen::GamePlay), spawn_book)
-            .add_systems(OnEnter(crate::screens::Screen::GamePlay), spawn_book_modal);
+        app.add_systems(OnEnter(Screen::GamePlay), spawn_book);
     }
-} 
\ No newline at end of file
+}
@@ -0,0 +1,320 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::combat::{BOSS_ADD_SPAWN_RADIUS, TELEGRAPH_GROUND_OFFSET};
+use crate::game::Health;
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::combat::{DamageEvent, Defeated};
+use crate::systems::enemy_ai::EnemyAI;
+use crate::systems::enemy_archetypes::Bestiary;
+use crate::ui::hud::{HudNotificationEvent, NotificationKind};
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== PHASE DATA =====
+
+/// One stage of a boss fight, authored on the boss archetype
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BossPhase {
+    pub name: String,
+    /// Entered once the boss's health fraction drops to this or below
+    pub health_threshold: f32,
+    #[serde(default = "default_speed_multiplier")]
+    pub speed_multiplier: f32,
+    #[serde(default)]
+    pub aoe: Option<AoeAttack>,
+    /// Enemies summoned when the phase begins
+    #[serde(default)]
+    pub adds: Vec<AddSpawn>,
+}
+
+fn default_speed_multiplier() -> f32 {
+    1.0
+}
+
+/// A ground slam aimed at the player's position, telegraphed before it lands
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AoeAttack {
+    pub radius: f32,
+    pub damage: u32,
+    /// Seconds between the telegraph appearing and the hit
+    pub windup: f32,
+    /// Seconds between two slams
+    pub interval: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AddSpawn {
+    pub archetype: String,
+    pub count: u32,
+}
+
+// ===== COMPONENTS =====
+
+/// Phase machine of a boss enemy, driven by its health
+#[derive(Component, Debug, Clone)]
+pub struct Boss {
+    pub title: String,
+    /// Ordered from the first phase to the last
+    pub phases: Vec<BossPhase>,
+    pub phase: usize,
+    /// Seconds until the next AoE slam of the current phase
+    pub aoe_cooldown: f32,
+    base_speed: f32,
+}
+
+impl Boss {
+    pub fn new(title: impl Into<String>, mut phases: Vec<BossPhase>, base_speed: f32) -> Self {
+        phases.sort_by(|a, b| b.health_threshold.total_cmp(&a.health_threshold));
+        let aoe_cooldown = phases.first().and_then(|phase| phase.aoe).map_or(0.0, |aoe| aoe.interval);
+        Self {
+            title: title.into(),
+            phases,
+            phase: 0,
+            aoe_cooldown,
+            base_speed,
+        }
+    }
+
+    /// The last phase whose threshold the health fraction has crossed
+    pub fn phase_for(&self, health_fraction: f32) -> usize {
+        self.phases
+            .iter()
+            .rposition(|phase| health_fraction <= phase.health_threshold)
+            .unwrap_or(0)
+    }
+
+    pub fn current(&self) -> Option<&BossPhase> {
+        self.phases.get(self.phase)
+    }
+
+    /// Moves to the phase for this health fraction; phases never go back
+    pub fn advance(&mut self, health_fraction: f32) -> Option<&BossPhase> {
+        let next = self.phase_for(health_fraction);
+        if next <= self.phase {
+            return None;
+        }
+        self.phase = next;
+        self.aoe_cooldown = self.phases[next].aoe.map_or(0.0, |aoe| aoe.interval);
+        self.current()
+    }
+}
+
+/// A pending AoE hit, drawn on the ground until it lands
+#[derive(Component)]
+pub struct Telegraph {
+    pub radius: f32,
+    pub damage: u32,
+    pub windup: f32,
+    pub remaining: f32,
+    pub source: Entity,
+}
+
+// ===== EVENTS =====
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct BossPhaseChangedEvent {
+    pub boss: Entity,
+    pub phase: usize,
+}
+
+// ===== PLUGIN =====
+
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<BossPhaseChangedEvent>()
+            .add_systems(
+                Update,
+                advance_boss_phases
+                    .run_if(in_state(Screen::GamePlay).or(in_state(Screen::FightScene))),
+            )
+            .add_systems(
+                Update,
+                // The realm's boss fight is turn-based, so slams and adds are real-time only
+                (spawn_boss_adds, telegraph_boss_slams, resolve_telegraphs)
+                    .after(advance_boss_phases)
+                    .run_if(in_state(Screen::GamePlay)),
+            )
+            .add_systems(Update, crate::ui::boss::sync_boss_bar);
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn advance_boss_phases(
+    mut bosses: Query<(Entity, &mut Boss, &Health, &mut EnemyAI), Without<Defeated>>,
+    mut phase_events: EventWriter<BossPhaseChangedEvent>,
+    mut notifications: EventWriter<HudNotificationEvent>,
+) {
+    for (entity, mut boss, health, mut ai) in &mut bosses {
+        let fraction = health.current as f32 / health.max.max(1) as f32;
+        let Some(phase) = boss.advance(fraction).cloned() else {
+            continue;
+        };
+
+        ai.move_speed = boss.base_speed * phase.speed_multiplier;
+        notifications.write(HudNotificationEvent::new(
+            format!("{}: {}", boss.title, phase.name),
+            NotificationKind::Warning,
+        ));
+        phase_events.write(BossPhaseChangedEvent {
+            boss: entity,
+            phase: boss.phase,
+        });
+    }
+}
+
+/// Summons the adds of a phase in a ring around the boss
+fn spawn_boss_adds(
+    mut commands: Commands,
+    mut phase_events: EventReader<BossPhaseChangedEvent>,
+    bosses: Query<(&Boss, &Transform)>,
+    bestiary: Bestiary,
+) {
+    for event in phase_events.read() {
+        let Ok((boss, boss_transform)) = bosses.get(event.boss) else {
+            continue;
+        };
+        let Some(phase) = boss.phases.get(event.phase) else {
+            continue;
+        };
+
+        let total: u32 = phase.adds.iter().map(|add| add.count).sum();
+        let archetypes = phase
+            .adds
+            .iter()
+            .flat_map(|add| std::iter::repeat_n(add.archetype.as_str(), add.count as usize));
+        for (index, archetype) in archetypes.enumerate() {
+            let angle = std::f32::consts::TAU * index as f32 / total as f32;
+            let offset = Vec3::new(angle.cos(), 0.0, angle.sin()) * BOSS_ADD_SPAWN_RADIUS;
+            let transform = Transform::from_translation(boss_transform.translation + offset)
+                .with_rotation(boss_transform.rotation);
+            if let Some(mut add) = bestiary.spawn(&mut commands, archetype, transform, 1.0, 1) {
+                add.insert(PlayingScene);
+            }
+        }
+    }
+}
+
+/// Starts a slam under the player whenever a boss's phase calls for one
+fn telegraph_boss_slams(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut bosses: Query<(Entity, &mut Boss), Without<Defeated>>,
+    player_query: Query<&Transform, With<CharacterController>>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+
+    for (entity, mut boss) in &mut bosses {
+        let Some(aoe) = boss.current().and_then(|phase| phase.aoe) else {
+            continue;
+        };
+        boss.aoe_cooldown -= time.delta_secs();
+        if boss.aoe_cooldown > 0.0 {
+            continue;
+        }
+        boss.aoe_cooldown = aoe.interval;
+
+        commands.spawn((
+            Name::new("Boss Telegraph"),
+            Telegraph {
+                radius: aoe.radius,
+                damage: aoe.damage,
+                windup: aoe.windup,
+                remaining: aoe.windup,
+                source: entity,
+            },
+            Transform::from_translation(player_transform.translation + Vec3::Y * TELEGRAPH_GROUND_OFFSET),
+            PlayingScene,
+        ));
+    }
+}
+
+/// Draws pending slams as a filling ring and lands them once their windup ends
+fn resolve_telegraphs(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+    mut telegraphs: Query<(Entity, &Transform, &mut Telegraph)>,
+    player_query: Query<(Entity, &Transform), With<CharacterController>>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    for (entity, transform, mut telegraph) in &mut telegraphs {
+        telegraph.remaining -= time.delta_secs();
+        if telegraph.remaining > 0.0 {
+            // Circles are drawn in the XY plane, so lay them flat on the ground
+            let isometry = Isometry3d::new(
+                transform.translation,
+                Quat::from_rotation_x(std::f32::consts::FRAC_PI_2),
+            );
+            let progress = 1.0 - telegraph.remaining / telegraph.windup.max(f32::EPSILON);
+            gizmos.circle(isometry, telegraph.radius, Color::DANGER_RED);
+            gizmos.circle(
+                isometry,
+                telegraph.radius * progress,
+                Color::WARNING_ORANGE.with_alpha(0.8),
+            );
+            continue;
+        }
+
+        if let Ok((player, player_transform)) = player_query.single() {
+            let offset = (player_transform.translation - transform.translation).with_y(0.0);
+            if offset.length() <= telegraph.radius {
+                damage_events.write(DamageEvent {
+                    target: player,
+                    amount: telegraph.damage,
+                    source: Some(telegraph.source),
+                });
+            }
+        }
+        commands.entity(entity).despawn();
+    }
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phase(name: &str, health_threshold: f32) -> BossPhase {
+        BossPhase {
+            name: name.to_string(),
+            health_threshold,
+            speed_multiplier: 1.0,
+            aoe: None,
+            adds: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn phases_follow_health_thresholds_in_any_authored_order() {
+        let boss = Boss::new(
+            "Warden",
+            vec![phase("last", 0.3), phase("first", 1.0), phase("second", 0.6)],
+            2.0,
+        );
+        assert_eq!(boss.phase_for(1.0), 0);
+        assert_eq!(boss.phase_for(0.6), 1);
+        assert_eq!(boss.phase_for(0.1), 2);
+        assert_eq!(boss.phases[2].name, "last");
+    }
+
+    #[test]
+    fn phases_only_advance_and_can_be_skipped() {
+        let mut boss = Boss::new(
+            "Warden",
+            vec![phase("first", 1.0), phase("second", 0.6), phase("last", 0.3)],
+            2.0,
+        );
+        assert!(boss.advance(0.9).is_none());
+        assert_eq!(boss.advance(0.2).map(|phase| phase.name.as_str()), Some("last"));
+        assert!(boss.advance(0.5).is_none());
+        assert_eq!(boss.phase, 2);
+    }
+}
@@ -3,12 +3,15 @@ use avian3d::{math::*, prelude::*};
 use bevy::prelude::*;
 use bevy_gltf_animation::prelude::*;
 use crate::systems::boundary::BoundaryConstraint;
+use crate::systems::death::Dead;
+use crate::systems::equipment::Stats;
 
 pub struct CharacterControllerPlugin;
 
 impl Plugin for CharacterControllerPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<LastInputDirection>()
+            .init_resource::<MovementInputActive>()
             .init_resource::<JumpCooldown>()
             .add_event::<MovementAction>()
             .add_systems(
@@ -79,6 +82,10 @@ impl Default for MovementBundle {
 #[derive(Resource, Default, Debug, Clone, Copy)]
 pub struct LastInputDirection(pub Vec2);
 
+/// Whether the Move action gave any input this frame, from whichever key or stick is bound
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct MovementInputActive(pub bool);
+
 /// Add a resource to track jump cooldown
 #[derive(Resource, Debug)]
 pub struct JumpCooldown {
@@ -95,41 +102,43 @@ impl Default for JumpCooldown {
     }
 }
 
-const MOVEMENT_KEYS: [KeyCode; 8] = [
-    KeyCode::KeyW,
-    KeyCode::KeyA,
-    KeyCode::KeyS,
-    KeyCode::KeyD,
-    KeyCode::ArrowUp,
-    KeyCode::ArrowDown,
-    KeyCode::ArrowLeft,
-    KeyCode::ArrowRight,
-];
-
 /// Responds to [`MovementAction`] events and moves character controllers accordingly
 fn movement(
     time: Res<Time>,
     mut movement_event_reader: EventReader<MovementAction>,
-    mut controllers: Query<(
-        &JumpImpulse,
-        &mut LinearVelocity,
-        &mut Transform,
-        &mut AnimationState,
-    )>,
+    mut controllers: Query<
+        (
+            &JumpImpulse,
+            Option<&Stats>,
+            &mut LinearVelocity,
+            &mut Transform,
+            &mut AnimationState,
+        ),
+        Without<Dead>,
+    >,
     mut jump_cooldown: ResMut<JumpCooldown>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    mut movement_input: ResMut<MovementInputActive>,
     boundary_constraint: Option<Res<BoundaryConstraint>>,
 ) {
     let delta_time = time.delta_secs();
     jump_cooldown.last_jump_time += delta_time;
 
-    // Check if any movement keys are pressed
-    let is_movement_pressed = keyboard.any_pressed(MOVEMENT_KEYS);
+    // Move events are only sent while the Move action is held
+    let events: Vec<&MovementAction> = movement_event_reader.read().collect();
+    movement_input.0 = events
+        .iter()
+        .any(|event| matches!(event, MovementAction::Move(_)));
+    let is_movement_pressed = movement_input.0;
 
-    for event in movement_event_reader.read() {
-        for (jump_impulse, mut linear_velocity, mut transform, mut animation_state) in
+    for event in events {
+        for (jump_impulse, stats, mut linear_velocity, mut transform, mut animation_state) in
             &mut controllers
         {
+            // Equipment modifiers apply on top of the controller's base values
+            let speed_multiplier = stats.map_or(1.0, |stats| stats.move_speed);
+            let jump_velocity =
+                (jump_impulse.0 + stats.map_or(0.0, |stats| stats.jump_bonus)).max(0.0);
+
             match event {
                 MovementAction::Move(direction) => {
                     // Smooth rotation
@@ -149,7 +158,7 @@ fn movement(
                         CharacterMovementConfig::MAX_RUN_SPEED * direction.length()
                     } else {
                         CharacterMovementConfig::MAX_SPEED * direction.length()
-                    };
+                    } * speed_multiplier;
                     let current_speed = Vec2::new(linear_velocity.x, linear_velocity.z).length();
 
                     // Smooth acceleration/deceleration
@@ -209,7 +218,7 @@ fn movement(
                 }
                 MovementAction::Jump => {
                     if jump_cooldown.last_jump_time >= jump_cooldown.cooldown_duration {
-                        linear_velocity.y = jump_impulse.0;
+                        linear_velocity.y = jump_velocity;
                         jump_cooldown.last_jump_time = 0.0;
                     }
                 }
@@ -225,9 +234,9 @@ fn movement(
         }
     }
 
-    // If no movement keys are pressed, immediately stop movement
+    // If there is no movement input, immediately stop movement
     if !is_movement_pressed {
-        for (_, mut linear_velocity, _, mut animation_state) in &mut controllers {
+        for (_, _, mut linear_velocity, _, mut animation_state) in &mut controllers {
             // Immediately stop horizontal movement
             linear_velocity.x = 0.0;
             linear_velocity.z = 0.0;
@@ -241,13 +250,12 @@ fn movement(
 /// Applies movement damping and prevents unwanted climbing
 fn apply_movement_damping(
     mut query: Query<(&mut LinearVelocity, &AnimationState, &Transform), With<CharacterController>>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    movement_input: Res<MovementInputActive>,
 ) {
-    // Check if any movement keys are pressed
-    let is_movement_pressed = keyboard.any_pressed(MOVEMENT_KEYS);
+    let is_movement_pressed = movement_input.0;
 
     for (mut linear_velocity, animation_state, _transform) in &mut query {
-        // If no movement keys are pressed, immediately stop horizontal movement
+        // If there is no movement input, immediately stop horizontal movement
         if !is_movement_pressed {
             linear_velocity.x = 0.0;
             linear_velocity.z = 0.0;
@@ -303,13 +311,12 @@ pub struct AnimationState {
 fn update_animations(
     mut query: Query<
         (&LinearVelocity, &mut GltfAnimations, &mut AnimationState),
-        Without<crate::systems::enemy_ai::Enemy>,
+        (Without<crate::systems::enemy_ai::Enemy>, Without<Dead>),
     >,
     mut animation_players: Query<&mut AnimationPlayer>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    movement_input: Res<MovementInputActive>,
 ) {
-    // Check if any movement keys are pressed
-    let is_movement_pressed = keyboard.any_pressed(MOVEMENT_KEYS);
+    let is_movement_pressed = movement_input.0;
 
     for (velocity, mut animations, mut animation_state) in &mut query {
         let horizontal_velocity = Vec2::new(velocity.x, velocity.z);
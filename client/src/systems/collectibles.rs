@@ -7,6 +7,7 @@ use crate::constants::collectibles::COIN_STREAMING_RADIUS;
 use crate::screens::Screen;
 use crate::systems::character_controller::CharacterController;
 use crate::systems::dojo::PickupItemEvent;
+use crate::systems::inventory::InventoryEvent;
 use crate::assets::ModelAssets;
 use crate::resources::audio::{PlaySfxEvent, SfxType};
 
@@ -32,7 +33,7 @@ pub struct FloatingItem {
     pub hover_speed: f32,
 }
 
-#[derive(Component, Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
 pub enum CollectibleType {
     Coin,
     HealthPotion,
@@ -40,10 +41,16 @@ pub enum CollectibleType {
     Book,
 }
 
-#[derive(Resource)]
-pub struct NextItemToAdd(pub CollectibleType);
-
-
+impl CollectibleType {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            CollectibleType::Coin => "Coin",
+            CollectibleType::HealthPotion => "Health Potion",
+            CollectibleType::SurvivalKit => "Survival Kit",
+            CollectibleType::Book => "Ancient Book",
+        }
+    }
+}
 
 #[derive(Resource)]
 pub struct CollectibleSpawner {
@@ -140,16 +147,22 @@ impl Plugin for CollectiblesPlugin {
                 Update,
                 (
                     update_coin_streaming,            // Stream coins every 2-3 seconds
-                    handle_coin_collisions,           // Handle collision-based coin collection
-                    update_floating_items,
-                    rotate_collectibles,
 
-                    crate::ui::inventory::add_item_to_inventory,
                     crate::ui::inventory::toggle_inventory_visibility,
                     crate::ui::inventory::adjust_inventory_for_dialogs,
                     track_player_movement,
                 )
                     .run_if(in_state(Screen::GamePlay)),
+            )
+            // Loot drops can also be picked up in the fight scene
+            .add_systems(
+                Update,
+                (
+                    handle_collectible_collisions,    // Handle collision-based collection
+                    update_floating_items,
+                    rotate_collectibles,
+                )
+                    .run_if(in_state(Screen::GamePlay).or(in_state(Screen::FightScene))),
             );
     }
 }
@@ -297,13 +310,14 @@ fn spawn_streaming_coin(
     )).id()
 }
 
-/// System that handles coin collection through collision events
-fn handle_coin_collisions(
+/// System that handles collectible pickup through collision events
+fn handle_collectible_collisions(
     mut commands: Commands,
     mut collision_events: EventReader<CollisionStarted>,
     player_query: Query<Entity, With<CharacterController>>,
-    coin_query: Query<(Entity, &CollectibleType, Option<&StreamingCoin>), (With<Collectible>, Without<Collected>)>,
+    collectible_query: Query<(Entity, &CollectibleType, Option<&StreamingCoin>), (With<Collectible>, Without<Collected>)>,
     mut pickup_events: EventWriter<PickupItemEvent>,
+    mut inventory_events: EventWriter<InventoryEvent>,
     mut streaming_manager: ResMut<CoinStreamingManager>,
     mut sfx_events: EventWriter<PlaySfxEvent>,
 ) {
@@ -323,34 +337,32 @@ fn handle_coin_collisions(
             continue; // Neither entity is the player
         };
 
-        // Check if the collectible is a coin
-        if let Ok((entity, collectible_type, streaming_coin)) = coin_query.get(collectible_entity) {
-            if *collectible_type == CollectibleType::Coin {
-                // Remove from streaming manager if it's a streaming coin
-                if let Some(streaming) = streaming_coin {
-                    streaming_manager.spawned_coins.remove(&streaming.position_id);
-                    streaming_manager.collected_positions.insert(streaming.position_id);
-                }
-
-                // Play coin collection sound effect
-                sfx_events.write(PlaySfxEvent {
-                    sfx_type: SfxType::CoinCollect,
-                });
-
-                // Mark as collected
-                commands.entity(entity).insert(Collected);
-                // Insert NextItemToAdd so inventory system will add it
-                commands.insert_resource(NextItemToAdd(*collectible_type));
-                // Despawn the entity immediately
-                commands.entity(entity).despawn();
-                // Trigger blockchain event
-                pickup_events.write(PickupItemEvent {
-                    item_type: *collectible_type,
-                    item_entity: entity,
-                });
-
-
+        if let Ok((entity, collectible_type, streaming_coin)) = collectible_query.get(collectible_entity) {
+            // Remove from streaming manager if it's a streaming coin
+            if let Some(streaming) = streaming_coin {
+                streaming_manager.spawned_coins.remove(&streaming.position_id);
+                streaming_manager.collected_positions.insert(streaming.position_id);
             }
+
+            // Play collection sound effect
+            sfx_events.write(PlaySfxEvent {
+                sfx_type: SfxType::CoinCollect,
+            });
+
+            // Mark as collected
+            commands.entity(entity).insert(Collected);
+            // Queue the item for the inventory
+            inventory_events.write(InventoryEvent::Add {
+                item_type: *collectible_type,
+                count: 1,
+            });
+            // Despawn the entity immediately
+            commands.entity(entity).despawn();
+            // Trigger blockchain event
+            pickup_events.write(PickupItemEvent {
+                item_type: *collectible_type,
+                item_entity: entity,
+            });
         }
     }
 }
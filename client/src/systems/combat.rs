@@ -0,0 +1,135 @@
+use bevy::prelude::*;
+
+use crate::constants::combat::{
+    HEAVY_ATTACK_MULTIPLIER, MELEE_ARC_DOT, MELEE_COOLDOWN, MELEE_RANGE,
+};
+use crate::game::Health;
+use crate::screens::Screen;
+use crate::systems::character_controller::{CharacterController, MovementAction};
+use crate::systems::death::Dead;
+use crate::systems::enemy_ai::{Enemy, EnemyKind};
+use crate::systems::equipment::Stats;
+
+// ===== EVENTS =====
+
+/// Request to subtract health from an entity
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DamageEvent {
+    pub target: Entity,
+    pub amount: u32,
+    pub source: Option<Entity>,
+}
+
+/// Emitted once when an enemy's health reaches zero. The enemy entity stays
+/// alive until `PostUpdate`, so readers in `Update` can still query it.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct EnemyDefeatedEvent {
+    pub entity: Entity,
+    pub kind: EnemyKind,
+    pub position: Vec3,
+}
+
+// ===== COMPONENTS =====
+
+/// Marks an enemy whose health reached zero; it is despawned in `PostUpdate`
+#[derive(Component)]
+pub struct Defeated;
+
+// ===== PLUGIN =====
+
+pub struct CombatPlugin;
+
+impl Plugin for CombatPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DamageEvent>()
+            .add_event::<EnemyDefeatedEvent>()
+            .add_systems(
+                Update,
+                (
+                    // The boss fight is turn-based, see `turn_combat`
+                    player_melee_attack.run_if(not(in_state(Screen::FightScene))),
+                    apply_damage,
+                )
+                    .chain(),
+            )
+            .add_systems(PostUpdate, despawn_defeated);
+    }
+}
+
+// ===== SYSTEMS =====
+
+/// Turns the player's fight moves into hits on every enemy in front of them
+fn player_melee_attack(
+    time: Res<Time>,
+    mut movement_events: EventReader<MovementAction>,
+    player_query: Query<(Entity, &Transform, &Stats), (With<CharacterController>, Without<Dead>)>,
+    enemy_query: Query<(Entity, &Transform), (With<Enemy>, With<Health>, Without<Defeated>)>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut last_swing: Local<Option<f32>>,
+) {
+    let now = time.elapsed_secs();
+    for event in movement_events.read() {
+        let multiplier = match event {
+            MovementAction::FightMove1 => 1.0,
+            MovementAction::FightMove2 => HEAVY_ATTACK_MULTIPLIER,
+            _ => continue,
+        };
+
+        if last_swing.is_some_and(|last| now - last < MELEE_COOLDOWN) {
+            continue;
+        }
+        let Ok((player, player_transform, stats)) = player_query.single() else {
+            continue;
+        };
+        *last_swing = Some(now);
+
+        // The character model faces its local +Z (the camera sits behind it on -Z)
+        let facing = (player_transform.rotation * Vec3::Z).with_y(0.0).normalize_or_zero();
+        let amount = (stats.damage * multiplier).round() as u32;
+
+        for (enemy, enemy_transform) in &enemy_query {
+            let offset = (enemy_transform.translation - player_transform.translation).with_y(0.0);
+            let in_front = offset.normalize_or_zero().dot(facing) >= MELEE_ARC_DOT;
+            if offset.length() <= MELEE_RANGE && in_front {
+                damage_events.write(DamageEvent {
+                    target: enemy,
+                    amount,
+                    source: Some(player),
+                });
+            }
+        }
+    }
+}
+
+pub fn apply_damage(
+    mut commands: Commands,
+    mut damage_events: EventReader<DamageEvent>,
+    mut targets: Query<(&mut Health, &GlobalTransform, Option<&EnemyKind>)>,
+    mut defeated_events: EventWriter<EnemyDefeatedEvent>,
+) {
+    for event in damage_events.read() {
+        let Ok((mut health, transform, kind)) = targets.get_mut(event.target) else {
+            continue;
+        };
+        // Several hits in one frame must not defeat the same enemy twice
+        if health.current == 0 {
+            continue;
+        }
+
+        health.current = health.current.saturating_sub(event.amount);
+        if let (0, Some(&kind)) = (health.current, kind) {
+            defeated_events.write(EnemyDefeatedEvent {
+                entity: event.target,
+                kind,
+                position: transform.translation(),
+            });
+            commands.entity(event.target).insert(Defeated);
+        }
+    }
+}
+
+fn despawn_defeated(mut commands: Commands, query: Query<Entity, With<Defeated>>) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
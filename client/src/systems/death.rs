@@ -0,0 +1,282 @@
+use avian3d::prelude::LinearVelocity;
+use bevy::prelude::*;
+use bevy_gltf_animation::prelude::GltfAnimations;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::player::{
+    DEATH_CAMERA_DISTANCE, DEATH_CAMERA_HEIGHT, DEATH_CAMERA_ORBIT_SPEED, DEATH_FALL_DURATION,
+    RESPAWN_SAFE_RADIUS, SCORE_PER_KILL,
+};
+use crate::game::Health;
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::boss::Boss;
+use crate::systems::character_controller::{AnimationState, CharacterController};
+use crate::systems::collectibles::CollectibleType;
+use crate::systems::combat::{EnemyDefeatedEvent, apply_damage};
+use crate::systems::enemy_ai::Enemy;
+use crate::systems::inventory::InventoryEvent;
+use crate::systems::objectives::ObjectiveEvent;
+use crate::ui::death::DeathScreenUI;
+use crate::ui::hud::{HudNotificationEvent, NotificationKind};
+
+// ===== COMPONENTS =====
+
+/// Marks the player from the moment their health reaches zero until they respawn
+#[derive(Component)]
+pub struct Dead;
+
+// ===== RESOURCES =====
+
+/// Tally of the current run, shown on the death screen and sent to the chain
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RunStats {
+    /// Seconds played, not counting time spent dead
+    pub elapsed: f32,
+    pub coins: u32,
+    pub kills: u32,
+    pub deaths: u32,
+}
+
+impl RunStats {
+    pub fn score(&self) -> u32 {
+        self.coins + self.kills * SCORE_PER_KILL
+    }
+}
+
+/// Where the player comes back after dying: the start of the run, or the last
+/// objective location they reached
+#[derive(Resource, Default, Debug)]
+pub struct Checkpoint(pub Option<Vec3>);
+
+/// Progress of the death sequence, present from the player's death until they respawn
+#[derive(Resource, Debug)]
+pub struct DeathSequence {
+    pub elapsed: f32,
+    pub position: Vec3,
+    /// Rotation the body falls from, restored on respawn
+    pub upright: Quat,
+    pub summary_shown: bool,
+}
+
+// ===== EVENTS =====
+
+/// The player's health reached zero
+#[derive(Event, Debug, Clone, Copy)]
+pub struct PlayerDiedEvent;
+
+/// Brings the dead player back at the checkpoint
+#[derive(Event, Debug, Clone, Copy)]
+pub struct RespawnEvent;
+
+/// The run is over, either restarted or abandoned for the main menu
+#[derive(Event, Debug, Clone, Copy)]
+pub struct RunEndedEvent {
+    pub stats: RunStats,
+}
+
+// ===== PLUGIN =====
+
+pub struct DeathPlugin;
+
+impl Plugin for DeathPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunStats>()
+            .init_resource::<Checkpoint>()
+            .add_event::<PlayerDiedEvent>()
+            .add_event::<RespawnEvent>()
+            .add_event::<RunEndedEvent>()
+            .add_systems(OnEnter(Screen::PreGameLoading), reset_run)
+            .add_systems(OnExit(Screen::GamePlay), end_death_sequence)
+            .add_systems(
+                Update,
+                track_run_stats.run_if(in_state(Screen::GamePlay).or(in_state(Screen::FightScene))),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_checkpoint,
+                    detect_player_death.after(apply_damage),
+                    play_death_sequence.run_if(resource_exists::<DeathSequence>),
+                    crate::ui::death::show_death_screen.run_if(resource_exists::<DeathSequence>),
+                    respawn_player,
+                )
+                    .chain()
+                    .run_if(in_state(Screen::GamePlay)),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn reset_run(mut stats: ResMut<RunStats>, mut checkpoint: ResMut<Checkpoint>) {
+    *stats = RunStats::default();
+    *checkpoint = Checkpoint::default();
+}
+
+fn end_death_sequence(mut commands: Commands) {
+    commands.remove_resource::<DeathSequence>();
+}
+
+fn track_run_stats(
+    time: Res<Time>,
+    mut stats: ResMut<RunStats>,
+    death: Option<Res<DeathSequence>>,
+    mut inventory_events: EventReader<InventoryEvent>,
+    mut kills: EventReader<EnemyDefeatedEvent>,
+) {
+    if death.is_none() {
+        stats.elapsed += time.delta_secs();
+    }
+    for event in inventory_events.read() {
+        match event {
+            InventoryEvent::Add {
+                item_type: CollectibleType::Coin,
+                count,
+            } => stats.coins += count,
+            InventoryEvent::AddGold { amount } => stats.coins += amount,
+            _ => {}
+        }
+    }
+    stats.kills += kills.read().count() as u32;
+}
+
+/// The run starts with a checkpoint where the player spawns; every objective
+/// location reached afterwards moves it
+pub fn update_checkpoint(
+    mut checkpoint: ResMut<Checkpoint>,
+    mut objective_events: EventReader<ObjectiveEvent>,
+    player_query: Query<&Transform, (With<CharacterController>, Without<Dead>)>,
+    mut notifications: EventWriter<HudNotificationEvent>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    if checkpoint.0.is_none() {
+        checkpoint.0 = Some(player_transform.translation);
+    }
+
+    let reached = objective_events
+        .read()
+        .any(|event| matches!(event, ObjectiveEvent::Reached { .. }));
+    if reached {
+        checkpoint.0 = Some(player_transform.translation);
+        notifications.write(HudNotificationEvent::new("Checkpoint reached", NotificationKind::Info));
+    }
+}
+
+fn detect_player_death(
+    mut commands: Commands,
+    mut player_query: Query<
+        (Entity, &Health, &Transform, &mut AnimationState, Option<&GltfAnimations>),
+        (With<CharacterController>, Without<Dead>),
+    >,
+    mut animation_players: Query<&mut AnimationPlayer>,
+    mut stats: ResMut<RunStats>,
+    mut died_events: EventWriter<PlayerDiedEvent>,
+) {
+    let Ok((player, health, transform, mut animation_state, animations)) = player_query.single_mut() else {
+        return;
+    };
+    if health.current > 0 {
+        return;
+    }
+
+    // Freeze the current pose; the body falls over in `play_death_sequence`
+    if let Some(mut animation_player) =
+        animations.and_then(|animations| animation_players.get_mut(animations.animation_player).ok())
+    {
+        animation_player.stop_all();
+    }
+    animation_state.current_animation = 0;
+    animation_state.fight_move_1 = false;
+    animation_state.fight_move_2 = false;
+
+    stats.deaths += 1;
+    commands.entity(player).insert(Dead);
+    commands.insert_resource(DeathSequence {
+        elapsed: 0.0,
+        position: transform.translation,
+        upright: transform.rotation,
+        summary_shown: false,
+    });
+    died_events.write(PlayerDiedEvent);
+}
+
+/// Topples the body and circles the camera up above it
+fn play_death_sequence(
+    time: Res<Time>,
+    mut death: ResMut<DeathSequence>,
+    mut player_query: Query<(&mut Transform, &mut LinearVelocity), (With<CharacterController>, With<Dead>)>,
+    mut camera_query: Query<
+        &mut Transform,
+        (With<Camera3d>, With<PlayingScene>, Without<CharacterController>),
+    >,
+) {
+    death.elapsed += time.delta_secs();
+
+    if let Ok((mut transform, mut velocity)) = player_query.single_mut() {
+        let fall = (death.elapsed / DEATH_FALL_DURATION).min(1.0);
+        // Ease out so the body hits the ground rather than tipping at a constant rate
+        let fall = 1.0 - (1.0 - fall) * (1.0 - fall);
+        transform.rotation = death.upright * Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2 * fall);
+        velocity.x = 0.0;
+        velocity.z = 0.0;
+    }
+
+    let angle = death.elapsed * DEATH_CAMERA_ORBIT_SPEED;
+    let orbit = Vec3::new(angle.cos(), 0.0, angle.sin()) * DEATH_CAMERA_DISTANCE;
+    let target = death.position + orbit + Vec3::Y * DEATH_CAMERA_HEIGHT;
+    for mut camera_transform in &mut camera_query {
+        camera_transform.translation = camera_transform
+            .translation
+            .lerp(target, (2.0 * time.delta_secs()).min(1.0));
+        camera_transform.look_at(death.position, Vec3::Y);
+    }
+}
+
+/// Brings the player back at full health at the checkpoint, clearing the enemies waiting there
+fn respawn_player(
+    mut commands: Commands,
+    mut respawn_events: EventReader<RespawnEvent>,
+    death: Option<Res<DeathSequence>>,
+    checkpoint: Res<Checkpoint>,
+    mut player_query: Query<
+        (Entity, &mut Health, &mut Transform, &mut LinearVelocity),
+        (With<CharacterController>, With<Dead>),
+    >,
+    enemies: Query<(Entity, &Transform), (With<Enemy>, Without<Boss>, Without<CharacterController>)>,
+    screens: Query<Entity, With<DeathScreenUI>>,
+    mut notifications: EventWriter<HudNotificationEvent>,
+) {
+    if respawn_events.read().count() == 0 {
+        return;
+    }
+    let Some(death) = death else {
+        return;
+    };
+    let Ok((player, mut health, mut transform, mut velocity)) = player_query.single_mut() else {
+        return;
+    };
+
+    let position = checkpoint.0.unwrap_or(death.position);
+    health.current = health.max;
+    transform.translation = position;
+    transform.rotation = death.upright;
+    velocity.0 = Vec3::ZERO;
+    commands.entity(player).remove::<Dead>();
+
+    for (enemy, enemy_transform) in &enemies {
+        if enemy_transform.translation.distance(position) <= RESPAWN_SAFE_RADIUS {
+            commands.entity(enemy).despawn();
+        }
+    }
+    for screen in &screens {
+        commands.entity(screen).despawn();
+    }
+    commands.remove_resource::<DeathSequence>();
+    notifications.write(HudNotificationEvent::new(
+        "You rise again at the checkpoint",
+        NotificationKind::Info,
+    ));
+}
@@ -228,8 +228,8 @@ fn handle_dojo_entity_updates(
                     game_data_events.write(GameDataReceivedEvent { game: game_entity });
                 }
                 "PlayerStats" => {
+                    // Decoded by `player_stats::decode_player_stats_updates`
                     info!("Received PlayerStats update");
-                    // TODO: Handle player stats updates
                 }
                 "PlayerInventory" => {
                     info!("Received PlayerInventory update");
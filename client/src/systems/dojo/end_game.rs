@@ -0,0 +1,48 @@
+use crate::constants::dojo::END_GAME_SELECTOR;
+use crate::systems::death::RunEndedEvent;
+use bevy::prelude::*;
+use dojo_bevy_plugin::{DojoResource, TokioRuntime};
+use starknet::core::types::{Call, Felt};
+
+use super::create_game::GameState;
+
+#[allow(dead_code)]
+pub(super) fn plugin(app: &mut App) {
+    // The run ends while leaving gameplay, so this runs regardless of the screen
+    app.add_systems(Update, handle_run_ended_events);
+}
+
+/// System to mark the game as over on chain with its final score, then forget it
+/// so the next run creates a new game. Calldata is `(game_id, score)`.
+#[allow(dead_code)]
+fn handle_run_ended_events(
+    mut events: EventReader<RunEndedEvent>,
+    mut dojo: ResMut<DojoResource>,
+    tokio: Res<TokioRuntime>,
+    dojo_config: Res<super::DojoSystemState>,
+    mut game_state: ResMut<GameState>,
+) {
+    for event in events.read() {
+        let Some(game_id) = game_state.current_game_id.take() else {
+            continue;
+        };
+        game_state.is_creating_game = false;
+
+        if !dojo_config.account_connected {
+            info!("Run over without an account connection, game {} stays open on chain", game_id);
+            continue;
+        }
+
+        info!(
+            "Ending game {} on blockchain with score {}...",
+            game_id,
+            event.stats.score()
+        );
+        let call = Call {
+            to: dojo_config.config.action_address,
+            selector: END_GAME_SELECTOR,
+            calldata: vec![Felt::from(game_id), Felt::from(event.stats.score())],
+        };
+        dojo.queue_tx(&tokio, vec![call]);
+    }
+}
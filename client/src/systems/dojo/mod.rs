@@ -1,12 +1,19 @@
 use crate::constants::dojo::DojoConfig;
 use bevy::prelude::*;
 use dojo_bevy_plugin::{DojoResource, TokioRuntime};
+use futures::FutureExt;
+use tokio::task::JoinHandle;
 
 pub mod create_game;
+pub mod end_game;
 pub mod pickup_item;
+pub mod player_stats;
+pub mod sacrifice;
+pub mod use_item;
 
 pub use create_game::CreateGameEvent;
 pub use pickup_item::PickupItemEvent;
+pub use player_stats::PlayerStatsUpdatedEvent;
 
 /// Resource to track overall Dojo system state
 #[derive(Resource, Debug, Default)]
@@ -29,7 +36,14 @@ pub fn plugin(app: &mut App) {
             Update,
             log_dojo_status.run_if(resource_changed::<DojoSystemState>),
         )
-        .add_plugins((create_game::plugin, pickup_item::plugin));
+        .add_plugins((
+            create_game::plugin,
+            end_game::plugin,
+            pickup_item::plugin,
+            player_stats::plugin,
+            sacrifice::plugin,
+            use_item::plugin,
+        ));
 }
 
 #[allow(dead_code)]
@@ -90,6 +104,33 @@ fn handle_dojo_setup(
     }
 }
 
+/// Polls transactions sent in the background and logs the finished ones. Each task
+/// returns a description of the sent transaction, or of why it failed.
+#[allow(dead_code)]
+pub(crate) fn poll_tx_tasks(
+    tasks: &mut Vec<JoinHandle<Result<String, String>>>,
+    entrypoint: &str,
+) {
+    tasks.retain_mut(|handle| {
+        if let Some(result) = handle.now_or_never() {
+            match result {
+                Ok(Ok(outcome)) => {
+                    info!("Blockchain {} tx completed: {}", entrypoint, outcome);
+                }
+                Ok(Err(err)) => {
+                    error!("Blockchain {} tx failed: {}", entrypoint, err);
+                }
+                Err(join_err) => {
+                    error!("JoinHandle error in {} task: {:?}", entrypoint, join_err);
+                }
+            }
+            false // Remove finished handle
+        } else {
+            true // Keep unfinished handle
+        }
+    });
+}
+
 /// System to log Dojo status changes for user feedback
 #[allow(dead_code)]
 fn log_dojo_status(dojo_state: Res<DojoSystemState>) {
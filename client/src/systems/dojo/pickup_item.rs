@@ -6,9 +6,11 @@ use dojo_bevy_plugin::TokioRuntime;
 use dojo_bevy_plugin::{DojoEntityUpdated, DojoResource};
 use futures::FutureExt;
 use starknet::accounts::Account;
-use starknet::core::types::Call;
+use starknet::core::types::{Call, Felt};
 use tokio::task::JoinHandle;
 
+use super::use_item::item_type_felt;
+
 /// Event to trigger item pickup on the blockchain
 #[derive(Event, Debug)]
 #[allow(dead_code)]
@@ -82,13 +84,20 @@ fn handle_pickup_item_events(
     dojo_config: Res<super::DojoSystemState>,
     tokio: Res<TokioRuntime>,
     mut pending_tasks: ResMut<PendingPickupTasks>,
+    game_state: Res<super::create_game::GameState>,
 ) {
     let account = dojo.sn.account.clone();
     for event in events.read() {
+        // Coins only exist in the client, and pickups before the game is created stay local
+        let (Some(game_id), Some(item_felt)) =
+            (game_state.current_game_id, item_type_felt(event.item_type))
+        else {
+            continue;
+        };
         let call = Call {
             to: dojo_config.config.action_address,
             selector: PICKUP_ITEM_SELECTOR,
-            calldata: vec![],
+            calldata: vec![Felt::from(game_id), item_felt],
         };
         let entity = event.item_entity;
         let item_type = event.item_type;
@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use dojo_bevy_plugin::DojoEntityUpdated;
+
+use super::create_game::GameState;
+
+/// Decoded `PlayerStats` model received from Torii
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerStatsUpdatedEvent {
+    pub health: u32,
+    pub max_health: u32,
+    pub level: u32,
+    pub experience: u32,
+    pub items_collected: u32,
+}
+
+#[allow(dead_code)]
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Update, decode_player_stats_updates);
+}
+
+/// System to decode `PlayerStats` models belonging to the local player
+#[allow(dead_code)]
+fn decode_player_stats_updates(
+    mut dojo_events: EventReader<DojoEntityUpdated>,
+    mut stats_events: EventWriter<PlayerStatsUpdatedEvent>,
+    game_state: Res<GameState>,
+) {
+    for event in dojo_events.read() {
+        for model in event.models.iter().filter(|model| model.name == "PlayerStats") {
+            let member_u32 = |name: &str| {
+                model
+                    .children
+                    .iter()
+                    .find(|member| member.name == name)
+                    .and_then(|member| member.ty.as_primitive())
+                    .and_then(|primitive| primitive.as_u32())
+            };
+
+            // Torii streams every player's stats; only keep ours
+            let player = model
+                .children
+                .iter()
+                .find(|member| member.name == "player")
+                .and_then(|member| member.ty.as_primitive())
+                .and_then(|primitive| primitive.as_contract_address());
+            if let (Some(ours), Some(player)) = (&game_state.player_address, player) {
+                if !ours.eq_ignore_ascii_case(&format!("{:#x}", player)) {
+                    continue;
+                }
+            }
+
+            let (
+                Some(health),
+                Some(max_health),
+                Some(level),
+                Some(experience),
+                Some(items_collected),
+            ) = (
+                member_u32("health"),
+                member_u32("max_health"),
+                member_u32("level"),
+                member_u32("experience"),
+                member_u32("items_collected"),
+            )
+            else {
+                warn!("Could not decode PlayerStats model: {:?}", model);
+                continue;
+            };
+
+            stats_events.write(PlayerStatsUpdatedEvent {
+                health,
+                max_health,
+                level,
+                experience,
+                items_collected,
+            });
+        }
+    }
+}
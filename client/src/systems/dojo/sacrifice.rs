@@ -0,0 +1,73 @@
+use crate::constants::dojo::SACRIFICE_ITEMS_SELECTOR;
+use crate::systems::sacrifice::ItemsSacrificedEvent;
+use bevy::prelude::*;
+use dojo_bevy_plugin::{DojoResource, TokioRuntime};
+use starknet::accounts::Account;
+use starknet::core::types::{Call, Felt};
+use tokio::task::JoinHandle;
+
+use super::use_item::item_type_felt;
+
+/// Resource to track sacrifice transactions still in flight
+#[derive(Resource, Default)]
+pub struct PendingSacrificeTasks(#[allow(dead_code)] pub Vec<JoinHandle<Result<String, String>>>);
+
+#[allow(dead_code)]
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<PendingSacrificeTasks>()
+        .add_systems(Update, (burn_items_on_chain, poll_sacrifice_tasks));
+}
+
+/// System to send a `sacrifice_items` call for every offering burned locally.
+/// Calldata is the item array: its length, then `(item_type, count)` pairs.
+#[allow(dead_code)]
+fn burn_items_on_chain(
+    mut events: EventReader<ItemsSacrificedEvent>,
+    dojo: Res<DojoResource>,
+    dojo_config: Res<super::DojoSystemState>,
+    tokio: Res<TokioRuntime>,
+    mut pending_tasks: ResMut<PendingSacrificeTasks>,
+) {
+    let account = dojo.sn.account.clone();
+    for event in events.read() {
+        // Items that only exist on the client (coins) are burned locally only
+        let items: Vec<(Felt, u32)> = event
+            .items
+            .iter()
+            .filter_map(|stack| item_type_felt(stack.item_type).map(|felt| (felt, stack.count)))
+            .collect();
+        if items.is_empty() {
+            continue;
+        }
+
+        let mut calldata = vec![Felt::from(items.len() as u32)];
+        for (item_felt, count) in items {
+            calldata.push(item_felt);
+            calldata.push(Felt::from(count));
+        }
+
+        let call = Call {
+            to: dojo_config.config.action_address,
+            selector: SACRIFICE_ITEMS_SELECTOR,
+            calldata,
+        };
+        let account = account.clone();
+        let handle = tokio.runtime.spawn(async move {
+            if let Some(account) = account {
+                let tx = account.execute_v3(vec![call]);
+                match tx.send().await {
+                    Ok(result) => Ok(format!("{:#x}", result.transaction_hash)),
+                    Err(e) => Err(format!("{:?}", e)),
+                }
+            } else {
+                Err("No account available".to_string())
+            }
+        });
+        pending_tasks.0.push(handle);
+    }
+}
+
+#[allow(dead_code)]
+fn poll_sacrifice_tasks(mut pending_tasks: ResMut<PendingSacrificeTasks>) {
+    super::poll_tx_tasks(&mut pending_tasks.0, "sacrifice_items");
+}
@@ -0,0 +1,75 @@
+use crate::constants::dojo::USE_ITEM_SELECTOR;
+use crate::systems::collectibles::CollectibleType;
+use crate::systems::items::ItemUsedEvent;
+use bevy::prelude::*;
+use dojo_bevy_plugin::{DojoResource, TokioRuntime};
+use starknet::accounts::Account;
+use starknet::core::types::{Call, Felt};
+use tokio::task::JoinHandle;
+
+/// Resource to track use-item transactions still in flight
+#[derive(Resource, Default)]
+pub struct PendingUseItemTasks(#[allow(dead_code)] pub Vec<JoinHandle<Result<String, String>>>);
+
+#[allow(dead_code)]
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<PendingUseItemTasks>()
+        .add_systems(Update, (mirror_item_use_on_chain, poll_use_item_tasks));
+}
+
+/// Maps a collectible to the contract's `ItemType` calldata, if it exists on chain.
+/// Cairo serializes enums by variant index, not by their `Into<felt252>` value.
+#[allow(dead_code)]
+pub(super) fn item_type_felt(item_type: CollectibleType) -> Option<Felt> {
+    match item_type {
+        CollectibleType::HealthPotion => Some(Felt::from(0u8)),
+        CollectibleType::SurvivalKit => Some(Felt::from(1u8)),
+        CollectibleType::Book => Some(Felt::from(2u8)),
+        CollectibleType::Coin => None,
+    }
+}
+
+/// System to send a `use_item` call for every item consumed locally
+#[allow(dead_code)]
+fn mirror_item_use_on_chain(
+    mut events: EventReader<ItemUsedEvent>,
+    dojo: Res<DojoResource>,
+    dojo_config: Res<super::DojoSystemState>,
+    tokio: Res<TokioRuntime>,
+    mut pending_tasks: ResMut<PendingUseItemTasks>,
+) {
+    let account = dojo.sn.account.clone();
+    for event in events.read() {
+        let item_type = event.item_type;
+        let Some(item_felt) = item_type_felt(item_type) else {
+            continue;
+        };
+
+        let call = Call {
+            to: dojo_config.config.action_address,
+            selector: USE_ITEM_SELECTOR,
+            calldata: vec![item_felt],
+        };
+        let account = account.clone();
+        let handle = tokio.runtime.spawn(async move {
+            if let Some(account) = account {
+                let tx = account.execute_v3(vec![call]);
+                match tx.send().await {
+                    Ok(result) => Ok(format!(
+                        "{:#x} for {:?}",
+                        result.transaction_hash, item_type
+                    )),
+                    Err(e) => Err(format!("{:?} for {:?}", e, item_type)),
+                }
+            } else {
+                Err(format!("No account available for {:?}", item_type))
+            }
+        });
+        pending_tasks.0.push(handle);
+    }
+}
+
+#[allow(dead_code)]
+fn poll_use_item_tasks(mut pending_tasks: ResMut<PendingUseItemTasks>) {
+    super::poll_tx_tasks(&mut pending_tasks.0, "use_item");
+}
@@ -3,25 +3,89 @@ use bevy_gltf_animation::prelude::*;
 use avian3d::{math::*, prelude::*};
 use crate::systems::character_controller::AnimationState;
 use crate::systems::boundary::BoundaryConstraint;
+use crate::constants::combat::{
+    CHARGE_DISTANCE, CHARGE_SPEED_MULTIPLIER, ENEMY_ATTACK_COOLDOWN, ENEMY_ATTACK_DAMAGE,
+    ENEMY_BASE_HEALTH, PROJECTILE_AIM_HEIGHT, PROJECTILE_MUZZLE_HEIGHT, RANGED_RETREAT_FRACTION,
+};
+use crate::constants::perception::LAST_KNOWN_REACHED_DISTANCE;
+use crate::constants::steering::{SEPARATION_WEIGHT, SLOT_ARRIVAL_DISTANCE};
+use crate::game::Health;
+use crate::screens::Screen;
+use crate::resources::audio::{PlaySfxEvent, SfxType};
+use crate::systems::combat::{DamageEvent, Defeated};
+use crate::systems::perception::{Perception, update_perception};
+use crate::systems::projectiles::{PassThrough, ProjectileVisuals, has_line_of_sight, spawn_projectile};
+use crate::systems::steering::{NotAnObstacle, Steering, avoid_obstacles, separate_crowd};
 
 /// Marker component for enemy entities
 #[derive(Component)]
 pub struct Enemy;
 
+/// Which kind of enemy an entity is, so objectives can ask for specific kills
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnemyKind {
+    #[default]
+    Creature,
+    Boss,
+}
+
+/// How an enemy closes in on the player
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnemyBehaviour {
+    /// Walks up to the player and fights at arm's length
+    #[default]
+    Melee,
+    /// Attacks from its range and backs off when the player gets close
+    Ranged,
+    /// Rushes the last stretch towards the player
+    Charger,
+    /// Slow and sturdy; walks up like a melee enemy but never gives ground
+    Tank,
+}
+
+impl EnemyBehaviour {
+    /// Speed along the direction to the player: positive closes in, negative backs off
+    pub fn desired_speed(self, distance: f32, attack_range: f32, move_speed: f32) -> f32 {
+        if distance > attack_range {
+            let charging = self == EnemyBehaviour::Charger && distance <= attack_range + CHARGE_DISTANCE;
+            if charging {
+                move_speed * CHARGE_SPEED_MULTIPLIER
+            } else {
+                move_speed
+            }
+        } else if self == EnemyBehaviour::Ranged && distance < attack_range * RANGED_RETREAT_FRACTION {
+            -move_speed
+        } else {
+            0.0
+        }
+    }
+}
+
 /// Component to track enemy AI state
 #[derive(Component)]
 pub struct EnemyAI {
+    pub behaviour: EnemyBehaviour,
     pub attack_range: f32,
     pub move_speed: f32,
     pub is_moving: bool,
+    pub attack_damage: u32,
+    /// Seconds between two hits, and the time left until the next one
+    pub attack_cooldown: f32,
+    pub cooldown_remaining: f32,
 }
 
 impl Default for EnemyAI {
     fn default() -> Self {
         Self {
+            behaviour: EnemyBehaviour::default(),
             attack_range: 3.0,
             move_speed: 3.0,
             is_moving: false,
+            attack_damage: ENEMY_ATTACK_DAMAGE,
+            attack_cooldown: ENEMY_ATTACK_COOLDOWN,
+            cooldown_remaining: 0.0,
         }
     }
 }
@@ -30,7 +94,11 @@ impl Default for EnemyAI {
 #[derive(Bundle)]
 pub struct EnemyBundle {
     pub enemy: Enemy,
+    pub kind: EnemyKind,
     pub ai: EnemyAI,
+    pub perception: Perception,
+    pub steering: Steering,
+    pub health: Health,
     pub animation_state: AnimationState,
     pub body: RigidBody,
     pub collider: Collider,
@@ -42,7 +110,14 @@ impl Default for EnemyBundle {
     fn default() -> Self {
         Self {
             enemy: Enemy,
+            kind: EnemyKind::default(),
             ai: EnemyAI::default(),
+            perception: Perception::default(),
+            steering: Steering::default(),
+            health: Health {
+                current: ENEMY_BASE_HEALTH,
+                max: ENEMY_BASE_HEALTH,
+            },
             animation_state: AnimationState {
                 forward_hold_time: 0.0,
                 current_animation: 0, // Start uninitialized to prevent twitching
@@ -72,40 +147,67 @@ impl Plugin for EnemyAIPlugin {
             Update,
             (
                 enemy_ai_movement,
+                enemy_melee_attack,
+                enemy_ranged_attack,
                 enemy_ai_animations,
-            ).chain(),
+            )
+                .chain()
+                .after(update_perception)
+                .after(separate_crowd)
+                // The boss fight is turn-based, so enemies only act on their own in gameplay
+                .run_if(in_state(Screen::GamePlay).and(resource_exists::<BoundaryConstraint>)),
         );
     }
 }
 
-/// System that handles enemy movement towards the player
+/// System that moves enemies towards where they last saw or heard the player,
+/// steered around walls and each other
 fn enemy_ai_movement(
     time: Res<Time>,
-    mut enemy_query: Query<(&mut Transform, &mut LinearVelocity, &mut EnemyAI, &mut AnimationState), (With<Enemy>, Without<crate::systems::character_controller::CharacterController>)>,
-    player_query: Query<&Transform, (With<crate::systems::character_controller::CharacterController>, Without<Enemy>)>,
+    spatial_query: SpatialQuery,
+    obstacles_ignored: Query<(), NotAnObstacle>,
+    mut enemy_query: Query<(Entity, &mut Transform, &mut LinearVelocity, &mut EnemyAI, &mut AnimationState, &mut Perception, &Steering), (With<Enemy>, Without<crate::systems::character_controller::CharacterController>)>,
     boundary_constraint: Res<BoundaryConstraint>,
 ) {
     let delta_time = time.delta_secs();
-    
-    // Find the player
-    let Ok(player_transform) = player_query.single() else {
-        return;
-    };
 
-    for (mut enemy_transform, mut enemy_velocity, mut enemy_ai, mut animation_state) in &mut enemy_query {
-        let player_pos = player_transform.translation;
+    for (enemy, mut enemy_transform, mut enemy_velocity, mut enemy_ai, mut animation_state, mut perception, steering) in &mut enemy_query {
         let enemy_pos = enemy_transform.translation;
-        let distance_to_player = enemy_pos.distance(player_pos);
+        let player_pos = perception.last_known_position.unwrap_or(enemy_pos);
+
+        // Enemies fighting up close head for their own slot around the player
+        let slot = steering.slot.filter(|_| perception.sees_player);
+        let goal = slot.map_or(player_pos, |offset| player_pos + offset);
+        let reach = if slot.is_some() { SLOT_ARRIVAL_DISTANCE } else { enemy_ai.attack_range };
+        let distance_to_goal = if slot.is_some() {
+            (goal - enemy_pos).with_y(0.0).length()
+        } else {
+            enemy_pos.distance(goal)
+        };
 
-        // Simple logic: move if player is out of range, stop if in range
-        if distance_to_player > enemy_ai.attack_range {
+        let speed = if perception.sees_player {
+            // The behaviour decides whether to close in, hold position or back off
+            enemy_ai
+                .behaviour
+                .desired_speed(distance_to_goal, reach, enemy_ai.move_speed)
+        } else if (player_pos - enemy_pos).with_y(0.0).length() > LAST_KNOWN_REACHED_DISTANCE {
+            // Walk over to investigate where the player was last seen or heard
+            enemy_ai.move_speed
+        } else {
+            // Nothing there (or nothing noticed yet), so stand and wait
+            perception.last_known_position = None;
+            0.0
+        };
+
+        // Move towards (or away from) the goal while keeping apart from other enemies
+        let direction_to_goal = (goal - enemy_pos).with_y(0.0).normalize_or_zero();
+        let desired_velocity = direction_to_goal * speed + steering.separation * enemy_ai.move_speed * SEPARATION_WEIGHT;
+        let target_velocity = avoid_obstacles(&spatial_query, &obstacles_ignored, enemy, enemy_pos, desired_velocity);
+
+        if target_velocity.length() > 0.05 {
             // Set moving state
             enemy_ai.is_moving = true;
             
-            // Move towards player
-            let direction_to_player = (player_pos - enemy_pos).normalize();
-            let target_velocity = direction_to_player * enemy_ai.move_speed;
-            
             // Check boundary constraints before applying movement
             let proposed_pos = enemy_pos + Vec3::new(target_velocity.x, 0.0, target_velocity.z) * delta_time;
             
@@ -138,10 +240,12 @@ fn enemy_ai_movement(
             
             enemy_velocity.y = 0.0;
             
-            // Rotate to face player
-            let direction_2d = Vec2::new(direction_to_player.x, direction_to_player.z).normalize();
-            let target_rotation = Quat::from_rotation_arc(Vec3::Z, Vec3::new(direction_2d.x, 0.0, direction_2d.y));
-            enemy_transform.rotation = enemy_transform.rotation.slerp(target_rotation, 3.0 * delta_time);
+            // Rotate to face the player, or the way we walk while investigating
+            let facing = if perception.sees_player { player_pos - enemy_pos } else { target_velocity };
+            if let Some(direction_2d) = Vec2::new(facing.x, facing.z).try_normalize() {
+                let target_rotation = Quat::from_rotation_arc(Vec3::Z, Vec3::new(direction_2d.x, 0.0, direction_2d.y));
+                enemy_transform.rotation = enemy_transform.rotation.slerp(target_rotation, 3.0 * delta_time);
+            }
             
             // Keep on ground
             enemy_transform.translation.y = -1.65;
@@ -168,6 +272,79 @@ fn enemy_ai_movement(
 
 
 
+/// Melee enemies within attack range of the player hit them on a cooldown
+fn enemy_melee_attack(
+    time: Res<Time>,
+    mut enemy_query: Query<(Entity, &Transform, &mut EnemyAI, &Perception), (With<Enemy>, Without<Defeated>)>,
+    player_query: Query<(Entity, &Transform), (With<crate::systems::character_controller::CharacterController>, Without<Enemy>)>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    let Ok((player, player_transform)) = player_query.single() else {
+        return;
+    };
+
+    for (enemy, enemy_transform, mut enemy_ai, perception) in &mut enemy_query {
+        enemy_ai.cooldown_remaining = (enemy_ai.cooldown_remaining - time.delta_secs()).max(0.0);
+        let offset = (player_transform.translation - enemy_transform.translation).with_y(0.0);
+        let ranged = enemy_ai.behaviour == EnemyBehaviour::Ranged;
+        if ranged
+            || !perception.sees_player
+            || enemy_ai.cooldown_remaining > 0.0
+            || offset.length() > enemy_ai.attack_range
+        {
+            continue;
+        }
+
+        enemy_ai.cooldown_remaining = enemy_ai.attack_cooldown;
+        damage_events.write(DamageEvent {
+            target: player,
+            amount: enemy_ai.attack_damage,
+            source: Some(enemy),
+        });
+    }
+}
+
+/// Ranged enemies fire at the player once their attack is ready and nothing blocks the shot
+fn enemy_ranged_attack(
+    mut commands: Commands,
+    spatial_query: SpatialQuery,
+    visuals: Res<ProjectileVisuals>,
+    pass_through: Query<(), PassThrough>,
+    mut enemy_query: Query<(Entity, &Transform, &mut EnemyAI, &Perception), (With<Enemy>, Without<Defeated>)>,
+    player_query: Query<(Entity, &Transform), (With<crate::systems::character_controller::CharacterController>, Without<Enemy>)>,
+    mut sfx_events: EventWriter<PlaySfxEvent>,
+) {
+    let Ok((player, player_transform)) = player_query.single() else {
+        return;
+    };
+    // The player's origin is at their feet
+    let target = player_transform.translation + Vec3::Y * PROJECTILE_AIM_HEIGHT;
+
+    for (enemy, enemy_transform, mut enemy_ai, perception) in &mut enemy_query {
+        if enemy_ai.behaviour != EnemyBehaviour::Ranged
+            || !perception.sees_player
+            || enemy_ai.cooldown_remaining > 0.0
+            || enemy_transform.translation.distance(target) > enemy_ai.attack_range
+        {
+            continue;
+        }
+
+        let muzzle = enemy_transform.translation + Vec3::Y * PROJECTILE_MUZZLE_HEIGHT;
+        if !has_line_of_sight(&spatial_query, &pass_through, muzzle, (player, target), enemy) {
+            continue;
+        }
+        let Ok(direction) = Dir3::new(target - muzzle) else {
+            continue;
+        };
+
+        enemy_ai.cooldown_remaining = enemy_ai.attack_cooldown;
+        spawn_projectile(&mut commands, &visuals, muzzle, direction, enemy_ai.attack_damage, enemy);
+        sfx_events.write(PlaySfxEvent {
+            sfx_type: SfxType::ProjectileFire,
+        });
+    }
+}
+
 /// System that handles enemy animations
 fn enemy_ai_animations(
     mut enemy_query: Query<(&mut GltfAnimations, &mut AnimationState, &EnemyAI), (With<Enemy>, Without<crate::systems::character_controller::CharacterController>)>,
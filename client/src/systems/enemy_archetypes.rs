@@ -0,0 +1,291 @@
+use avian3d::prelude::{Collider, CollisionEventsEnabled, Friction, GravityScale, Restitution};
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_gltf_animation::prelude::GltfSceneRoot;
+use serde::{Deserialize, Serialize};
+
+use crate::assets::{DataAssets, ModelAssets};
+use crate::constants::combat::ENEMY_ATTACK_COOLDOWN;
+use crate::constants::loot::ENEMY_LOOT_TABLE;
+use crate::game::Health;
+use crate::systems::boss::{Boss, BossPhase};
+use crate::systems::character_controller::setup_idle_animation;
+use crate::systems::enemy_ai::{EnemyAI, EnemyBehaviour, EnemyBundle, EnemyKind};
+use crate::systems::loot::LootSource;
+use crate::systems::steering::Steering;
+
+// ===== ARCHETYPE DATA =====
+
+/// Every enemy archetype of the game, authored in `assets/data/*.enemies.json`
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
+pub struct EnemyArchetypes {
+    pub archetypes: Vec<EnemyArchetype>,
+}
+
+impl EnemyArchetypes {
+    pub fn get(&self, name: &str) -> Option<&EnemyArchetype> {
+        self.archetypes.iter().find(|archetype| archetype.name == name)
+    }
+}
+
+/// Stats, looks and behaviour shared by every enemy of one type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnemyArchetype {
+    pub name: String,
+    /// Name shown on the boss health bar
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub kind: EnemyKind,
+    /// Path of the glTF model; enemies without one use the shared enemy model
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(skip)]
+    pub model_handle: Option<Handle<Gltf>>,
+    #[serde(default = "default_scale")]
+    pub scale: f32,
+    #[serde(default)]
+    pub collider: EnemyCollider,
+    pub speed: f32,
+    pub health: u32,
+    pub damage: u32,
+    pub attack_range: f32,
+    #[serde(default = "default_attack_cooldown")]
+    pub attack_cooldown: f32,
+    #[serde(default)]
+    pub behaviour: EnemyBehaviour,
+    /// Name of the loot table in `LootTables` the enemy drops from
+    #[serde(default = "default_loot_table")]
+    pub loot_table: String,
+    /// Phases of a boss encounter, from full health down
+    #[serde(default)]
+    pub phases: Vec<BossPhase>,
+}
+
+fn default_scale() -> f32 {
+    4.0
+}
+
+fn default_attack_cooldown() -> f32 {
+    ENEMY_ATTACK_COOLDOWN
+}
+
+fn default_loot_table() -> String {
+    ENEMY_LOOT_TABLE.to_string()
+}
+
+/// Collision shape of an archetype, in model space before scaling
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "shape", rename_all = "snake_case")]
+pub enum EnemyCollider {
+    Capsule { radius: f32, length: f32 },
+    Sphere { radius: f32 },
+    Cuboid { half_extents: [f32; 3] },
+}
+
+impl Default for EnemyCollider {
+    fn default() -> Self {
+        EnemyCollider::Capsule {
+            radius: 0.5,
+            length: 1.5,
+        }
+    }
+}
+
+impl EnemyCollider {
+    pub fn collider(&self) -> Collider {
+        match *self {
+            EnemyCollider::Capsule { radius, length } => Collider::capsule(radius, length),
+            EnemyCollider::Sphere { radius } => Collider::sphere(radius),
+            EnemyCollider::Cuboid { half_extents: [x, y, z] } => {
+                Collider::cuboid(x * 2.0, y * 2.0, z * 2.0)
+            }
+        }
+    }
+
+    /// Radius of the shape's footprint on the ground
+    pub fn footprint_radius(&self) -> f32 {
+        match *self {
+            EnemyCollider::Capsule { radius, .. } | EnemyCollider::Sphere { radius } => radius,
+            EnemyCollider::Cuboid { half_extents: [x, _, z] } => x.hypot(z),
+        }
+    }
+}
+
+// ===== SPAWNING =====
+
+/// Looks up archetypes by name and spawns enemies from them
+#[derive(SystemParam)]
+pub struct Bestiary<'w> {
+    data: Option<Res<'w, DataAssets>>,
+    archetypes: Res<'w, Assets<EnemyArchetypes>>,
+    models: Option<Res<'w, ModelAssets>>,
+}
+
+impl Bestiary<'_> {
+    pub fn get(&self, name: &str) -> Option<&EnemyArchetype> {
+        let data = self.data.as_ref()?;
+        self.archetypes.get(&data.enemies)?.get(name)
+    }
+
+    /// Spawns an enemy of the named archetype with its health and damage scaled by
+    /// `difficulty`. Returns `None` if the archetype or its model is unknown.
+    pub fn spawn<'a>(
+        &self,
+        commands: &'a mut Commands,
+        name: &str,
+        transform: Transform,
+        difficulty: f32,
+        loot_level: u32,
+    ) -> Option<EntityCommands<'a>> {
+        let Some(archetype) = self.get(name) else {
+            warn!("Unknown enemy archetype '{}'", name);
+            return None;
+        };
+        let model = match &archetype.model_handle {
+            Some(handle) => handle.clone(),
+            None => self.models.as_ref()?.enemy.clone(),
+        };
+        let health = (archetype.health as f32 * difficulty).round() as u32;
+
+        let mut entity = commands.spawn((
+            Name::new(format!("Enemy {}", archetype.name)),
+            GltfSceneRoot::new(model),
+            transform.with_scale(Vec3::splat(archetype.scale)),
+            EnemyBundle {
+                kind: archetype.kind,
+                ai: EnemyAI {
+                    behaviour: archetype.behaviour,
+                    attack_range: archetype.attack_range,
+                    move_speed: archetype.speed,
+                    attack_damage: (archetype.damage as f32 * difficulty).round() as u32,
+                    attack_cooldown: archetype.attack_cooldown,
+                    ..default()
+                },
+                health: Health {
+                    current: health,
+                    max: health,
+                },
+                collider: archetype.collider.collider(),
+                steering: Steering::new(archetype.collider.footprint_radius() * archetype.scale),
+                ..default()
+            },
+            LootSource::new(archetype.loot_table.clone(), loot_level),
+            Friction::new(0.5),
+            Restitution::new(0.0),
+            GravityScale(1.0),
+            CollisionEventsEnabled,
+        ));
+        if archetype.kind == EnemyKind::Boss {
+            let title = archetype.title.clone().unwrap_or_else(|| archetype.name.clone());
+            entity.insert(Boss::new(title, archetype.phases.clone(), archetype.speed));
+        }
+        entity.observe(setup_idle_animation);
+        Some(entity)
+    }
+}
+
+// ===== LOADER =====
+
+#[derive(Default)]
+pub struct EnemyArchetypesLoader;
+
+#[derive(Debug)]
+pub enum EnemyArchetypesLoaderError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+}
+
+impl std::fmt::Display for EnemyArchetypesLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnemyArchetypesLoaderError::Io(err) => write!(f, "could not read enemy file: {}", err),
+            EnemyArchetypesLoaderError::Json(err) => write!(f, "invalid enemy file: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for EnemyArchetypesLoaderError {}
+
+impl From<std::io::Error> for EnemyArchetypesLoaderError {
+    fn from(err: std::io::Error) -> Self {
+        EnemyArchetypesLoaderError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for EnemyArchetypesLoaderError {
+    fn from(err: serde_json::Error) -> Self {
+        EnemyArchetypesLoaderError::Json(err)
+    }
+}
+
+impl AssetLoader for EnemyArchetypesLoader {
+    type Asset = EnemyArchetypes;
+    type Settings = ();
+    type Error = EnemyArchetypesLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut data: EnemyArchetypes = serde_json::from_slice(&bytes)?;
+        // Models load as dependencies, so they are ready once the data is
+        for archetype in &mut data.archetypes {
+            if let Some(path) = archetype.model.clone() {
+                archetype.model_handle = Some(load_context.load(path));
+            }
+        }
+        Ok(data)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["enemies.json"]
+    }
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn campaign_enemies_parse_with_every_behaviour() {
+        let data: EnemyArchetypes =
+            serde_json::from_str(include_str!("../../assets/data/campaign.enemies.json")).unwrap();
+
+        for behaviour in [
+            EnemyBehaviour::Melee,
+            EnemyBehaviour::Ranged,
+            EnemyBehaviour::Charger,
+            EnemyBehaviour::Tank,
+        ] {
+            assert!(data.archetypes.iter().any(|archetype| archetype.behaviour == behaviour));
+        }
+        assert!(data.get("realm_boss").is_some_and(|boss| boss.kind == EnemyKind::Boss));
+
+        // Adds of every boss phase must name known archetypes
+        for phase in data.archetypes.iter().flat_map(|archetype| &archetype.phases) {
+            for add in &phase.adds {
+                assert!(data.get(&add.archetype).is_some(), "unknown add {}", add.archetype);
+            }
+        }
+    }
+
+    #[test]
+    fn ranged_enemies_back_off_and_chargers_rush() {
+        assert!(EnemyBehaviour::Ranged.desired_speed(2.0, 12.0, 3.0) < 0.0);
+        assert_eq!(EnemyBehaviour::Ranged.desired_speed(10.0, 12.0, 3.0), 0.0);
+        assert_eq!(EnemyBehaviour::Melee.desired_speed(2.0, 3.0, 3.0), 0.0);
+        assert!(
+            EnemyBehaviour::Charger.desired_speed(6.0, 3.0, 3.0)
+                > EnemyBehaviour::Melee.desired_speed(6.0, 3.0, 3.0)
+        );
+    }
+}
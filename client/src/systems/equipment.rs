@@ -0,0 +1,261 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::constants::movement::CharacterMovementConfig;
+use crate::constants::player::{BASE_DAMAGE, BASE_MAX_HEALTH};
+use crate::game::Health;
+use crate::screens::Screen;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::progression::{Experience, level_max_health_bonus};
+
+// ===== COMPONENTS & RESOURCES =====
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EquipmentSlot {
+    Weapon,
+    Armor,
+    Accessory,
+}
+
+/// A single change to one stat granted by a piece of equipment
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StatModifier {
+    /// Flat melee damage added to every hit
+    Damage(f32),
+    /// Flat bonus to maximum health
+    MaxHealth(u32),
+    /// Fraction added to the movement speed multiplier (0.1 = 10% faster)
+    MoveSpeed(f32),
+    /// Flat bonus to jump velocity
+    JumpImpulse(f32),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EquipmentItem {
+    pub name: String,
+    pub slot: EquipmentSlot,
+    pub modifiers: Vec<StatModifier>,
+}
+
+impl EquipmentItem {
+    pub fn new(name: impl Into<String>, slot: EquipmentSlot, modifiers: Vec<StatModifier>) -> Self {
+        Self {
+            name: name.into(),
+            slot,
+            modifiers,
+        }
+    }
+}
+
+/// What the player currently wears, one item per slot.
+///
+/// Kept as a resource like [`crate::systems::inventory::Inventory`] so it
+/// survives the player entity being respawned between scenes.
+#[derive(Resource, Default, Debug, Clone)]
+pub struct Equipment {
+    slots: HashMap<EquipmentSlot, EquipmentItem>,
+}
+
+impl Equipment {
+    pub fn get(&self, slot: EquipmentSlot) -> Option<&EquipmentItem> {
+        self.slots.get(&slot)
+    }
+
+    /// Puts the item in its slot and returns whatever was there before
+    pub fn equip(&mut self, item: EquipmentItem) -> Option<EquipmentItem> {
+        self.slots.insert(item.slot, item)
+    }
+
+    pub fn unequip(&mut self, slot: EquipmentSlot) -> Option<EquipmentItem> {
+        self.slots.remove(&slot)
+    }
+
+    pub fn modifiers(&self) -> impl Iterator<Item = &StatModifier> {
+        self.slots.values().flat_map(|item| item.modifiers.iter())
+    }
+
+    pub fn clear(&mut self) {
+        self.slots.clear();
+    }
+}
+
+/// Effective stats of a character after equipment is applied.
+///
+/// Derived from [`Equipment`]; never edit it directly.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub damage: f32,
+    pub max_health: u32,
+    /// Multiplier on the walk and run speeds from `CharacterMovementConfig`
+    pub move_speed: f32,
+    /// Flat bonus added to the character's `JumpImpulse`
+    pub jump_bonus: f32,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            damage: BASE_DAMAGE,
+            max_health: BASE_MAX_HEALTH,
+            move_speed: 1.0,
+            jump_bonus: 0.0,
+        }
+    }
+}
+
+impl Stats {
+    /// Base stats with every modifier applied on top
+    pub fn from_modifiers<'a>(modifiers: impl IntoIterator<Item = &'a StatModifier>) -> Self {
+        let mut stats = Stats::default();
+        for modifier in modifiers {
+            match *modifier {
+                StatModifier::Damage(amount) => stats.damage += amount,
+                StatModifier::MaxHealth(amount) => stats.max_health += amount,
+                StatModifier::MoveSpeed(amount) => stats.move_speed += amount,
+                StatModifier::JumpImpulse(amount) => stats.jump_bonus += amount,
+            }
+        }
+        stats.damage = stats.damage.max(0.0);
+        stats.max_health = stats.max_health.max(1);
+        // Keep the walk speed from dropping to a crawl or outrunning the sprint
+        stats.move_speed = stats.move_speed.clamp(
+            0.25,
+            CharacterMovementConfig::MAX_RUN_SPEED / CharacterMovementConfig::MAX_SPEED,
+        );
+        stats
+    }
+}
+
+#[derive(Event, Debug, Clone)]
+pub enum EquipmentEvent {
+    Equip(EquipmentItem),
+    Unequip(EquipmentSlot),
+}
+
+// ===== PLUGIN =====
+
+pub struct EquipmentPlugin;
+
+impl Plugin for EquipmentPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Equipment>()
+            .add_event::<EquipmentEvent>()
+            .add_systems(OnEnter(Screen::PreGameLoading), reset_equipment)
+            .add_systems(
+                Update,
+                (apply_equipment_events, derive_player_stats).chain(),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn reset_equipment(mut equipment: ResMut<Equipment>) {
+    equipment.clear();
+}
+
+fn apply_equipment_events(mut events: EventReader<EquipmentEvent>, mut equipment: ResMut<Equipment>) {
+    for event in events.read() {
+        match event {
+            EquipmentEvent::Equip(item) => {
+                if let Some(previous) = equipment.equip(item.clone()) {
+                    info!("Replaced {} with {}", previous.name, item.name);
+                }
+            }
+            EquipmentEvent::Unequip(slot) => {
+                equipment.unequip(*slot);
+            }
+        }
+    }
+}
+
+/// Recomputes the player's `Stats` when equipment or level changes or a player
+/// spawns, and carries max health changes over to `Health`
+pub fn derive_player_stats(
+    equipment: Res<Equipment>,
+    mut player_query: Query<
+        (&mut Stats, Option<&mut Health>, Option<Ref<Experience>>),
+        With<CharacterController>,
+    >,
+) {
+    for (mut stats, health, experience) in &mut player_query {
+        let level_changed = experience.as_ref().is_some_and(|experience| experience.is_changed());
+        if !equipment.is_changed() && !stats.is_added() && !level_changed {
+            continue;
+        }
+
+        let mut derived = Stats::from_modifiers(equipment.modifiers());
+        if let Some(experience) = &experience {
+            derived.max_health += level_max_health_bonus(experience.level);
+        }
+        if *stats != derived {
+            *stats = derived;
+        }
+
+        if let Some(mut health) = health {
+            if health.max != derived.max_health {
+                // Gaining max health also heals by the difference; losing it only caps
+                if derived.max_health > health.max {
+                    health.current += derived.max_health - health.max;
+                }
+                health.max = derived.max_health;
+                health.current = health.current.min(health.max);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modifiers_from_all_slots_are_summed() {
+        let mut equipment = Equipment::default();
+        equipment.equip(EquipmentItem::new(
+            "Sword",
+            EquipmentSlot::Weapon,
+            vec![StatModifier::Damage(5.0)],
+        ));
+        equipment.equip(EquipmentItem::new(
+            "Mail",
+            EquipmentSlot::Armor,
+            vec![StatModifier::MaxHealth(20), StatModifier::MoveSpeed(-0.1)],
+        ));
+        equipment.equip(EquipmentItem::new(
+            "Feather",
+            EquipmentSlot::Accessory,
+            vec![StatModifier::JumpImpulse(1.5), StatModifier::Damage(1.0)],
+        ));
+
+        let stats = Stats::from_modifiers(equipment.modifiers());
+        assert_eq!(stats.damage, BASE_DAMAGE + 6.0);
+        assert_eq!(stats.max_health, BASE_MAX_HEALTH + 20);
+        assert!((stats.move_speed - 0.9).abs() < f32::EPSILON);
+        assert_eq!(stats.jump_bonus, 1.5);
+    }
+
+    #[test]
+    fn equipping_replaces_the_item_in_the_same_slot() {
+        let mut equipment = Equipment::default();
+        equipment.equip(EquipmentItem::new(
+            "Dagger",
+            EquipmentSlot::Weapon,
+            vec![StatModifier::Damage(2.0)],
+        ));
+        let previous = equipment.equip(EquipmentItem::new(
+            "Axe",
+            EquipmentSlot::Weapon,
+            vec![StatModifier::Damage(8.0)],
+        ));
+
+        assert_eq!(previous.map(|item| item.name), Some("Dagger".to_string()));
+        assert_eq!(Stats::from_modifiers(equipment.modifiers()).damage, BASE_DAMAGE + 8.0);
+    }
+
+    #[test]
+    fn move_speed_is_clamped() {
+        let slow = [StatModifier::MoveSpeed(-5.0)];
+        assert_eq!(Stats::from_modifiers(&slow).move_speed, 0.25);
+    }
+}
@@ -0,0 +1,214 @@
+use bevy::prelude::*;
+
+use crate::assets::FontAssets;
+use crate::resources::controls::{ControlAction, ControlSettings};
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::objectives::ObjectiveManager;
+use crate::ui::dialog::{DialogConfig, DialogPosition, DialogText, spawn_dialog};
+use crate::ui::hud::{HudNotificationEvent, NotificationKind};
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// Something the player can use with the interact key when standing within `radius`.
+///
+/// What happens is up to the entity: attach an observer for [`Interacted`] when
+/// spawning it, e.g. `commands.spawn((.., Interactable::new(..))).observe(open_door)`.
+#[derive(Component, Clone, Debug)]
+pub struct Interactable {
+    /// What using it does, shown after the interact input in the prompt, e.g. `to Open`
+    pub verb: String,
+    pub radius: f32,
+    /// Name objectives use to refer to this kind of target, e.g. `book` or `chest`
+    pub target: String,
+    pub requirement: Option<InteractionRequirement>,
+    /// Disabled interactables are ignored, e.g. chests that were already opened
+    pub enabled: bool,
+}
+
+impl Interactable {
+    pub fn new(target: impl Into<String>, verb: impl Into<String>, radius: f32) -> Self {
+        Self {
+            verb: verb.into(),
+            radius,
+            target: target.into(),
+            requirement: None,
+            enabled: true,
+        }
+    }
+
+    pub fn with_requirement(mut self, requirement: InteractionRequirement) -> Self {
+        self.requirement = Some(requirement);
+        self
+    }
+}
+
+/// A condition that must hold before an interaction goes through
+#[derive(Clone, Debug, PartialEq)]
+pub enum InteractionRequirement {
+    /// Every required objective of the level is complete
+    LevelExitUnlocked,
+}
+
+impl InteractionRequirement {
+    /// Explains to the player why the interaction is blocked
+    pub fn check(&self, objective_manager: &ObjectiveManager) -> Result<(), String> {
+        match self {
+            InteractionRequirement::LevelExitUnlocked => {
+                if objective_manager.exit_unlocked() {
+                    return Ok(());
+                }
+                let remaining: Vec<&str> = objective_manager
+                    .remaining_required()
+                    .map(|objective| objective.title.as_str())
+                    .collect();
+                Err(format!("The realm is sealed. Complete first: {}", remaining.join(", ")))
+            }
+        }
+    }
+}
+
+/// The nearest enabled interactable in range of the player, if any
+#[derive(Resource, Default, Debug)]
+pub struct FocusedInteractable(pub Option<Entity>);
+
+/// Marker for the shared "Press <interact input> to ..." prompt
+#[derive(Component)]
+pub struct InteractionPrompt;
+
+// ===== EVENTS =====
+
+/// The player pressed the interact key
+#[derive(Event, Debug, Clone, Copy)]
+pub struct InteractRequestEvent;
+
+/// Triggered on an interactable once the player used it and its requirement passed
+#[derive(Event, Debug, Clone)]
+pub struct Interacted {
+    pub target: String,
+}
+
+// ===== PLUGIN =====
+
+pub struct InteractionPlugin;
+
+impl Plugin for InteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FocusedInteractable>()
+            .add_event::<InteractRequestEvent>()
+            .add_systems(OnEnter(Screen::GamePlay), spawn_interaction_prompt)
+            .add_systems(
+                Update,
+                (focus_nearest_interactable, update_interaction_prompt, resolve_interactions)
+                    .chain()
+                    .run_if(in_state(Screen::GamePlay)),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn spawn_interaction_prompt(
+    mut commands: Commands,
+    font_assets: Res<FontAssets>,
+    windows: Query<&Window>,
+) {
+    let prompt = spawn_dialog(
+        &mut commands,
+        &font_assets,
+        windows,
+        DialogConfig {
+            text: String::new(),
+            position: DialogPosition::BottomCenter { bottom_margin: 4.0 },
+            ..Default::default()
+        },
+        PlayingScene,
+    );
+    commands.entity(prompt).insert(InteractionPrompt);
+}
+
+fn focus_nearest_interactable(
+    player_query: Query<&Transform, With<CharacterController>>,
+    interactables: Query<(Entity, &GlobalTransform, &Interactable)>,
+    mut focused: ResMut<FocusedInteractable>,
+) {
+    let nearest = player_query.single().ok().and_then(|player_transform| {
+        interactables
+            .iter()
+            .filter(|(_, _, interactable)| interactable.enabled)
+            .map(|(entity, transform, interactable)| {
+                let distance = player_transform.translation.distance(transform.translation());
+                (entity, distance, interactable.radius)
+            })
+            .filter(|(_, distance, radius)| distance <= radius)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(entity, _, _)| entity)
+    });
+
+    if focused.0 != nearest {
+        focused.0 = nearest;
+    }
+}
+
+/// Names the inputs bound to Interact, so the prompt follows rebinds and gamepads
+fn update_interaction_prompt(
+    focused: Res<FocusedInteractable>,
+    interactables: Query<&Interactable>,
+    controls: Res<ControlSettings>,
+    mut prompt_query: Query<(&mut DialogText, &mut Visibility), With<InteractionPrompt>>,
+) {
+    let Ok((mut text, mut visibility)) = prompt_query.single_mut() else {
+        return;
+    };
+
+    match focused.0.and_then(|entity| interactables.get(entity).ok()) {
+        Some(interactable) => {
+            let prompt = match controls.inputs_label(ControlAction::Interact) {
+                Some(inputs) => format!("Press {} {}", inputs, interactable.verb),
+                None => format!("Bind Interact in Settings {}", interactable.verb),
+            };
+            if text.0 != prompt {
+                text.0 = prompt;
+            }
+            visibility.set_if_neq(Visibility::Visible);
+        }
+        None => {
+            visibility.set_if_neq(Visibility::Hidden);
+        }
+    }
+}
+
+/// Uses the focused interactable, or tells the player why it is locked
+fn resolve_interactions(
+    mut commands: Commands,
+    mut requests: EventReader<InteractRequestEvent>,
+    focused: Res<FocusedInteractable>,
+    interactables: Query<&Interactable>,
+    objective_manager: Res<ObjectiveManager>,
+    mut notifications: EventWriter<HudNotificationEvent>,
+) {
+    if requests.read().last().is_none() {
+        return;
+    }
+    let Some((entity, interactable)) = focused
+        .0
+        .and_then(|entity| interactables.get(entity).ok().map(|interactable| (entity, interactable)))
+    else {
+        return;
+    };
+
+    if let Some(requirement) = &interactable.requirement {
+        if let Err(reason) = requirement.check(&objective_manager) {
+            notifications.write(HudNotificationEvent::new(reason, NotificationKind::Warning));
+            return;
+        }
+    }
+
+    commands.trigger_targets(
+        Interacted {
+            target: interactable.target.clone(),
+        },
+        entity,
+    );
+}
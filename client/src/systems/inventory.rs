@@ -0,0 +1,306 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::inventory::{INVENTORY_SLOTS, MAX_STACK_SIZE};
+use crate::screens::Screen;
+use crate::systems::collectibles::CollectibleType;
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// A stack of identical items occupying one inventory slot
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemStack {
+    pub item_type: CollectibleType,
+    pub count: u32,
+}
+
+/// The player's inventory: a fixed number of ordered slots holding typed stacks.
+///
+/// This is the single source of truth for item counts; the hotbar in
+/// `ui::inventory` only renders it.
+#[derive(Resource, Debug, Clone)]
+pub struct Inventory {
+    slots: Vec<Option<ItemStack>>,
+}
+
+impl Default for Inventory {
+    fn default() -> Self {
+        Self::with_slots(INVENTORY_SLOTS)
+    }
+}
+
+impl Inventory {
+    pub fn with_slots(slot_count: usize) -> Self {
+        Self {
+            slots: vec![None; slot_count],
+        }
+    }
+
+    pub fn slots(&self) -> &[Option<ItemStack>] {
+        &self.slots
+    }
+
+    /// Total amount of an item type across all stacks
+    pub fn count(&self, item_type: CollectibleType) -> u32 {
+        self.slots
+            .iter()
+            .flatten()
+            .filter(|stack| stack.item_type == item_type)
+            .map(|stack| stack.count)
+            .sum()
+    }
+
+    /// Adds items, topping up existing stacks first and then filling empty slots in order.
+    /// Returns the amount that did not fit.
+    pub fn add(&mut self, item_type: CollectibleType, amount: u32) -> u32 {
+        let mut remaining = amount;
+
+        for stack in self.slots.iter_mut().flatten() {
+            if remaining == 0 {
+                break;
+            }
+            if stack.item_type == item_type && stack.count < MAX_STACK_SIZE {
+                let added = remaining.min(MAX_STACK_SIZE - stack.count);
+                stack.count += added;
+                remaining -= added;
+            }
+        }
+
+        for slot in self.slots.iter_mut() {
+            if remaining == 0 {
+                break;
+            }
+            if slot.is_none() {
+                let added = remaining.min(MAX_STACK_SIZE);
+                *slot = Some(ItemStack {
+                    item_type,
+                    count: added,
+                });
+                remaining -= added;
+            }
+        }
+
+        remaining
+    }
+
+    /// Removes items, draining the last stacks first so earlier slots keep their place.
+    /// Nothing is removed if the inventory holds fewer than `amount`.
+    pub fn remove(&mut self, item_type: CollectibleType, amount: u32) -> bool {
+        if self.count(item_type) < amount {
+            return false;
+        }
+
+        let mut remaining = amount;
+        for slot in self.slots.iter_mut().rev() {
+            if remaining == 0 {
+                break;
+            }
+            if let Some(stack) = slot {
+                if stack.item_type == item_type {
+                    let removed = remaining.min(stack.count);
+                    stack.count -= removed;
+                    remaining -= removed;
+                    if stack.count == 0 {
+                        *slot = None;
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    pub fn clear(&mut self) {
+        self.slots.iter_mut().for_each(|slot| *slot = None);
+    }
+
+    /// Puts saved stacks back into their slots. Stacks beyond the slot count are dropped.
+    pub fn restore(&mut self, slots: &[Option<ItemStack>]) {
+        self.clear();
+        for (slot, saved) in self.slots.iter_mut().zip(slots) {
+            *slot = *saved;
+        }
+    }
+}
+
+/// Gold earned this run. Currency does not take up inventory slots.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wallet {
+    pub gold: u32,
+}
+
+/// Queued changes to the inventory. Gameplay systems write these instead of
+/// touching the [`Inventory`] resource, so several pickups in one frame are all applied.
+#[derive(Event, Debug, Clone, Copy)]
+pub enum InventoryEvent {
+    Add { item_type: CollectibleType, count: u32 },
+    Remove { item_type: CollectibleType, count: u32 },
+    AddGold { amount: u32 },
+}
+
+// ===== PLUGIN =====
+
+pub struct InventoryPlugin;
+
+impl Plugin for InventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Inventory>()
+            .init_resource::<Wallet>()
+            .add_event::<InventoryEvent>()
+            // A fresh run starts with an empty inventory; FightScene round trips keep it
+            .add_systems(OnEnter(Screen::PreGameLoading), reset_inventory)
+            .add_systems(Update, apply_inventory_events)
+            .add_systems(
+                Update,
+                (
+                    crate::ui::inventory::sync_inventory_ui,
+                    crate::ui::inventory::sync_wallet_ui,
+                )
+                    .after(apply_inventory_events)
+                    .run_if(in_state(Screen::GamePlay)),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn reset_inventory(mut inventory: ResMut<Inventory>, mut wallet: ResMut<Wallet>) {
+    inventory.clear();
+    *wallet = Wallet::default();
+}
+
+fn apply_inventory_events(
+    mut events: EventReader<InventoryEvent>,
+    mut inventory: ResMut<Inventory>,
+    mut wallet: ResMut<Wallet>,
+) {
+    for event in events.read() {
+        match *event {
+            InventoryEvent::Add { item_type, count } => {
+                let overflow = inventory.add(item_type, count);
+                if overflow > 0 {
+                    warn!("Inventory full, dropped {} x {:?}", overflow, item_type);
+                }
+            }
+            InventoryEvent::Remove { item_type, count } => {
+                if !inventory.remove(item_type, count) {
+                    warn!(
+                        "Tried to remove {} x {:?} but only {} in inventory",
+                        count,
+                        item_type,
+                        inventory.count(item_type)
+                    );
+                }
+            }
+            InventoryEvent::AddGold { amount } => {
+                wallet.gold = wallet.gold.saturating_add(amount);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_type_stacks_into_one_slot() {
+        let mut inventory = Inventory::default();
+        inventory.add(CollectibleType::Coin, 1);
+        inventory.add(CollectibleType::Coin, 2);
+
+        assert_eq!(
+            inventory.slots()[0],
+            Some(ItemStack {
+                item_type: CollectibleType::Coin,
+                count: 3
+            })
+        );
+        assert!(inventory.slots()[1..].iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn full_stack_spills_into_next_empty_slot() {
+        let mut inventory = Inventory::default();
+        inventory.add(CollectibleType::Coin, MAX_STACK_SIZE - 1);
+        inventory.add(CollectibleType::HealthPotion, 1);
+        inventory.add(CollectibleType::Coin, 3);
+
+        assert_eq!(inventory.slots()[0].unwrap().count, MAX_STACK_SIZE);
+        assert_eq!(inventory.slots()[1].unwrap().item_type, CollectibleType::HealthPotion);
+        assert_eq!(
+            inventory.slots()[2],
+            Some(ItemStack {
+                item_type: CollectibleType::Coin,
+                count: 2
+            })
+        );
+        assert_eq!(inventory.count(CollectibleType::Coin), MAX_STACK_SIZE + 2);
+    }
+
+    #[test]
+    fn new_types_take_slots_in_order() {
+        let mut inventory = Inventory::default();
+        inventory.add(CollectibleType::Coin, 1);
+        inventory.add(CollectibleType::Book, 1);
+        inventory.add(CollectibleType::SurvivalKit, 1);
+
+        let order: Vec<_> = inventory
+            .slots()
+            .iter()
+            .flatten()
+            .map(|stack| stack.item_type)
+            .collect();
+        assert_eq!(
+            order,
+            vec![CollectibleType::Coin, CollectibleType::Book, CollectibleType::SurvivalKit]
+        );
+    }
+
+    #[test]
+    fn freed_slot_is_reused_before_later_slots() {
+        let mut inventory = Inventory::default();
+        inventory.add(CollectibleType::Coin, 1);
+        inventory.add(CollectibleType::Book, 1);
+        inventory.add(CollectibleType::SurvivalKit, 1);
+
+        assert!(inventory.remove(CollectibleType::Book, 1));
+        assert_eq!(inventory.slots()[1], None);
+
+        inventory.add(CollectibleType::HealthPotion, 1);
+        assert_eq!(inventory.slots()[1].unwrap().item_type, CollectibleType::HealthPotion);
+        assert_eq!(inventory.slots()[2].unwrap().item_type, CollectibleType::SurvivalKit);
+    }
+
+    #[test]
+    fn removal_drains_last_stack_first() {
+        let mut inventory = Inventory::default();
+        inventory.add(CollectibleType::Coin, MAX_STACK_SIZE + 5);
+
+        assert!(inventory.remove(CollectibleType::Coin, 3));
+        assert_eq!(inventory.slots()[0].unwrap().count, MAX_STACK_SIZE);
+        assert_eq!(inventory.slots()[1].unwrap().count, 2);
+
+        assert!(inventory.remove(CollectibleType::Coin, 2));
+        assert_eq!(inventory.slots()[1], None);
+    }
+
+    #[test]
+    fn removing_more_than_held_changes_nothing() {
+        let mut inventory = Inventory::default();
+        inventory.add(CollectibleType::HealthPotion, 2);
+
+        assert!(!inventory.remove(CollectibleType::HealthPotion, 3));
+        assert_eq!(inventory.count(CollectibleType::HealthPotion), 2);
+        assert!(!inventory.remove(CollectibleType::Book, 1));
+    }
+
+    #[test]
+    fn overflow_is_returned_when_full() {
+        let mut inventory = Inventory::with_slots(1);
+        let overflow = inventory.add(CollectibleType::Coin, MAX_STACK_SIZE + 4);
+
+        assert_eq!(overflow, 4);
+        assert_eq!(inventory.add(CollectibleType::Book, 1), 1);
+    }
+}
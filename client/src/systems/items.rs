@@ -0,0 +1,247 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::constants::items::{
+    HEALTH_POTION_COOLDOWN, HEALTH_POTION_HEAL, SURVIVAL_KIT_BUFF_DURATION,
+    SURVIVAL_KIT_COOLDOWN, SURVIVAL_KIT_REGEN_PER_SECOND,
+};
+use crate::game::Health;
+use crate::screens::Screen;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::collectibles::CollectibleType;
+use crate::systems::inventory::{Inventory, InventoryEvent};
+use crate::ui::hud::{HudNotificationEvent, NotificationKind};
+
+// ===== EVENTS =====
+
+/// Request to consume one item of the given type from the inventory
+#[derive(Event, Debug, Clone, Copy)]
+pub struct UseItemEvent {
+    pub item_type: CollectibleType,
+}
+
+/// Emitted after an item was consumed and its effect applied
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ItemUsedEvent {
+    pub item_type: CollectibleType,
+}
+
+/// Emitted when a use request was rejected
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ItemUseFailedEvent {
+    pub item_type: CollectibleType,
+    pub reason: ItemUseFailure,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ItemUseFailure {
+    NotUsable,
+    NoneLeft,
+    FullHealth,
+    OnCooldown { remaining: f32 },
+}
+
+impl ItemUseFailure {
+    pub fn message(&self, item_type: CollectibleType) -> String {
+        match self {
+            ItemUseFailure::NotUsable => format!("{} can't be used", item_type.display_name()),
+            ItemUseFailure::NoneLeft => format!("No {} left", item_type.display_name()),
+            ItemUseFailure::FullHealth => "Already at full health".to_string(),
+            ItemUseFailure::OnCooldown { remaining } => {
+                format!("{} ready in {:.1}s", item_type.display_name(), remaining)
+            }
+        }
+    }
+}
+
+// ===== COMPONENTS & RESOURCES =====
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuffKind {
+    Regeneration,
+}
+
+#[derive(Clone, Debug)]
+pub struct ActiveBuff {
+    pub kind: BuffKind,
+    pub timer: Timer,
+    /// Fractional health healed but not yet applied to the integer `Health`
+    carry: f32,
+}
+
+/// Timed effects currently applied to a character
+#[derive(Component, Default, Debug)]
+pub struct ActiveBuffs(pub Vec<ActiveBuff>);
+
+/// Per-item-type cooldowns started when an item is used
+#[derive(Resource, Default, Debug)]
+pub struct ItemCooldowns {
+    timers: HashMap<CollectibleType, Timer>,
+}
+
+impl ItemCooldowns {
+    /// Seconds left before the item type can be used again, if it is cooling down
+    pub fn remaining(&self, item_type: CollectibleType) -> Option<f32> {
+        self.timers
+            .get(&item_type)
+            .filter(|timer| !timer.finished())
+            .map(|timer| timer.remaining_secs())
+    }
+
+    fn start(&mut self, item_type: CollectibleType, seconds: f32) {
+        self.timers
+            .insert(item_type, Timer::from_seconds(seconds, TimerMode::Once));
+    }
+}
+
+// ===== PLUGIN =====
+
+pub struct ItemsPlugin;
+
+impl Plugin for ItemsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ItemCooldowns>()
+            .add_event::<UseItemEvent>()
+            .add_event::<ItemUsedEvent>()
+            .add_event::<ItemUseFailedEvent>()
+            .add_systems(
+                Update,
+                (
+                    tick_item_cooldowns,
+                    // Turn-based fights take item use as a turn action instead
+                    handle_use_item_events.run_if(not(in_state(Screen::FightScene))),
+                    tick_active_buffs,
+                    notify_item_use,
+                    crate::ui::inventory::update_slot_cooldowns,
+                )
+                    .chain(),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn tick_item_cooldowns(time: Res<Time>, mut cooldowns: ResMut<ItemCooldowns>) {
+    for timer in cooldowns.timers.values_mut() {
+        timer.tick(time.delta());
+    }
+    cooldowns.timers.retain(|_, timer| !timer.finished());
+}
+
+/// Validates use requests, applies item effects and consumes the stack
+fn handle_use_item_events(
+    mut events: EventReader<UseItemEvent>,
+    inventory: Res<Inventory>,
+    mut cooldowns: ResMut<ItemCooldowns>,
+    mut player_query: Query<(&mut Health, &mut ActiveBuffs), With<CharacterController>>,
+    mut inventory_events: EventWriter<InventoryEvent>,
+    mut used_events: EventWriter<ItemUsedEvent>,
+    mut failed_events: EventWriter<ItemUseFailedEvent>,
+) {
+    for event in events.read() {
+        let item_type = event.item_type;
+        let Ok((mut health, mut buffs)) = player_query.single_mut() else {
+            continue;
+        };
+
+        let result = if !matches!(
+            item_type,
+            CollectibleType::HealthPotion | CollectibleType::SurvivalKit
+        ) {
+            Err(ItemUseFailure::NotUsable)
+        } else if let Some(remaining) = cooldowns.remaining(item_type) {
+            Err(ItemUseFailure::OnCooldown { remaining })
+        } else if inventory.count(item_type) == 0 {
+            Err(ItemUseFailure::NoneLeft)
+        } else if item_type == CollectibleType::HealthPotion && health.current >= health.max {
+            Err(ItemUseFailure::FullHealth)
+        } else {
+            Ok(())
+        };
+
+        if let Err(reason) = result {
+            failed_events.write(ItemUseFailedEvent { item_type, reason });
+            continue;
+        }
+
+        match item_type {
+            CollectibleType::HealthPotion => {
+                health.current = (health.current + HEALTH_POTION_HEAL).min(health.max);
+                cooldowns.start(item_type, HEALTH_POTION_COOLDOWN);
+            }
+            CollectibleType::SurvivalKit => {
+                let timer = Timer::from_seconds(SURVIVAL_KIT_BUFF_DURATION, TimerMode::Once);
+                // Opening another kit refreshes the running buff instead of stacking it
+                match buffs.0.iter_mut().find(|buff| buff.kind == BuffKind::Regeneration) {
+                    Some(buff) => buff.timer = timer,
+                    None => buffs.0.push(ActiveBuff {
+                        kind: BuffKind::Regeneration,
+                        timer,
+                        carry: 0.0,
+                    }),
+                }
+                cooldowns.start(item_type, SURVIVAL_KIT_COOLDOWN);
+            }
+            _ => unreachable!("non-usable items are rejected above"),
+        }
+
+        inventory_events.write(InventoryEvent::Remove { item_type, count: 1 });
+        used_events.write(ItemUsedEvent { item_type });
+    }
+}
+
+fn tick_active_buffs(
+    time: Res<Time>,
+    mut query: Query<(&mut Health, &mut ActiveBuffs)>,
+    mut notifications: EventWriter<HudNotificationEvent>,
+) {
+    for (mut health, mut buffs) in &mut query {
+        for buff in buffs.0.iter_mut() {
+            buff.timer.tick(time.delta());
+            match buff.kind {
+                BuffKind::Regeneration => {
+                    buff.carry += SURVIVAL_KIT_REGEN_PER_SECOND * time.delta_secs();
+                    let whole = buff.carry.floor();
+                    if whole >= 1.0 {
+                        buff.carry -= whole;
+                        health.current = (health.current + whole as u32).min(health.max);
+                    }
+                }
+            }
+        }
+
+        let before = buffs.0.len();
+        buffs.0.retain(|buff| !buff.timer.finished());
+        if buffs.0.len() < before {
+            notifications.write(HudNotificationEvent::new(
+                "Regeneration wore off",
+                NotificationKind::Info,
+            ));
+        }
+    }
+}
+
+fn notify_item_use(
+    mut used_events: EventReader<ItemUsedEvent>,
+    mut failed_events: EventReader<ItemUseFailedEvent>,
+    mut notifications: EventWriter<HudNotificationEvent>,
+) {
+    for event in used_events.read() {
+        let message = match event.item_type {
+            CollectibleType::HealthPotion => format!("+{} health", HEALTH_POTION_HEAL),
+            CollectibleType::SurvivalKit => format!(
+                "Regeneration for {:.0}s",
+                SURVIVAL_KIT_BUFF_DURATION
+            ),
+            other => format!("Used {}", other.display_name()),
+        };
+        notifications.write(HudNotificationEvent::new(message, NotificationKind::Success));
+    }
+
+    for event in failed_events.read() {
+        notifications.write(HudNotificationEvent::new(
+            event.reason.message(event.item_type),
+            NotificationKind::Warning,
+        ));
+    }
+}
@@ -0,0 +1,627 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{HashMap, HashSet};
+
+use crate::assets::ModelAssets;
+use crate::constants::loot::{
+    CHEST_INTERACT_RADIUS, CHEST_LOOT_TABLE, CHEST_POSITIONS, ENEMY_LOOT_TABLE, LOOT_SCATTER_RADIUS, LOOT_SEED,
+    RARITY_WEIGHT_PER_LEVEL,
+};
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::collectibles::{
+    Collectible, CollectibleRotation, CollectibleType, FloatingItem, Sensor,
+};
+use crate::systems::combat::{EnemyDefeatedEvent, apply_damage};
+use crate::systems::equipment::{EquipmentEvent, EquipmentItem, EquipmentSlot, StatModifier};
+use crate::systems::interaction::{Interactable, Interacted};
+use crate::ui::hud::{HudNotificationEvent, NotificationKind};
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== LOOT MODEL =====
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+impl Rarity {
+    /// Number of tiers above Common, used for level scaling
+    fn tier(&self) -> u32 {
+        *self as u32
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Rarity::Common => "Common",
+            Rarity::Uncommon => "Uncommon",
+            Rarity::Rare => "Rare",
+            Rarity::Epic => "Epic",
+            Rarity::Legendary => "Legendary",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            Rarity::Common => Color::WHITE,
+            Rarity::Uncommon => Color::SUCCESS_GREEN,
+            Rarity::Rare => Color::srgb(0.3, 0.55, 1.0),
+            Rarity::Epic => Color::ELYSIUM_PURPLE,
+            Rarity::Legendary => Color::ELYSIUM_GOLD,
+        }
+    }
+}
+
+/// One weighted outcome of a loot roll
+#[derive(Clone, Debug)]
+pub struct LootEntry {
+    pub item_type: CollectibleType,
+    pub rarity: Rarity,
+    pub weight: u32,
+    pub min_count: u32,
+    pub max_count: u32,
+    /// Source level required before this entry can drop
+    pub min_level: u32,
+}
+
+impl LootEntry {
+    pub fn new(item_type: CollectibleType, rarity: Rarity, weight: u32) -> Self {
+        Self {
+            item_type,
+            rarity,
+            weight,
+            min_count: 1,
+            max_count: 1,
+            min_level: 1,
+        }
+    }
+
+    pub fn count(mut self, min: u32, max: u32) -> Self {
+        self.min_count = min;
+        self.max_count = max.max(min);
+        self
+    }
+
+    pub fn min_level(mut self, level: u32) -> Self {
+        self.min_level = level;
+        self
+    }
+
+    /// Weight after level scaling: rarer tiers become more likely at higher levels
+    fn scaled_weight(&self, level: u32) -> f32 {
+        let levels_above_first = level.saturating_sub(1) as f32;
+        self.weight as f32
+            * (1.0 + RARITY_WEIGHT_PER_LEVEL * levels_above_first * self.rarity.tier() as f32)
+    }
+}
+
+/// An item that came out of a roll
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LootDrop {
+    pub item_type: CollectibleType,
+    pub count: u32,
+    pub rarity: Rarity,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct LootTable {
+    pub entries: Vec<LootEntry>,
+    /// Number of weighted picks per roll
+    pub rolls: u32,
+    /// Weight of rolling nothing on a pick
+    pub empty_weight: u32,
+    /// Items that always drop, on top of the weighted picks
+    pub guaranteed: Vec<LootDrop>,
+}
+
+impl LootTable {
+    pub fn roll(&self, level: u32, rng: &mut impl Rng) -> Vec<LootDrop> {
+        let mut drops = self.guaranteed.clone();
+
+        let eligible: Vec<(&LootEntry, f32)> = self
+            .entries
+            .iter()
+            .filter(|entry| level >= entry.min_level)
+            .map(|entry| (entry, entry.scaled_weight(level)))
+            .collect();
+        let total: f32 = self.empty_weight as f32 + eligible.iter().map(|(_, w)| w).sum::<f32>();
+        if total <= 0.0 {
+            return drops;
+        }
+
+        for _ in 0..self.rolls {
+            let mut pick = rng.random_range(0.0..total);
+            if pick < self.empty_weight as f32 {
+                continue;
+            }
+            pick -= self.empty_weight as f32;
+
+            for (entry, weight) in &eligible {
+                if pick < *weight {
+                    drops.push(LootDrop {
+                        item_type: entry.item_type,
+                        count: rng.random_range(entry.min_count..=entry.max_count),
+                        rarity: entry.rarity,
+                    });
+                    break;
+                }
+                pick -= weight;
+            }
+        }
+
+        drops
+    }
+}
+
+/// All loot tables, looked up by name
+#[derive(Resource, Debug, Clone)]
+pub struct LootTables(pub HashMap<String, LootTable>);
+
+impl LootTables {
+    pub fn get(&self, name: &str) -> Option<&LootTable> {
+        self.0.get(name)
+    }
+}
+
+impl Default for LootTables {
+    fn default() -> Self {
+        let enemy = LootTable {
+            entries: vec![
+                LootEntry::new(CollectibleType::Coin, Rarity::Common, 60).count(1, 3),
+                LootEntry::new(CollectibleType::HealthPotion, Rarity::Uncommon, 25),
+                LootEntry::new(CollectibleType::SurvivalKit, Rarity::Rare, 8).min_level(2),
+                LootEntry::new(CollectibleType::Coin, Rarity::Epic, 3).count(10, 15),
+                LootEntry::new(CollectibleType::Book, Rarity::Legendary, 1).min_level(3),
+            ],
+            rolls: 2,
+            empty_weight: 30,
+            guaranteed: vec![LootDrop {
+                item_type: CollectibleType::Coin,
+                count: 1,
+                rarity: Rarity::Common,
+            }],
+        };
+
+        let chest = LootTable {
+            entries: vec![
+                LootEntry::new(CollectibleType::Coin, Rarity::Common, 40).count(3, 6),
+                LootEntry::new(CollectibleType::HealthPotion, Rarity::Uncommon, 30).count(1, 2),
+                LootEntry::new(CollectibleType::SurvivalKit, Rarity::Rare, 15),
+                LootEntry::new(CollectibleType::Coin, Rarity::Epic, 6).count(15, 25),
+                LootEntry::new(CollectibleType::Book, Rarity::Legendary, 2).min_level(2),
+            ],
+            rolls: 3,
+            empty_weight: 0,
+            guaranteed: vec![LootDrop {
+                item_type: CollectibleType::HealthPotion,
+                count: 1,
+                rarity: Rarity::Uncommon,
+            }],
+        };
+
+        Self(HashMap::from([
+            (ENEMY_LOOT_TABLE.to_string(), enemy),
+            (CHEST_LOOT_TABLE.to_string(), chest),
+        ]))
+    }
+}
+
+/// Seeded generator shared by every loot roll, reset at the start of a run
+#[derive(Resource)]
+pub struct LootRng(pub StdRng);
+
+impl Default for LootRng {
+    fn default() -> Self {
+        Self(StdRng::seed_from_u64(LOOT_SEED))
+    }
+}
+
+// ===== COMPONENTS & EVENTS =====
+
+/// Which table an enemy or chest drops from, and at what level
+#[derive(Component, Clone, Debug)]
+pub struct LootSource {
+    pub table: String,
+    pub level: u32,
+}
+
+impl LootSource {
+    pub fn new(table: impl Into<String>, level: u32) -> Self {
+        Self {
+            table: table.into(),
+            level,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct LootChest {
+    /// Index into `CHEST_POSITIONS`
+    pub index: usize,
+    pub opened: bool,
+}
+
+/// Gear found in the chest at `index`, equipped as soon as the chest opens
+pub fn chest_equipment(index: usize) -> Option<EquipmentItem> {
+    match index {
+        0 => Some(EquipmentItem::new(
+            "Iron Sword",
+            EquipmentSlot::Weapon,
+            vec![StatModifier::Damage(5.0)],
+        )),
+        1 => Some(EquipmentItem::new(
+            "Leather Armor",
+            EquipmentSlot::Armor,
+            vec![StatModifier::MaxHealth(20)],
+        )),
+        2 => Some(EquipmentItem::new(
+            "Feather Charm",
+            EquipmentSlot::Accessory,
+            vec![StatModifier::JumpImpulse(1.5), StatModifier::MoveSpeed(0.1)],
+        )),
+        _ => None,
+    }
+}
+
+/// Chests already opened this run, so they stay empty after a FightScene round trip
+#[derive(Resource, Default, Debug)]
+pub struct OpenedChests(pub HashSet<usize>);
+
+/// Request to open a chest entity
+#[derive(Event, Debug, Clone, Copy)]
+pub struct OpenChestEvent {
+    pub chest: Entity,
+}
+
+// ===== PLUGIN =====
+
+pub struct LootPlugin;
+
+impl Plugin for LootPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LootTables>()
+            .init_resource::<LootRng>()
+            .init_resource::<OpenedChests>()
+            .add_event::<OpenChestEvent>()
+            .add_systems(OnEnter(Screen::PreGameLoading), reset_loot_rng)
+            .add_systems(OnEnter(Screen::GamePlay), spawn_loot_chests)
+            .add_systems(
+                Update,
+                (drop_enemy_loot.after(apply_damage), open_chests),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn reset_loot_rng(mut rng: ResMut<LootRng>, mut opened: ResMut<OpenedChests>) {
+    *rng = LootRng::default();
+    opened.0.clear();
+}
+
+fn spawn_loot_chests(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    opened: Res<OpenedChests>,
+) {
+    let mesh = meshes.add(Cuboid::new(1.6, 1.0, 1.0));
+
+    for (index, position) in CHEST_POSITIONS.iter().enumerate() {
+        let is_opened = opened.0.contains(&index);
+        // Each chest gets its own material so opening one only darkens that chest
+        let material = materials.add(StandardMaterial {
+            base_color: chest_color(is_opened),
+            perceptual_roughness: 0.8,
+            ..default()
+        });
+
+        commands.spawn((
+            Name::new(format!("Loot Chest {}", index + 1)),
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material),
+            Transform::from_translation(Vec3::from_array(*position)),
+            Collider::cuboid(1.6, 1.0, 1.0),
+            RigidBody::Static,
+            LootChest {
+                index,
+                opened: is_opened,
+            },
+            LootSource::new(CHEST_LOOT_TABLE, 1),
+            Interactable {
+                enabled: !is_opened,
+                ..Interactable::new("chest", "to Open", CHEST_INTERACT_RADIUS)
+            },
+            PlayingScene,
+        ))
+        .observe(request_open_chest);
+    }
+}
+
+fn request_open_chest(trigger: Trigger<Interacted>, mut open_chest_events: EventWriter<OpenChestEvent>) {
+    open_chest_events.write(OpenChestEvent {
+        chest: trigger.target(),
+    });
+}
+
+fn chest_color(opened: bool) -> Color {
+    if opened {
+        Color::srgb(0.2, 0.13, 0.06)
+    } else {
+        Color::srgb(0.45, 0.28, 0.12)
+    }
+}
+
+fn drop_enemy_loot(
+    mut commands: Commands,
+    mut events: EventReader<EnemyDefeatedEvent>,
+    sources: Query<&LootSource>,
+    tables: Res<LootTables>,
+    mut rng: ResMut<LootRng>,
+    assets: Option<Res<ModelAssets>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut notifications: EventWriter<HudNotificationEvent>,
+    mut equipment_events: EventWriter<EquipmentEvent>,
+) {
+    let Some(assets) = assets else {
+        events.clear();
+        return;
+    };
+
+    for event in events.read() {
+        let source = sources
+            .get(event.entity)
+            .cloned()
+            .unwrap_or_else(|_| LootSource::new(ENEMY_LOOT_TABLE, 1));
+        let Some(table) = tables.get(&source.table) else {
+            warn!("Unknown loot table '{}'", source.table);
+            continue;
+        };
+
+        let drops = table.roll(source.level, &mut rng.0);
+        spawn_drops(
+            &mut commands,
+            &assets,
+            &mut meshes,
+            &mut materials,
+            &mut rng.0,
+            &drops,
+            event.position,
+            &mut notifications,
+        );
+    }
+}
+
+fn open_chests(
+    mut commands: Commands,
+    mut events: EventReader<OpenChestEvent>,
+    mut chests: Query<(
+        &mut LootChest,
+        &mut Interactable,
+        &LootSource,
+        &Transform,
+        &MeshMaterial3d<StandardMaterial>,
+    )>,
+    tables: Res<LootTables>,
+    mut rng: ResMut<LootRng>,
+    mut opened: ResMut<OpenedChests>,
+    assets: Option<Res<ModelAssets>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut notifications: EventWriter<HudNotificationEvent>,
+) {
+    let Some(assets) = assets else {
+        events.clear();
+        return;
+    };
+
+    for event in events.read() {
+        let Ok((mut chest, mut interactable, source, transform, material)) = chests.get_mut(event.chest) else {
+            continue;
+        };
+        if chest.opened {
+            continue;
+        }
+        chest.opened = true;
+        interactable.enabled = false;
+        opened.0.insert(chest.index);
+
+        // Darken the chest so opened ones are recognisable
+        if let Some(material) = materials.get_mut(&material.0) {
+            material.base_color = chest_color(true);
+        }
+
+        if let Some(item) = chest_equipment(chest.index) {
+            notifications.write(HudNotificationEvent::new(
+                format!("Equipped {}", item.name),
+                NotificationKind::Success,
+            ));
+            equipment_events.write(EquipmentEvent::Equip(item));
+        }
+
+        let Some(table) = tables.get(&source.table) else {
+            warn!("Unknown loot table '{}'", source.table);
+            continue;
+        };
+        let drops = table.roll(source.level, &mut rng.0);
+        spawn_drops(
+            &mut commands,
+            &assets,
+            &mut meshes,
+            &mut materials,
+            &mut rng.0,
+            &drops,
+            transform.translation + Vec3::Y,
+            &mut notifications,
+        );
+    }
+}
+
+// ===== SPAWNING =====
+
+/// Scatters rolled drops around `origin` as floating, rotating collectibles.
+/// Stacks become one pickup per item so each pickup adds one to the inventory.
+fn spawn_drops(
+    commands: &mut Commands,
+    assets: &ModelAssets,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    rng: &mut impl Rng,
+    drops: &[LootDrop],
+    origin: Vec3,
+    notifications: &mut EventWriter<HudNotificationEvent>,
+) {
+    for drop in drops {
+        if drop.rarity >= Rarity::Rare {
+            notifications.write(HudNotificationEvent::new(
+                format!("{} drop: {}", drop.rarity.display_name(), drop.item_type.display_name()),
+                NotificationKind::Success,
+            ));
+        }
+
+        for _ in 0..drop.count {
+            let angle = rng.random_range(0.0..std::f32::consts::TAU);
+            let distance = rng.random_range(0.0..LOOT_SCATTER_RADIUS);
+            let position = origin + Vec3::new(angle.cos() * distance, 1.0, angle.sin() * distance);
+            spawn_loot_collectible(commands, assets, meshes, materials, drop, position);
+        }
+    }
+}
+
+fn spawn_loot_collectible(
+    commands: &mut Commands,
+    assets: &ModelAssets,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    drop: &LootDrop,
+    position: Vec3,
+) {
+    let mut entity = commands.spawn((
+        Name::new(format!("Loot {}", drop.item_type.display_name())),
+        Transform::from_translation(position),
+        Collider::sphere(0.4),
+        RigidBody::Kinematic,
+        Visibility::Visible,
+        Collectible,
+        drop.item_type,
+        FloatingItem {
+            base_height: position.y,
+            hover_amplitude: 0.2,
+            hover_speed: 2.0,
+        },
+        CollectibleRotation {
+            enabled: true,
+            clockwise: true,
+            speed: 1.0,
+        },
+        Sensor,
+        CollisionEventsEnabled,
+    ));
+
+    // Only coins and the book have models; the rest use tinted primitives
+    let rarity_glow = drop.rarity.color().to_linear() * drop.rarity.tier() as f32;
+    match drop.item_type {
+        CollectibleType::Coin => {
+            entity.insert(SceneRoot(assets.coin.clone()));
+            entity.insert(Transform::from_translation(position).with_scale(Vec3::splat(0.75)));
+        }
+        CollectibleType::Book => {
+            entity.insert(SceneRoot(assets.book.clone()));
+        }
+        CollectibleType::HealthPotion => {
+            entity.insert((
+                Mesh3d(meshes.add(Sphere::new(0.3))),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: Color::DANGER_RED,
+                    emissive: rarity_glow,
+                    ..default()
+                })),
+            ));
+        }
+        CollectibleType::SurvivalKit => {
+            entity.insert((
+                Mesh3d(meshes.add(Cuboid::new(0.6, 0.4, 0.4))),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: Color::SUCCESS_GREEN,
+                    emissive: rarity_glow,
+                    ..default()
+                })),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_rolls_same_loot() {
+        let table = LootTables::default();
+        let chest = table.get(CHEST_LOOT_TABLE).unwrap();
+
+        let mut first = StdRng::seed_from_u64(7);
+        let mut second = StdRng::seed_from_u64(7);
+        for level in 1..5 {
+            assert_eq!(chest.roll(level, &mut first), chest.roll(level, &mut second));
+        }
+    }
+
+    #[test]
+    fn guaranteed_drops_always_come_first() {
+        let table = LootTable {
+            entries: vec![],
+            rolls: 5,
+            empty_weight: 1,
+            guaranteed: vec![LootDrop {
+                item_type: CollectibleType::Book,
+                count: 1,
+                rarity: Rarity::Legendary,
+            }],
+        };
+
+        let drops = table.roll(1, &mut StdRng::seed_from_u64(1));
+        assert_eq!(drops, table.guaranteed);
+    }
+
+    #[test]
+    fn entries_above_source_level_never_drop() {
+        let table = LootTable {
+            entries: vec![
+                LootEntry::new(CollectibleType::Coin, Rarity::Common, 1),
+                LootEntry::new(CollectibleType::Book, Rarity::Legendary, 1000).min_level(5),
+            ],
+            rolls: 50,
+            ..default()
+        };
+
+        let drops = table.roll(1, &mut StdRng::seed_from_u64(3));
+        assert_eq!(drops.len(), 50);
+        assert!(drops.iter().all(|drop| drop.item_type == CollectibleType::Coin));
+    }
+
+    #[test]
+    fn every_chest_holds_gear_for_a_different_slot() {
+        let slots: HashSet<_> = (0..CHEST_POSITIONS.len())
+            .map(|index| chest_equipment(index).expect("chest without gear").slot)
+            .collect();
+        assert_eq!(slots.len(), CHEST_POSITIONS.len());
+    }
+
+    #[test]
+    fn higher_levels_favour_rarer_tiers() {
+        let common = LootEntry::new(CollectibleType::Coin, Rarity::Common, 10);
+        let legendary = LootEntry::new(CollectibleType::Book, Rarity::Legendary, 10);
+
+        assert_eq!(common.scaled_weight(1), common.scaled_weight(10));
+        assert_eq!(legendary.scaled_weight(1), 10.0);
+        assert!(legendary.scaled_weight(10) > legendary.scaled_weight(2));
+    }
+}
@@ -1,8 +1,26 @@
 pub mod book_interaction;
+pub mod boss;
 pub mod character_controller;
 pub mod collectibles;
 pub mod collectibles_config;
+pub mod combat;
+pub mod death;
 pub mod dojo;
 pub mod enemy_ai;
+pub mod enemy_archetypes;
+pub mod equipment;
+pub mod interaction;
+pub mod inventory;
+pub mod items;
+pub mod loot;
 pub mod objectives;
+pub mod perception;
+pub mod progression;
+pub mod projectiles;
+pub mod quests;
+pub mod sacrifice;
+pub mod save;
+pub mod steering;
+pub mod turn_combat;
+pub mod waves;
 pub mod boundary;
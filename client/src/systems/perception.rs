@@ -0,0 +1,223 @@
+use avian3d::prelude::SpatialQuery;
+use bevy::prelude::*;
+
+use crate::constants::perception::{
+    COMBAT_NOISE_RADIUS, ENEMY_EYE_HEIGHT, FOOTSTEP_NOISE_INTERVAL, PERCEPTION_MEMORY,
+    PLAYER_VISIBLE_HEIGHT, PROXIMITY_SENSE_RANGE, RUNNING_NOISE_RADIUS, SIGHT_RANGE,
+    VIEW_HALF_ANGLE_DEGREES, WALKING_NOISE_RADIUS,
+};
+use crate::resources::audio::MovementAudioState;
+use crate::screens::Screen;
+use crate::systems::boundary::BoundaryConstraint;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::combat::{DamageEvent, Defeated};
+use crate::systems::death::Dead;
+use crate::systems::enemy_ai::Enemy;
+use crate::systems::projectiles::{PassThrough, has_line_of_sight};
+
+// ===== COMPONENTS =====
+
+/// What an enemy knows about the player: whether it can see them and where
+/// they were last seen or heard
+#[derive(Component, Debug, Clone)]
+pub struct Perception {
+    pub sight_range: f32,
+    /// Cosine of half the vision cone's angle
+    pub view_cos: f32,
+    /// Multiplier on the distance noises carry to this enemy
+    pub hearing: f32,
+    /// Seconds the last known position is remembered
+    pub memory: f32,
+    pub sees_player: bool,
+    pub last_known_position: Option<Vec3>,
+    since_noticed: f32,
+}
+
+impl Default for Perception {
+    fn default() -> Self {
+        Self {
+            sight_range: SIGHT_RANGE,
+            view_cos: VIEW_HALF_ANGLE_DEGREES.to_radians().cos(),
+            hearing: 1.0,
+            memory: PERCEPTION_MEMORY,
+            sees_player: false,
+            last_known_position: None,
+            since_noticed: 0.0,
+        }
+    }
+}
+
+impl Perception {
+    /// Perception of an enemy that already knows where the player is
+    pub fn alerted(position: Vec3) -> Self {
+        let mut perception = Self::default();
+        perception.notice(position);
+        perception
+    }
+
+    /// Whether a target at `offset` from the eyes is within range and inside the vision
+    /// cone around `forward`. Line of sight is checked separately.
+    pub fn in_view(&self, forward: Vec3, offset: Vec3) -> bool {
+        let distance = offset.length();
+        if distance <= PROXIMITY_SENSE_RANGE {
+            return true;
+        }
+        let flat = offset.with_y(0.0).normalize_or_zero();
+        distance <= self.sight_range && forward.with_y(0.0).normalize_or_zero().dot(flat) >= self.view_cos
+    }
+
+    pub fn hears(&self, distance: f32, radius: f32) -> bool {
+        distance <= radius * self.hearing
+    }
+
+    pub fn notice(&mut self, position: Vec3) {
+        self.last_known_position = Some(position);
+        self.since_noticed = 0.0;
+    }
+
+    /// Forgets the last known position once it is older than the enemy's memory
+    pub fn tick(&mut self, delta: f32) {
+        self.since_noticed += delta;
+        if self.since_noticed > self.memory {
+            self.last_known_position = None;
+        }
+    }
+}
+
+// ===== EVENTS =====
+
+/// A sound enemies within `radius` can hear, such as footsteps or a fight
+#[derive(Event, Debug, Clone, Copy)]
+pub struct NoiseEvent {
+    pub position: Vec3,
+    pub radius: f32,
+}
+
+// ===== PLUGIN =====
+
+pub struct PerceptionPlugin;
+
+impl Plugin for PerceptionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<NoiseEvent>().add_systems(
+            Update,
+            (emit_footstep_noise, emit_combat_noise, update_perception)
+                .chain()
+                .run_if(in_state(Screen::GamePlay).and(resource_exists::<BoundaryConstraint>)),
+        );
+    }
+}
+
+// ===== SYSTEMS =====
+
+/// The player's footsteps can be heard, much further while running
+fn emit_footstep_noise(
+    time: Res<Time>,
+    movement_state: Res<MovementAudioState>,
+    player_query: Query<&Transform, (With<CharacterController>, Without<Dead>)>,
+    mut noise_events: EventWriter<NoiseEvent>,
+    mut cooldown: Local<f32>,
+) {
+    *cooldown -= time.delta_secs();
+    if !movement_state.is_moving || *cooldown > 0.0 {
+        return;
+    }
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+
+    *cooldown = FOOTSTEP_NOISE_INTERVAL;
+    noise_events.write(NoiseEvent {
+        position: player_transform.translation,
+        radius: if movement_state.is_running {
+            RUNNING_NOISE_RADIUS
+        } else {
+            WALKING_NOISE_RADIUS
+        },
+    });
+}
+
+/// Every hit makes noise where the attacker stands
+fn emit_combat_noise(
+    mut damage_events: EventReader<DamageEvent>,
+    transforms: Query<&Transform>,
+    mut noise_events: EventWriter<NoiseEvent>,
+) {
+    for event in damage_events.read() {
+        let origin = event.source.unwrap_or(event.target);
+        if let Ok(transform) = transforms.get(origin) {
+            noise_events.write(NoiseEvent {
+                position: transform.translation,
+                radius: COMBAT_NOISE_RADIUS,
+            });
+        }
+    }
+}
+
+/// Updates what each enemy sees and hears of the player
+pub fn update_perception(
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+    pass_through: Query<(), PassThrough>,
+    mut noise_events: EventReader<NoiseEvent>,
+    mut enemy_query: Query<(Entity, &Transform, &mut Perception), (With<Enemy>, Without<Defeated>)>,
+    // Enemies lose interest in a dead player
+    player_query: Query<(Entity, &Transform), (With<CharacterController>, Without<Enemy>, Without<Dead>)>,
+) {
+    let Ok((player, player_transform)) = player_query.single() else {
+        for (_, _, mut perception) in &mut enemy_query {
+            perception.sees_player = false;
+        }
+        return;
+    };
+    let noises: Vec<NoiseEvent> = noise_events.read().copied().collect();
+    let target = player_transform.translation + Vec3::Y * PLAYER_VISIBLE_HEIGHT;
+
+    for (enemy, enemy_transform, mut perception) in &mut enemy_query {
+        perception.tick(time.delta_secs());
+
+        // Enemy models face +Z. Sight needs the player in the cone and no wall in between
+        let eyes = enemy_transform.translation + Vec3::Y * ENEMY_EYE_HEIGHT;
+        perception.sees_player = perception.in_view(enemy_transform.rotation * Vec3::Z, target - eyes)
+            && has_line_of_sight(&spatial_query, &pass_through, eyes, (player, target), enemy);
+        if perception.sees_player {
+            perception.notice(player_transform.translation);
+            continue;
+        }
+
+        // Otherwise the enemy goes to investigate the closest noise it heard
+        let heard = noises
+            .iter()
+            .map(|noise| (noise, noise.position.distance(enemy_transform.translation)))
+            .filter(|(noise, distance)| perception.hears(*distance, noise.radius))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b));
+        if let Some((noise, _)) = heard {
+            perception.notice(noise.position);
+        }
+    }
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sight_covers_the_cone_in_front_and_close_range_behind() {
+        let perception = Perception::default();
+        assert!(perception.in_view(Vec3::Z, Vec3::new(2.0, 0.0, 10.0)));
+        assert!(!perception.in_view(Vec3::Z, Vec3::new(0.0, 0.0, -10.0)));
+        assert!(!perception.in_view(Vec3::Z, Vec3::new(0.0, 0.0, SIGHT_RANGE + 1.0)));
+        assert!(perception.in_view(Vec3::Z, Vec3::new(0.0, 0.0, -2.0)));
+    }
+
+    #[test]
+    fn last_known_position_is_forgotten_after_memory() {
+        let mut perception = Perception::alerted(Vec3::X);
+        perception.tick(PERCEPTION_MEMORY - 1.0);
+        assert!(perception.last_known_position.is_some());
+        perception.tick(2.0);
+        assert!(perception.last_known_position.is_none());
+    }
+}
@@ -0,0 +1,245 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::player::{LEVEL_UP_MAX_HEALTH, XP_PER_KILL, XP_PER_LEVEL, XP_PER_PICKUP};
+use crate::game::Health;
+use crate::screens::Screen;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::combat::EnemyDefeatedEvent;
+use crate::systems::dojo::{PickupItemEvent, PlayerStatsUpdatedEvent};
+use crate::systems::equipment::derive_player_stats;
+use crate::ui::hud::{HudNotificationEvent, NotificationKind};
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// Level and lifetime experience of a character, following the contract's
+/// rule of one level per `XP_PER_LEVEL` experience
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Experience {
+    pub level: u32,
+    pub total: u32,
+}
+
+impl Default for Experience {
+    fn default() -> Self {
+        Self { level: 1, total: 0 }
+    }
+}
+
+impl Experience {
+    /// Experience earned towards the next level
+    pub fn progress(&self) -> u32 {
+        self.total % XP_PER_LEVEL
+    }
+
+    /// Adds experience and returns how many levels were gained
+    pub fn add(&mut self, amount: u32) -> u32 {
+        self.total = self.total.saturating_add(amount);
+        let level = 1 + self.total / XP_PER_LEVEL;
+        let gained = level.saturating_sub(self.level);
+        self.level = self.level.max(level);
+        gained
+    }
+}
+
+/// Which progress to keep when the chain reports `on_chain`. The chain only
+/// catches up with what the client already earned, so it never lowers progress.
+pub fn reconciled_progress(local: Experience, on_chain: Experience) -> Experience {
+    if on_chain.total > local.total {
+        on_chain
+    } else {
+        local
+    }
+}
+
+/// Max health granted by levels on top of the base stats
+pub fn level_max_health_bonus(level: u32) -> u32 {
+    level.saturating_sub(1) * LEVEL_UP_MAX_HEALTH
+}
+
+/// The player's experience for the current run. The player entity is respawned
+/// between scenes, so its `Experience` component is restored from here.
+#[derive(Resource, Default, Debug)]
+pub struct PlayerProgress(pub Experience);
+
+// ===== EVENTS =====
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct XpGainEvent {
+    pub amount: u32,
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct LevelUpEvent {
+    pub level: u32,
+}
+
+// ===== PLUGIN =====
+
+pub struct ProgressionPlugin;
+
+impl Plugin for ProgressionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayerProgress>()
+            .add_event::<XpGainEvent>()
+            .add_event::<LevelUpEvent>()
+            .add_systems(OnEnter(Screen::PreGameLoading), reset_progress)
+            .add_systems(
+                Update,
+                (
+                    restore_experience,
+                    award_experience,
+                    apply_xp_gains,
+                    reconcile_with_chain,
+                )
+                    .chain()
+                    .before(derive_player_stats),
+            )
+            .add_systems(
+                Update,
+                (heal_on_level_up, notify_level_up).after(derive_player_stats),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn reset_progress(mut progress: ResMut<PlayerProgress>) {
+    *progress = PlayerProgress::default();
+}
+
+fn restore_experience(
+    progress: Res<PlayerProgress>,
+    mut query: Query<&mut Experience, (With<CharacterController>, Added<Experience>)>,
+) {
+    for mut experience in &mut query {
+        *experience = progress.0;
+    }
+}
+
+/// Turns pickups and kills into experience
+fn award_experience(
+    mut pickups: EventReader<PickupItemEvent>,
+    mut kills: EventReader<EnemyDefeatedEvent>,
+    mut xp_events: EventWriter<XpGainEvent>,
+) {
+    for _ in pickups.read() {
+        xp_events.write(XpGainEvent {
+            amount: XP_PER_PICKUP,
+        });
+    }
+    for _ in kills.read() {
+        xp_events.write(XpGainEvent { amount: XP_PER_KILL });
+    }
+}
+
+fn apply_xp_gains(
+    mut xp_events: EventReader<XpGainEvent>,
+    mut progress: ResMut<PlayerProgress>,
+    mut player_query: Query<&mut Experience, With<CharacterController>>,
+    mut level_up_events: EventWriter<LevelUpEvent>,
+) {
+    for event in xp_events.read() {
+        let levels_gained = progress.0.add(event.amount);
+        for offset in (0..levels_gained).rev() {
+            level_up_events.write(LevelUpEvent {
+                level: progress.0.level - offset,
+            });
+        }
+    }
+
+    if progress.is_changed() {
+        for mut experience in &mut player_query {
+            if *experience != progress.0 {
+                *experience = progress.0;
+            }
+        }
+    }
+}
+
+/// Adopt the on-chain level and experience when `PlayerStats` is ahead of the
+/// local prediction, e.g. after resuming a game on another device
+fn reconcile_with_chain(
+    mut stats_events: EventReader<PlayerStatsUpdatedEvent>,
+    mut progress: ResMut<PlayerProgress>,
+    mut player_query: Query<&mut Experience, With<CharacterController>>,
+) {
+    for event in stats_events.read() {
+        let on_chain = Experience {
+            level: event.level.max(1),
+            total: event.experience,
+        };
+        let reconciled = reconciled_progress(progress.0, on_chain);
+        if progress.0 == reconciled {
+            continue;
+        }
+
+        warn!(
+            "Local progression {:?} is behind chain {:?}, resyncing",
+            progress.0, on_chain
+        );
+        progress.0 = reconciled;
+        for mut experience in &mut player_query {
+            *experience = reconciled;
+        }
+    }
+}
+
+/// A level up fully restores health, after `derive_player_stats` raised the max
+fn heal_on_level_up(
+    mut level_up_events: EventReader<LevelUpEvent>,
+    mut player_query: Query<&mut Health, With<CharacterController>>,
+) {
+    if level_up_events.read().last().is_none() {
+        return;
+    }
+    for mut health in &mut player_query {
+        health.current = health.max;
+    }
+}
+
+fn notify_level_up(
+    mut level_up_events: EventReader<LevelUpEvent>,
+    mut notifications: EventWriter<HudNotificationEvent>,
+) {
+    for event in level_up_events.read() {
+        notifications.write(HudNotificationEvent::new(
+            format!("Level up! Now level {} (+{} max health)", event.level, LEVEL_UP_MAX_HEALTH),
+            NotificationKind::Success,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_hundred_experience_is_a_level() {
+        let mut experience = Experience::default();
+        assert_eq!(experience.add(90), 0);
+        assert_eq!(experience.add(10), 1);
+        assert_eq!(experience.level, 2);
+        assert_eq!(experience.progress(), 0);
+
+        assert_eq!(experience.add(250), 2);
+        assert_eq!(experience.level, 4);
+        assert_eq!(experience.progress(), 50);
+    }
+
+    #[test]
+    fn stale_chain_stats_never_lower_local_progress() {
+        let local = Experience { level: 2, total: 130 };
+        let stale = Experience::default();
+        assert_eq!(reconciled_progress(local, stale), local);
+
+        let ahead = Experience { level: 3, total: 210 };
+        assert_eq!(reconciled_progress(local, ahead), ahead);
+    }
+
+    #[test]
+    fn level_bonus_starts_at_level_two() {
+        assert_eq!(level_max_health_bonus(1), 0);
+        assert_eq!(level_max_health_bonus(3), 2 * LEVEL_UP_MAX_HEALTH);
+    }
+}
@@ -0,0 +1,170 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use crate::constants::combat::{PROJECTILE_LIFETIME, PROJECTILE_RADIUS, PROJECTILE_SPEED};
+use crate::game::Health;
+use crate::resources::audio::{PlaySfxEvent, SfxType};
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::collectibles::Collectible;
+use crate::systems::combat::DamageEvent;
+use crate::systems::enemy_ai::Enemy;
+
+/// Most hits looked at when searching past pass-through colliders
+const MAX_QUERY_HITS: u32 = 8;
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// A shot travelling in a straight line until it hits something or expires
+#[derive(Component)]
+pub struct Projectile {
+    pub damage: u32,
+    /// The shooter, which its own shots pass through
+    pub source: Entity,
+    /// Seconds left before the shot fizzles out
+    pub lifetime: f32,
+}
+
+/// Shared mesh and material of every projectile
+#[derive(Resource)]
+pub struct ProjectileVisuals {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+/// Colliders that neither block sight nor stop projectiles
+pub type PassThrough = Or<(With<Sensor>, With<Collectible>, With<Projectile>)>;
+
+// ===== PLUGIN =====
+
+pub struct ProjectilesPlugin;
+
+impl Plugin for ProjectilesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_projectile_visuals).add_systems(
+            Update,
+            advance_projectiles.run_if(in_state(Screen::GamePlay)),
+        );
+    }
+}
+
+// ===== SPAWNING =====
+
+/// Fires a projectile from `origin`; the caller plays the firing sound
+pub fn spawn_projectile(
+    commands: &mut Commands,
+    visuals: &ProjectileVisuals,
+    origin: Vec3,
+    direction: Dir3,
+    damage: u32,
+    source: Entity,
+) {
+    commands.spawn((
+        Name::new("Projectile"),
+        Projectile {
+            damage,
+            source,
+            lifetime: PROJECTILE_LIFETIME,
+        },
+        Mesh3d(visuals.mesh.clone()),
+        MeshMaterial3d(visuals.material.clone()),
+        Transform::from_translation(origin),
+        // Moved by its velocity and never pushed around; hits come from shape casts
+        RigidBody::Kinematic,
+        Collider::sphere(PROJECTILE_RADIUS),
+        Sensor,
+        LinearVelocity(direction * PROJECTILE_SPEED),
+        PlayingScene,
+    ));
+}
+
+/// Whether `target` is the first solid thing on the straight line from `from`
+pub fn has_line_of_sight(
+    spatial_query: &SpatialQuery,
+    pass_through: &Query<(), PassThrough>,
+    from: Vec3,
+    target: (Entity, Vec3),
+    shooter: Entity,
+) -> bool {
+    let (target, to) = target;
+    let Ok((direction, distance)) = Dir3::new_and_length(to - from) else {
+        return true;
+    };
+    let filter = SpatialQueryFilter::default().with_excluded_entities([shooter]);
+    let mut hits = spatial_query.ray_hits(from, direction, distance, MAX_QUERY_HITS, true, &filter);
+    hits.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+    hits.iter()
+        .find(|hit| !pass_through.contains(hit.entity))
+        .is_none_or(|hit| hit.entity == target)
+}
+
+// ===== SYSTEMS =====
+
+fn setup_projectile_visuals(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(ProjectileVisuals {
+        mesh: meshes.add(Sphere::new(PROJECTILE_RADIUS)),
+        material: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.9, 0.4, 0.1),
+            emissive: LinearRgba::rgb(6.0, 2.0, 0.4),
+            ..default()
+        }),
+    });
+}
+
+/// Casts each projectile along this frame's travel; the first solid hit ends it
+/// and damages the target if it can be hurt
+fn advance_projectiles(
+    mut commands: Commands,
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+    mut projectiles: Query<(Entity, &Transform, &LinearVelocity, &mut Projectile)>,
+    pass_through: Query<(), PassThrough>,
+    targets: Query<(), (With<Health>, Without<Enemy>)>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut sfx_events: EventWriter<PlaySfxEvent>,
+) {
+    let delta = time.delta_secs();
+    let shape = Collider::sphere(PROJECTILE_RADIUS);
+
+    for (entity, transform, velocity, mut projectile) in &mut projectiles {
+        projectile.lifetime -= delta;
+        if projectile.lifetime <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let Ok((direction, speed)) = Dir3::new_and_length(velocity.0) else {
+            continue;
+        };
+
+        let filter = SpatialQueryFilter::default().with_excluded_entities([entity, projectile.source]);
+        let mut hits = spatial_query.shape_hits(
+            &shape,
+            transform.translation,
+            Quat::IDENTITY,
+            direction,
+            MAX_QUERY_HITS,
+            &ShapeCastConfig::from_max_distance(speed * delta),
+            &filter,
+        );
+        hits.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        let Some(hit) = hits.iter().find(|hit| !pass_through.contains(hit.entity)) else {
+            continue;
+        };
+
+        if targets.contains(hit.entity) {
+            damage_events.write(DamageEvent {
+                target: hit.entity,
+                amount: projectile.damage,
+                source: Some(projectile.source),
+            });
+        }
+        sfx_events.write(PlaySfxEvent {
+            sfx_type: SfxType::ProjectileImpact,
+        });
+        commands.entity(entity).despawn();
+    }
+}
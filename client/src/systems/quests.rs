@@ -0,0 +1,125 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::objectives::LOCATION_TRIGGER_RADIUS;
+use crate::systems::objectives::Objective;
+
+// ===== QUEST DATA =====
+
+/// Quest definitions for the whole campaign, authored in `assets/data/*.quests.json`
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
+pub struct QuestData {
+    pub levels: Vec<LevelQuests>,
+}
+
+impl QuestData {
+    pub fn level(&self, level: u32) -> Option<&LevelQuests> {
+        self.levels.iter().find(|quests| quests.level == level)
+    }
+}
+
+/// Objectives and named locations of one level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LevelQuests {
+    pub level: u32,
+    pub name: String,
+    #[serde(default)]
+    pub locations: Vec<QuestLocation>,
+    pub objectives: Vec<Objective>,
+}
+
+/// A named point or region that `reach` goals refer to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestLocation {
+    pub id: String,
+    pub position: [f32; 3],
+    #[serde(default = "default_location_radius")]
+    pub radius: f32,
+    /// Turns the location into a box-shaped region of this half size
+    #[serde(default)]
+    pub half_extents: Option<[f32; 3]>,
+}
+
+fn default_location_radius() -> f32 {
+    LOCATION_TRIGGER_RADIUS
+}
+
+/// Something granted when an objective completes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum QuestReward {
+    Experience { amount: u32 },
+    Item {
+        item: crate::systems::collectibles::CollectibleType,
+        count: u32,
+    },
+    Gold { amount: u32 },
+}
+
+impl QuestReward {
+    pub fn label(&self) -> String {
+        match self {
+            QuestReward::Experience { amount } => format!("{} XP", amount),
+            QuestReward::Item { item, count } => format!("{} x{}", item.display_name(), count),
+            QuestReward::Gold { amount } => format!("{} Gold", amount),
+        }
+    }
+}
+
+// ===== LOADER =====
+
+#[derive(Default)]
+pub struct QuestDataLoader;
+
+#[derive(Debug)]
+pub enum QuestDataLoaderError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+}
+
+impl std::fmt::Display for QuestDataLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuestDataLoaderError::Io(err) => write!(f, "could not read quest file: {}", err),
+            QuestDataLoaderError::Json(err) => write!(f, "invalid quest file: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for QuestDataLoaderError {}
+
+impl From<std::io::Error> for QuestDataLoaderError {
+    fn from(err: std::io::Error) -> Self {
+        QuestDataLoaderError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for QuestDataLoaderError {
+    fn from(err: serde_json::Error) -> Self {
+        QuestDataLoaderError::Json(err)
+    }
+}
+
+impl AssetLoader for QuestDataLoader {
+    type Asset = QuestData;
+    type Settings = ();
+    type Error = QuestDataLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let data: QuestData = serde_json::from_slice(&bytes)?;
+        Ok(data)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["quests.json"]
+    }
+}
@@ -0,0 +1,233 @@
+use bevy::prelude::*;
+use std::collections::BTreeMap;
+
+use crate::constants::sacrifice::{
+    SACRIFICE_HEALTH_PER_VALUE, SACRIFICE_REQUIRED_VALUE, SACRIFICE_VALUE_PER_LOOT_LEVEL,
+};
+use crate::screens::Screen;
+use crate::systems::collectibles::CollectibleType;
+use crate::systems::inventory::{Inventory, InventoryEvent, ItemStack};
+use crate::ui::hud::{HudNotificationEvent, NotificationKind};
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// How much an item is worth when burned at the book
+pub fn sacrifice_value(item_type: CollectibleType) -> u32 {
+    match item_type {
+        CollectibleType::Coin => 1,
+        CollectibleType::HealthPotion => 4,
+        CollectibleType::SurvivalKit => 6,
+        CollectibleType::Book => 10,
+    }
+}
+
+/// State of the sacrifice menu opened at the book: whether it is shown and how
+/// many of each item the player picked to burn
+#[derive(Resource, Default, Debug)]
+pub struct SacrificeMenu {
+    pub open: bool,
+    selection: BTreeMap<CollectibleType, u32>,
+}
+
+impl SacrificeMenu {
+    pub fn selected(&self, item_type: CollectibleType) -> u32 {
+        self.selection.get(&item_type).copied().unwrap_or(0)
+    }
+
+    /// Changes the selected amount of an item, kept between zero and what the player owns
+    pub fn adjust(&mut self, item_type: CollectibleType, delta: i32, owned: u32) {
+        let selected = (self.selected(item_type) as i64 + delta as i64).clamp(0, owned as i64) as u32;
+        if selected == 0 {
+            self.selection.remove(&item_type);
+        } else {
+            self.selection.insert(item_type, selected);
+        }
+    }
+
+    pub fn value(&self) -> u32 {
+        self.selection
+            .iter()
+            .map(|(item_type, count)| sacrifice_value(*item_type) * count)
+            .sum()
+    }
+
+    pub fn meets_cost(&self) -> bool {
+        self.value() >= SACRIFICE_REQUIRED_VALUE
+    }
+
+    pub fn stacks(&self) -> Vec<ItemStack> {
+        self.selection
+            .iter()
+            .map(|(&item_type, &count)| ItemStack { item_type, count })
+            .collect()
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+        self.selection.clear();
+    }
+}
+
+/// What the player burned to enter the realm; the fight scales with its value
+#[derive(Resource, Default, Debug, Clone)]
+pub struct SacrificeOffering {
+    pub items: Vec<ItemStack>,
+    pub value: u32,
+}
+
+impl SacrificeOffering {
+    fn surplus(&self) -> u32 {
+        self.value.saturating_sub(SACRIFICE_REQUIRED_VALUE)
+    }
+
+    /// Bigger offerings summon a tougher enemy...
+    pub fn enemy_health_multiplier(&self) -> f32 {
+        1.0 + self.surplus() as f32 * SACRIFICE_HEALTH_PER_VALUE
+    }
+
+    /// ...that drops better loot
+    pub fn loot_level(&self) -> u32 {
+        1 + self.surplus() / SACRIFICE_VALUE_PER_LOOT_LEVEL
+    }
+}
+
+// ===== EVENTS =====
+
+/// The player confirmed the selection in the sacrifice menu
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ConfirmSacrificeEvent;
+
+/// Items were burned locally; mirrored on chain when connected
+#[derive(Event, Debug, Clone)]
+pub struct ItemsSacrificedEvent {
+    pub items: Vec<ItemStack>,
+    pub value: u32,
+}
+
+// ===== PLUGIN =====
+
+pub struct SacrificePlugin;
+
+impl Plugin for SacrificePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SacrificeMenu>()
+            .init_resource::<SacrificeOffering>()
+            .add_event::<ConfirmSacrificeEvent>()
+            .add_event::<ItemsSacrificedEvent>()
+            .add_systems(OnEnter(Screen::PreGameLoading), reset_offering)
+            .add_systems(OnExit(Screen::FightScene), reset_offering)
+            .add_systems(OnExit(Screen::GamePlay), close_sacrifice_menu)
+            .add_systems(
+                Update,
+                (
+                    burn_offering,
+                    crate::ui::sacrifice::sync_sacrifice_menu,
+                )
+                    .chain()
+                    .run_if(in_state(Screen::GamePlay)),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn reset_offering(mut offering: ResMut<SacrificeOffering>) {
+    *offering = SacrificeOffering::default();
+}
+
+fn close_sacrifice_menu(mut menu: ResMut<SacrificeMenu>) {
+    menu.close();
+}
+
+/// Burns the selected items and opens the realm, if the offering covers the cost
+fn burn_offering(
+    mut confirm_events: EventReader<ConfirmSacrificeEvent>,
+    mut menu: ResMut<SacrificeMenu>,
+    inventory: Res<Inventory>,
+    mut inventory_events: EventWriter<InventoryEvent>,
+    mut sacrificed_events: EventWriter<ItemsSacrificedEvent>,
+    mut offering: ResMut<SacrificeOffering>,
+    mut notifications: EventWriter<HudNotificationEvent>,
+    mut next_state: ResMut<NextState<Screen>>,
+) {
+    if confirm_events.read().last().is_none() || !menu.open {
+        return;
+    }
+
+    if !menu.meets_cost() {
+        notifications.write(HudNotificationEvent::new(
+            format!(
+                "The book demands more: {} / {}",
+                menu.value(),
+                SACRIFICE_REQUIRED_VALUE
+            ),
+            NotificationKind::Warning,
+        ));
+        return;
+    }
+
+    let items = menu.stacks();
+    // The inventory may have changed while the menu was open
+    if items
+        .iter()
+        .any(|stack| inventory.count(stack.item_type) < stack.count)
+    {
+        notifications.write(HudNotificationEvent::new(
+            "You no longer carry everything you offered",
+            NotificationKind::Warning,
+        ));
+        return;
+    }
+
+    for stack in &items {
+        inventory_events.write(InventoryEvent::Remove {
+            item_type: stack.item_type,
+            count: stack.count,
+        });
+    }
+
+    *offering = SacrificeOffering {
+        items: items.clone(),
+        value: menu.value(),
+    };
+    sacrificed_events.write(ItemsSacrificedEvent {
+        items,
+        value: offering.value,
+    });
+    menu.close();
+    next_state.set(Screen::FightScene);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selection_is_clamped_to_owned_items() {
+        let mut menu = SacrificeMenu::default();
+        menu.adjust(CollectibleType::HealthPotion, 5, 2);
+        assert_eq!(menu.selected(CollectibleType::HealthPotion), 2);
+
+        menu.adjust(CollectibleType::HealthPotion, -3, 2);
+        assert_eq!(menu.selected(CollectibleType::HealthPotion), 0);
+        assert!(menu.stacks().is_empty());
+    }
+
+    #[test]
+    fn offering_value_decides_cost_and_scaling() {
+        let mut menu = SacrificeMenu::default();
+        menu.adjust(CollectibleType::Coin, 6, 10);
+        assert!(!menu.meets_cost());
+
+        menu.adjust(CollectibleType::HealthPotion, 1, 1);
+        assert_eq!(menu.value(), 10);
+        assert!(menu.meets_cost());
+
+        let offering = SacrificeOffering {
+            items: menu.stacks(),
+            value: SACRIFICE_REQUIRED_VALUE + 2 * SACRIFICE_VALUE_PER_LOOT_LEVEL,
+        };
+        assert_eq!(offering.loot_level(), 3);
+        assert!(offering.enemy_health_multiplier() > 1.0);
+    }
+}
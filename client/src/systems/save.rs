@@ -0,0 +1,474 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use avian3d::prelude::LinearVelocity;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::de::Error as _;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::assets::DataAssets;
+use crate::constants::save::{GAME_DATA_DIR, SAVE_SLOTS, SAVE_VERSION, SAVES_DIR};
+use crate::screens::Screen;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::collectibles::CoinStreamingManager;
+use crate::systems::death::{Checkpoint, Dead, RunStats, update_checkpoint};
+use crate::systems::inventory::{Inventory, ItemStack, Wallet};
+use crate::systems::objectives::{Objective, ObjectiveManager};
+use crate::systems::progression::{Experience, PlayerProgress};
+use crate::systems::quests::QuestData;
+use crate::ui::hud::{HudNotificationEvent, NotificationKind};
+
+// ===== SAVE FORMAT =====
+
+/// Everything needed to pick a run back up, written to disk as JSON.
+///
+/// Bump `SAVE_VERSION` whenever a field changes and add a step to `MIGRATIONS`
+/// so older saves keep loading.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SaveGame {
+    pub version: u32,
+    /// Seconds since the Unix epoch when the save was written
+    pub saved_at: u64,
+    pub player_position: [f32; 3],
+    pub checkpoint: Option<[f32; 3]>,
+    pub inventory: Vec<Option<ItemStack>>,
+    pub gold: u32,
+    pub experience: Experience,
+    pub stats: RunStats,
+    pub objectives: SavedObjectives,
+    pub coins: SavedCoins,
+}
+
+impl SaveGame {
+    /// One line description for the load menu, e.g. "Level 2 | 34 coins | 05:12"
+    pub fn label(&self) -> String {
+        let elapsed = self.stats.elapsed as u32;
+        format!(
+            "Level {} | {} coins | {:02}:{:02}",
+            self.experience.level,
+            self.stats.coins,
+            elapsed / 60,
+            elapsed % 60
+        )
+    }
+}
+
+/// The quest level being played and the progress of its objectives
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct SavedObjectives {
+    pub level: u32,
+    pub objectives: Vec<Objective>,
+}
+
+/// Coin positions are rolled at random for every run, so they are saved along
+/// with the indices of the ones already picked up
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct SavedCoins {
+    pub positions: Vec<[f32; 3]>,
+    pub collected: Vec<usize>,
+}
+
+/// Upgrades a save by one version. Entry `n` turns a version `n + 1` save into version `n + 2`.
+/// Empty while version 1 is the only format.
+const MIGRATIONS: &[fn(&mut Map<String, Value>)] = &[];
+
+/// Reads a save of any known version, migrating it to the current one
+pub fn parse_save(text: &str) -> Result<SaveGame, SaveError> {
+    let mut value: Value = serde_json::from_str(text)?;
+    let Some(fields) = value.as_object_mut() else {
+        return Err(serde_json::Error::custom("save is not a JSON object").into());
+    };
+
+    // A save without a version field is read as the first version
+    let version = fields.get("version").and_then(Value::as_u64).unwrap_or(1) as u32;
+    if version > SAVE_VERSION {
+        return Err(SaveError::UnsupportedVersion(version));
+    }
+    for migrate in &MIGRATIONS[version.max(1) as usize - 1..] {
+        migrate(fields);
+    }
+    fields.insert("version".into(), SAVE_VERSION.into());
+
+    Ok(serde_json::from_value(value)?)
+}
+
+// ===== STORAGE =====
+
+#[derive(Debug)]
+pub enum SaveError {
+    /// The platform has no data directory to save into
+    NoDataDir,
+    Io(io::Error),
+    Json(serde_json::Error),
+    /// Written by a newer build of the game
+    UnsupportedVersion(u32),
+}
+
+impl std::fmt::Display for SaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::NoDataDir => write!(f, "no data directory to save into"),
+            SaveError::Io(err) => write!(f, "could not access save file: {}", err),
+            SaveError::Json(err) => write!(f, "invalid save file: {}", err),
+            SaveError::UnsupportedVersion(version) => {
+                write!(f, "save version {} is newer than this game supports", version)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<io::Error> for SaveError {
+    fn from(err: io::Error) -> Self {
+        SaveError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(err: serde_json::Error) -> Self {
+        SaveError::Json(err)
+    }
+}
+
+/// The game's directory under a per-user base directory, following each platform's
+/// convention. On Linux and other XDG systems the base is `xdg_var`, or `fallback`
+/// under the home directory when it is unset.
+pub fn platform_dir(xdg_var: &str, fallback: &str) -> Option<PathBuf> {
+    let base = if cfg!(target_os = "windows") {
+        env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        env::var_os("HOME").map(|home| PathBuf::from(home).join("Library/Application Support"))
+    } else {
+        env::var_os(xdg_var)
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(fallback)))
+    };
+    base.map(|base| base.join(GAME_DATA_DIR))
+}
+
+/// Per-user directory for the game's files
+pub fn data_dir() -> Option<PathBuf> {
+    platform_dir("XDG_DATA_HOME", ".local/share")
+}
+
+fn slot_path(slot: usize) -> Result<PathBuf, SaveError> {
+    let dir = data_dir().ok_or(SaveError::NoDataDir)?;
+    Ok(dir.join(SAVES_DIR).join(format!("slot_{}.json", slot + 1)))
+}
+
+/// Writes a slot through a temporary file so a crash mid-write cannot corrupt it
+pub fn write_slot(slot: usize, save: &SaveGame) -> Result<(), SaveError> {
+    let path = slot_path(slot)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let temp = path.with_extension("json.tmp");
+    fs::write(&temp, serde_json::to_string_pretty(save)?)?;
+    fs::rename(&temp, &path)?;
+    Ok(())
+}
+
+/// Reads a slot, returning `None` if nothing has been saved there
+pub fn read_slot(slot: usize) -> Result<Option<SaveGame>, SaveError> {
+    match fs::read_to_string(slot_path(slot)?) {
+        Ok(text) => parse_save(&text).map(Some),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// The contents of every slot. Unreadable slots are logged and shown as empty.
+pub fn read_all_slots() -> Vec<Option<SaveGame>> {
+    (0..SAVE_SLOTS)
+        .map(|slot| {
+            read_slot(slot).unwrap_or_else(|err| {
+                warn!("Could not read save slot {}: {}", slot + 1, err);
+                None
+            })
+        })
+        .collect()
+}
+
+/// The most recently written slot, which the main menu's Continue resumes
+pub fn latest_slot(slots: &[Option<SaveGame>]) -> Option<(usize, &SaveGame)> {
+    slots
+        .iter()
+        .enumerate()
+        .filter_map(|(slot, save)| save.as_ref().map(|save| (slot, save)))
+        .max_by_key(|(_, save)| save.saved_at)
+}
+
+/// Slot a new game autosaves into: the first empty one, otherwise the oldest
+pub fn fresh_slot(slots: &[Option<SaveGame>]) -> usize {
+    slots
+        .iter()
+        .position(Option::is_none)
+        .or_else(|| {
+            slots
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, save)| save.as_ref().map_or(0, |save| save.saved_at))
+                .map(|(slot, _)| slot)
+        })
+        .unwrap_or(0)
+}
+
+// ===== RESOURCES =====
+
+/// Slot the current run autosaves into
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct ActiveSaveSlot(pub usize);
+
+/// A save chosen in the main menu, applied once the level has loaded
+#[derive(Resource, Debug, Clone)]
+pub struct PendingLoad(pub SaveGame);
+
+/// The state of the run that goes into a save
+#[derive(SystemParam)]
+struct RunSnapshot<'w, 's> {
+    inventory: Res<'w, Inventory>,
+    wallet: Res<'w, Wallet>,
+    progress: Res<'w, PlayerProgress>,
+    stats: Res<'w, RunStats>,
+    checkpoint: Res<'w, Checkpoint>,
+    objectives: Res<'w, ObjectiveManager>,
+    streaming_manager: Res<'w, CoinStreamingManager>,
+    player_query: Query<'w, 's, (&'static Transform, Has<Dead>), With<CharacterController>>,
+}
+
+impl RunSnapshot<'_, '_> {
+    fn capture(&self) -> Option<SaveGame> {
+        let (transform, dead) = self.player_query.single().ok()?;
+        // A dead player is saved where they will respawn rather than where they fell
+        let position = match (dead, self.checkpoint.0) {
+            (true, Some(checkpoint)) => checkpoint,
+            _ => transform.translation,
+        };
+        let mut collected: Vec<usize> = self.streaming_manager.collected_positions.iter().copied().collect();
+        collected.sort_unstable();
+
+        Some(SaveGame {
+            version: SAVE_VERSION,
+            saved_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_secs()),
+            player_position: position.to_array(),
+            checkpoint: self.checkpoint.0.map(Vec3::to_array),
+            inventory: self.inventory.slots().to_vec(),
+            gold: self.wallet.gold,
+            experience: self.progress.0,
+            stats: *self.stats,
+            objectives: SavedObjectives {
+                level: self.objectives.current_level,
+                objectives: self.objectives.objectives.clone(),
+            },
+            coins: SavedCoins {
+                positions: self.streaming_manager.positions.iter().map(|position| position.to_array()).collect(),
+                collected,
+            },
+        })
+    }
+
+    /// Writes the run to the active slot, returning whether it was saved
+    fn save(&self, slot: ActiveSaveSlot) -> bool {
+        let Some(save) = self.capture() else {
+            return false;
+        };
+        match write_slot(slot.0, &save) {
+            Ok(()) => {
+                info!("Saved the run to slot {}", slot.0 + 1);
+                true
+            }
+            Err(err) => {
+                warn!("Could not save to slot {}: {}", slot.0 + 1, err);
+                false
+            }
+        }
+    }
+}
+
+// ===== PLUGIN =====
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActiveSaveSlot>()
+            // The level and the run's resources are reset while loading, so a save is
+            // applied on the way out of the loading screen
+            .add_systems(
+                OnExit(Screen::PreGameLoading),
+                restore_run.run_if(resource_exists::<PendingLoad>),
+            )
+            .add_systems(OnExit(Screen::GamePlay), autosave_on_exit)
+            .add_systems(
+                Update,
+                (
+                    restore_player_position
+                        .before(update_checkpoint)
+                        .run_if(resource_exists::<PendingLoad>),
+                    autosave_on_checkpoint
+                        .after(update_checkpoint)
+                        .run_if(not(resource_exists::<PendingLoad>)),
+                )
+                    .chain()
+                    .run_if(in_state(Screen::GamePlay)),
+            )
+            .add_systems(Last, autosave_on_app_exit.run_if(in_state(Screen::GamePlay)));
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn restore_run(
+    pending: Res<PendingLoad>,
+    mut inventory: ResMut<Inventory>,
+    mut wallet: ResMut<Wallet>,
+    mut progress: ResMut<PlayerProgress>,
+    mut stats: ResMut<RunStats>,
+    mut checkpoint: ResMut<Checkpoint>,
+    mut objectives: ResMut<ObjectiveManager>,
+    mut streaming_manager: ResMut<CoinStreamingManager>,
+    data_assets: Option<Res<DataAssets>>,
+    quest_data: Res<Assets<QuestData>>,
+) {
+    let save = &pending.0;
+
+    inventory.restore(&save.inventory);
+    wallet.gold = save.gold;
+    progress.0 = save.experience;
+    *stats = save.stats;
+    checkpoint.0 = save.checkpoint.map(Vec3::from_array);
+
+    // Only coins that were saved with the run keep their collected state
+    if !save.coins.positions.is_empty() {
+        streaming_manager.positions = save.coins.positions.iter().copied().map(Vec3::from_array).collect();
+        streaming_manager.collected_positions = save.coins.collected.iter().copied().collect();
+    }
+
+    match data_assets.and_then(|assets| quest_data.get(&assets.quests)) {
+        Some(data) => restore_objectives(&mut objectives, data, &save.objectives),
+        None => warn!("Quest data is not loaded, objective progress was not restored"),
+    }
+}
+
+/// Reloads the saved level from the quest data and carries over the progress of
+/// every objective it still declares, so quest edits do not break old saves
+fn restore_objectives(manager: &mut ObjectiveManager, data: &QuestData, saved: &SavedObjectives) {
+    if !manager.load_level(data, saved.level) {
+        warn!("Quest data declares no level {}, objective progress was not restored", saved.level);
+        return;
+    }
+    for objective in &mut manager.objectives {
+        if let Some(progress) = saved.objectives.iter().find(|other| other.key == objective.key) {
+            *objective = Objective {
+                id: objective.id,
+                ..progress.clone()
+            };
+        }
+    }
+}
+
+/// Moves the player to the saved position once they have spawned
+fn restore_player_position(
+    mut commands: Commands,
+    pending: Res<PendingLoad>,
+    mut player_query: Query<(&mut Transform, &mut LinearVelocity), With<CharacterController>>,
+    mut notifications: EventWriter<HudNotificationEvent>,
+) {
+    let Ok((mut transform, mut velocity)) = player_query.single_mut() else {
+        return;
+    };
+    transform.translation = Vec3::from_array(pending.0.player_position);
+    velocity.0 = Vec3::ZERO;
+    commands.remove_resource::<PendingLoad>();
+    notifications.write(HudNotificationEvent::new("Save loaded", NotificationKind::Info));
+}
+
+fn autosave_on_checkpoint(
+    snapshot: RunSnapshot,
+    checkpoint: Res<Checkpoint>,
+    slot: Res<ActiveSaveSlot>,
+    mut notifications: EventWriter<HudNotificationEvent>,
+) {
+    if !checkpoint.is_changed() || checkpoint.0.is_none() {
+        return;
+    }
+    if snapshot.save(*slot) {
+        notifications.write(HudNotificationEvent::new("Progress saved", NotificationKind::Info));
+    }
+}
+
+fn autosave_on_exit(snapshot: RunSnapshot, slot: Res<ActiveSaveSlot>) {
+    snapshot.save(*slot);
+}
+
+/// Closing the window skips the screen's exit, so the run is saved here instead
+fn autosave_on_app_exit(mut exit_events: EventReader<AppExit>, snapshot: RunSnapshot, slot: Res<ActiveSaveSlot>) {
+    if exit_events.read().count() > 0 {
+        snapshot.save(*slot);
+    }
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::collectibles::CollectibleType;
+
+    fn sample_save() -> SaveGame {
+        SaveGame {
+            version: SAVE_VERSION,
+            saved_at: 1_700_000_000,
+            player_position: [4.0, 1.0, -2.5],
+            checkpoint: Some([0.0, 1.0, 0.0]),
+            inventory: vec![
+                Some(ItemStack {
+                    item_type: CollectibleType::HealthPotion,
+                    count: 2,
+                }),
+                None,
+            ],
+            gold: 120,
+            experience: Experience { level: 2, total: 140 },
+            stats: RunStats {
+                elapsed: 312.0,
+                coins: 34,
+                kills: 5,
+                deaths: 1,
+            },
+            objectives: SavedObjectives::default(),
+            coins: SavedCoins {
+                positions: vec![[1.0, 2.0, 3.0], [4.0, 2.0, 6.0]],
+                collected: vec![1],
+            },
+        }
+    }
+
+    #[test]
+    fn saves_round_trip_through_json() {
+        let save = sample_save();
+        let text = serde_json::to_string(&save).unwrap();
+        assert_eq!(parse_save(&text).unwrap(), save);
+    }
+
+    #[test]
+    fn saves_from_newer_versions_are_rejected() {
+        let mut save = sample_save();
+        save.version = SAVE_VERSION + 1;
+        let text = serde_json::to_string(&save).unwrap();
+        assert!(matches!(parse_save(&text), Err(SaveError::UnsupportedVersion(_))));
+    }
+
+    #[test]
+    fn every_old_version_has_a_migration() {
+        assert_eq!(MIGRATIONS.len(), SAVE_VERSION as usize - 1);
+    }
+}
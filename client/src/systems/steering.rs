@@ -0,0 +1,361 @@
+use std::collections::HashMap;
+
+use avian3d::prelude::{SpatialQuery, SpatialQueryFilter};
+use bevy::prelude::*;
+
+use crate::constants::steering::{
+    AVOIDANCE_HEIGHT, AVOIDANCE_LOOKAHEAD, CROWD_CELL_SIZE, MAX_SLOT_RINGS, SEPARATION_MARGIN,
+    SLOT_RANGE_FRACTION,
+};
+use crate::screens::Screen;
+use crate::systems::boundary::BoundaryConstraint;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::combat::Defeated;
+use crate::systems::enemy_ai::{Enemy, EnemyAI, EnemyBehaviour};
+use crate::systems::perception::{Perception, update_perception};
+use crate::systems::projectiles::PassThrough;
+
+/// Most hits a single avoidance ray collects before picking the closest obstacle
+const MAX_AVOIDANCE_HITS: u32 = 8;
+
+// ===== COMPONENTS =====
+
+/// How an enemy moves within the crowd: its footprint, its attack slot around the
+/// player and the push it gets from enemies too close to it
+#[derive(Component, Debug, Clone)]
+pub struct Steering {
+    /// Radius of the enemy's footprint on the ground
+    pub radius: f32,
+    /// Position of the enemy's attack slot, relative to the player
+    pub slot: Option<Vec3>,
+    /// Push away from overlapping neighbours, up to length 1
+    pub separation: Vec3,
+}
+
+impl Steering {
+    pub fn new(radius: f32) -> Self {
+        Self {
+            radius,
+            slot: None,
+            separation: Vec3::ZERO,
+        }
+    }
+}
+
+impl Default for Steering {
+    fn default() -> Self {
+        Self::new(2.0)
+    }
+}
+
+/// Colliders that do not count as obstacles: pass-through colliders, enemies and the player
+pub type NotAnObstacle = Or<(PassThrough, With<Enemy>, With<CharacterController>)>;
+
+// ===== RESOURCES =====
+
+/// Enemies bucketed by grid cell, so neighbours are found without comparing every pair
+#[derive(Resource, Default)]
+pub struct CrowdGrid {
+    cells: HashMap<IVec2, Vec<(Entity, Vec3, f32)>>,
+}
+
+impl CrowdGrid {
+    fn cell(position: Vec3) -> IVec2 {
+        IVec2::new(
+            (position.x / CROWD_CELL_SIZE).floor() as i32,
+            (position.z / CROWD_CELL_SIZE).floor() as i32,
+        )
+    }
+
+    pub fn clear(&mut self) {
+        // Keep the buckets allocated, the crowd is rebuilt every frame
+        for bucket in self.cells.values_mut() {
+            bucket.clear();
+        }
+    }
+
+    pub fn insert(&mut self, entity: Entity, position: Vec3, radius: f32) {
+        self.cells
+            .entry(Self::cell(position))
+            .or_default()
+            .push((entity, position, radius));
+    }
+
+    /// Entities in every cell within `range` of `position`, with their position and radius
+    pub fn nearby(&self, position: Vec3, range: f32) -> impl Iterator<Item = (Entity, Vec3, f32)> + '_ {
+        let min = Self::cell(position - Vec3::splat(range));
+        let max = Self::cell(position + Vec3::splat(range));
+        (min.x..=max.x)
+            .flat_map(move |x| (min.y..=max.y).map(move |y| IVec2::new(x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+    }
+}
+
+// ===== PLUGIN =====
+
+pub struct SteeringPlugin;
+
+impl Plugin for SteeringPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CrowdGrid>().add_systems(
+            Update,
+            (rebuild_crowd_grid, assign_attack_slots, separate_crowd)
+                .chain()
+                .after(update_perception)
+                .run_if(in_state(Screen::GamePlay).and(resource_exists::<BoundaryConstraint>)),
+        );
+    }
+}
+
+// ===== STEERING =====
+
+/// Push away from every neighbour whose footprint overlaps ours (plus a margin),
+/// stronger the deeper the overlap
+pub fn separation(
+    entity: Entity,
+    position: Vec3,
+    radius: f32,
+    neighbours: impl Iterator<Item = (Entity, Vec3, f32)>,
+) -> Vec3 {
+    let mut push = Vec3::ZERO;
+    for (other, other_position, other_radius) in neighbours {
+        if other == entity {
+            continue;
+        }
+        let reach = radius + other_radius + SEPARATION_MARGIN;
+        let offset = (position - other_position).with_y(0.0);
+        let distance = offset.length();
+        if distance >= reach {
+            continue;
+        }
+        // Enemies standing exactly on top of each other split along a direction picked from their ids
+        let direction = offset.try_normalize().unwrap_or_else(|| {
+            let angle = (entity.index() as f32 - other.index() as f32) * 2.4;
+            Vec3::new(angle.cos(), 0.0, angle.sin())
+        });
+        push += direction * (1.0 - distance / reach);
+    }
+    push.clamp_length_max(1.0)
+}
+
+/// Turns `velocity` along the closest wall ahead of an enemy, so it slides around
+/// static geometry instead of walking into it
+pub fn avoid_obstacles(
+    spatial_query: &SpatialQuery,
+    ignored: &Query<(), NotAnObstacle>,
+    enemy: Entity,
+    position: Vec3,
+    velocity: Vec3,
+) -> Vec3 {
+    let Ok((direction, speed)) = Dir3::new_and_length(velocity.with_y(0.0)) else {
+        return velocity;
+    };
+    let origin = position + Vec3::Y * AVOIDANCE_HEIGHT;
+    let filter = SpatialQueryFilter::default().with_excluded_entities([enemy]);
+    let hit = spatial_query
+        .ray_hits(origin, direction, AVOIDANCE_LOOKAHEAD, MAX_AVOIDANCE_HITS, true, &filter)
+        .into_iter()
+        .filter(|hit| !ignored.contains(hit.entity))
+        .min_by(|a, b| a.distance.total_cmp(&b.distance));
+    let Some(hit) = hit else {
+        return velocity;
+    };
+
+    let normal = hit.normal.with_y(0.0).normalize_or_zero();
+    let into_wall = velocity.dot(normal);
+    if into_wall >= 0.0 {
+        return velocity;
+    }
+    // Slide along the wall; when walking straight at it, pick a side to go around
+    let slide = velocity - normal * into_wall;
+    let steered = if slide.length() < speed * 0.3 {
+        normal.cross(Vec3::Y) * speed
+    } else {
+        slide.normalize() * speed
+    };
+    let urgency = 1.0 - hit.distance / AVOIDANCE_LOOKAHEAD;
+    velocity.lerp(steered, urgency).with_y(velocity.y)
+}
+
+// ===== ATTACK SLOTS =====
+
+/// An enemy closing in on the player that wants an attack slot
+pub struct SlotRequest {
+    /// Position relative to the player
+    pub offset: Vec3,
+    pub radius: f32,
+    pub attack_range: f32,
+    /// The slot it held last frame, kept if still free
+    pub previous: Option<Vec3>,
+}
+
+/// Spreads attackers on rings around the player, each slot one footprint apart.
+/// Attackers holding a slot keep it, then closer attackers pick first, each taking
+/// the free slot nearest its bearing. Returns each attacker's slot relative to the
+/// player, `None` if every ring is full.
+pub fn assign_slots(requests: &[SlotRequest]) -> Vec<Option<Vec3>> {
+    let mut order: Vec<usize> = (0..requests.len()).collect();
+    order.sort_by(|&a, &b| {
+        let (a, b) = (&requests[a], &requests[b]);
+        (a.previous.is_none(), a.offset.length_squared())
+            .partial_cmp(&(b.previous.is_none(), b.offset.length_squared()))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut claimed: Vec<(Vec3, f32)> = Vec::new();
+    let mut slots = vec![None; requests.len()];
+    for index in order {
+        let request = &requests[index];
+        let free = |slot: Vec3| {
+            claimed
+                .iter()
+                .all(|(other, radius)| other.distance(slot) + 1e-3 >= request.radius + radius + SEPARATION_MARGIN)
+        };
+        let first_ring = request.attack_range * SLOT_RANGE_FRACTION;
+        let spacing = request.radius * 2.0 + SEPARATION_MARGIN;
+
+        // Only slots on the first ring are held, so waiting attackers move up once one frees
+        let slot = request
+            .previous
+            .filter(|&slot| (slot.length() - first_ring).abs() < 1e-3 && free(slot))
+            .or_else(|| {
+                let bearing = request.offset.z.atan2(request.offset.x);
+                (0..MAX_SLOT_RINGS).find_map(|ring| {
+                    let ring_radius = first_ring + spacing * ring as f32;
+                    // Neighbouring slots are one spacing apart in a straight line
+                    let half_step = (spacing / (2.0 * ring_radius)).min(1.0).asin();
+                    let count = ((std::f32::consts::PI / half_step).floor() as usize).max(1);
+                    let step = std::f32::consts::TAU / count as f32;
+                    let nearest = (bearing / step).round();
+                    // Try the slots in order of how far they are from the attacker's bearing
+                    (0..count)
+                        .map(|k| {
+                            let shift = (k as f32 / 2.0).ceil() * if k % 2 == 0 { 1.0 } else { -1.0 };
+                            let angle = (nearest + shift) * step;
+                            Vec3::new(angle.cos(), 0.0, angle.sin()) * ring_radius
+                        })
+                        .find(|&slot| free(slot))
+                })
+            });
+
+        if let Some(slot) = slot {
+            claimed.push((slot, request.radius));
+        }
+        slots[index] = slot;
+    }
+    slots
+}
+
+// ===== SYSTEMS =====
+
+fn rebuild_crowd_grid(
+    mut grid: ResMut<CrowdGrid>,
+    enemies: Query<(Entity, &Transform, &Steering), (With<Enemy>, Without<Defeated>)>,
+) {
+    grid.clear();
+    for (entity, transform, steering) in &enemies {
+        grid.insert(entity, transform.translation, steering.radius);
+    }
+}
+
+/// Hands out slots around the player to the enemies that see them and fight up close
+fn assign_attack_slots(
+    mut enemies: Query<(&Transform, &EnemyAI, &Perception, &mut Steering), (With<Enemy>, Without<Defeated>)>,
+    player_query: Query<&Transform, (With<CharacterController>, Without<Enemy>)>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let player = player_transform.translation;
+
+    let mut attackers = Vec::new();
+    let mut requests = Vec::new();
+    for (transform, ai, perception, mut steering) in &mut enemies {
+        // Ranged enemies keep their distance instead of taking a slot
+        let previous = steering.slot.take();
+        if !perception.sees_player || ai.behaviour == EnemyBehaviour::Ranged {
+            continue;
+        }
+        requests.push(SlotRequest {
+            offset: (transform.translation - player).with_y(0.0),
+            radius: steering.radius,
+            attack_range: ai.attack_range,
+            previous,
+        });
+        attackers.push(steering);
+    }
+
+    for (mut steering, slot) in attackers.into_iter().zip(assign_slots(&requests)) {
+        steering.slot = slot;
+    }
+}
+
+pub fn separate_crowd(
+    grid: Res<CrowdGrid>,
+    mut enemies: Query<(Entity, &Transform, &mut Steering), (With<Enemy>, Without<Defeated>)>,
+) {
+    for (entity, transform, mut steering) in &mut enemies {
+        // Neighbours are at most as large as the reach of a grid cell
+        let range = steering.radius + CROWD_CELL_SIZE;
+        let push = separation(
+            entity,
+            transform.translation,
+            steering.radius,
+            grid.nearby(transform.translation, range),
+        );
+        steering.separation = push;
+    }
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(offset: Vec3) -> SlotRequest {
+        SlotRequest {
+            offset,
+            radius: 1.0,
+            attack_range: 5.0,
+            previous: None,
+        }
+    }
+
+    #[test]
+    fn attackers_from_one_side_are_spread_apart() {
+        let requests: Vec<SlotRequest> = (0..4).map(|i| request(Vec3::new(10.0 + i as f32, 0.0, 0.0))).collect();
+        let slots: Vec<Vec3> = assign_slots(&requests).into_iter().map(Option::unwrap).collect();
+
+        for (i, a) in slots.iter().enumerate() {
+            for b in &slots[i + 1..] {
+                assert!(a.distance(*b) >= 2.0 + SEPARATION_MARGIN - 1e-3);
+            }
+        }
+        // The closest attacker gets the slot facing it
+        assert!(slots[0].x > 0.0 && slots[0].z.abs() < 1e-3);
+    }
+
+    #[test]
+    fn held_slots_are_kept() {
+        let mut first = request(Vec3::new(0.0, 0.0, 10.0));
+        first.previous = Some(Vec3::new(4.0, 0.0, 0.0));
+        let slots = assign_slots(&[first, request(Vec3::new(4.5, 0.0, 0.0))]);
+        assert_eq!(slots[0], Some(Vec3::new(4.0, 0.0, 0.0)));
+        assert_ne!(slots[1], slots[0]);
+    }
+
+    #[test]
+    fn overlapping_enemies_push_apart() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        let neighbours = [(a, Vec3::ZERO, 1.0), (b, Vec3::X, 1.0)];
+        let push = separation(a, Vec3::ZERO, 1.0, neighbours.into_iter());
+        assert!(push.x < 0.0);
+
+        let stacked = [(a, Vec3::ZERO, 1.0), (b, Vec3::ZERO, 1.0)];
+        assert!(separation(a, Vec3::ZERO, 1.0, stacked.into_iter()).length() > 0.0);
+    }
+}
@@ -0,0 +1,512 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::constants::combat::{
+    BATTLE_SEED, BOSS_BASE_DAMAGE, BOSS_ENRAGE_MULTIPLIER, BOSS_ENRAGE_THRESHOLD, BOSS_TURN_DELAY,
+    CRIT_CHANCE, CRIT_MULTIPLIER, DAMAGE_VARIANCE, DEFEND_DAMAGE_TAKEN, SKILL_COOLDOWN_TURNS,
+    SKILL_MULTIPLIER,
+};
+use crate::constants::items::HEALTH_POTION_HEAL;
+use crate::game::Health;
+use crate::screens::Screen;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::collectibles::CollectibleType;
+use crate::systems::combat::{DamageEvent, Defeated};
+use crate::systems::enemy_ai::Enemy;
+use crate::systems::equipment::{Stats, derive_player_stats};
+use crate::systems::inventory::{Inventory, InventoryEvent};
+use crate::systems::items::{ItemUsedEvent, UseItemEvent};
+use crate::systems::sacrifice::SacrificeOffering;
+use crate::ui::hud::{HudNotificationEvent, NotificationKind};
+
+/// Lines of the battle log kept for the combat menu
+const BATTLE_LOG_LINES: usize = 5;
+
+// ===== BATTLE MODEL =====
+
+/// One side of a turn-based battle
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Combatant {
+    pub health: u32,
+    pub max_health: u32,
+    pub damage: f32,
+    /// Takes reduced damage until its next action
+    pub defending: bool,
+}
+
+impl Combatant {
+    pub fn new(health: u32, max_health: u32, damage: f32) -> Self {
+        Self {
+            health: health.min(max_health),
+            max_health,
+            damage,
+            defending: false,
+        }
+    }
+
+    pub fn is_down(&self) -> bool {
+        self.health == 0
+    }
+
+    fn health_fraction(&self) -> f32 {
+        self.health as f32 / self.max_health.max(1) as f32
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TurnAction {
+    Attack,
+    Defend,
+    /// Drink a health potion from the inventory
+    UseItem,
+    /// A heavy blow that ignores the boss's guard, on a cooldown
+    Skill,
+}
+
+/// What the boss will do on its next turn, shown to the player in advance
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BossIntent {
+    Strike,
+    HeavyStrike,
+    Guard,
+    /// Hits and heals itself by half the damage dealt
+    Drain,
+}
+
+impl BossIntent {
+    pub fn describe(&self) -> &'static str {
+        match self {
+            BossIntent::Strike => "The boss readies a strike",
+            BossIntent::HeavyStrike => "The boss winds up a crushing blow",
+            BossIntent::Guard => "The boss raises its guard",
+            BossIntent::Drain => "The boss hungers for your life",
+        }
+    }
+
+    fn damage_multiplier(&self) -> f32 {
+        match self {
+            BossIntent::Strike => 1.0,
+            BossIntent::HeavyStrike => 1.8,
+            BossIntent::Guard => 0.0,
+            BossIntent::Drain => 0.8,
+        }
+    }
+}
+
+/// Intents the boss cycles through, switching patterns once it enrages
+const CALM_PATTERN: [BossIntent; 4] = [
+    BossIntent::Strike,
+    BossIntent::Guard,
+    BossIntent::Strike,
+    BossIntent::HeavyStrike,
+];
+const ENRAGED_PATTERN: [BossIntent; 3] = [
+    BossIntent::HeavyStrike,
+    BossIntent::Drain,
+    BossIntent::Strike,
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TurnPhase {
+    PlayerTurn,
+    BossTurn,
+    Victory,
+    Defeat,
+}
+
+/// Seed of a battle, derived from the run's fixed seed and the sacrificed value
+pub fn battle_seed(offering_value: u32) -> u64 {
+    BATTLE_SEED ^ (offering_value as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+}
+
+/// A turn-based fight between the player and the realm's boss.
+///
+/// Every random roll comes from a generator seeded with `seed`, in a fixed order,
+/// so the same seed and actions always replay to the same result.
+#[derive(Resource, Debug)]
+pub struct TurnBattle {
+    pub seed: u64,
+    pub turn: u32,
+    pub phase: TurnPhase,
+    pub player: Combatant,
+    pub boss: Combatant,
+    pub intent: BossIntent,
+    pub enraged: bool,
+    /// Turns until the skill can be used again
+    pub skill_cooldown: u32,
+    pub log: Vec<String>,
+    pattern_step: usize,
+    rng: StdRng,
+}
+
+impl TurnBattle {
+    pub fn new(seed: u64, player: Combatant, boss: Combatant) -> Self {
+        let intent = CALM_PATTERN[0];
+        let mut battle = Self {
+            seed,
+            turn: 1,
+            phase: TurnPhase::PlayerTurn,
+            player,
+            boss,
+            intent,
+            enraged: false,
+            skill_cooldown: 0,
+            log: Vec::new(),
+            pattern_step: 0,
+            rng: StdRng::seed_from_u64(seed),
+        };
+        battle.record(intent.describe().to_string());
+        battle
+    }
+
+    fn record(&mut self, line: String) {
+        self.log.push(line);
+        if self.log.len() > BATTLE_LOG_LINES {
+            self.log.remove(0);
+        }
+    }
+
+    /// Rolls variance and criticals, then applies the target's guard
+    fn roll_damage(&mut self, base: f32, target_defending: bool) -> (u32, bool) {
+        let variance = self
+            .rng
+            .random_range(1.0 - DAMAGE_VARIANCE..=1.0 + DAMAGE_VARIANCE);
+        let critical = self.rng.random_bool(CRIT_CHANCE);
+
+        let mut amount = base * variance;
+        if critical {
+            amount *= CRIT_MULTIPLIER;
+        }
+        if target_defending {
+            amount *= DEFEND_DAMAGE_TAKEN;
+        }
+        (amount.round().max(1.0) as u32, critical)
+    }
+
+    /// Resolves the player's action and returns the damage dealt to the boss.
+    /// Using an item assumes the caller checked the inventory.
+    pub fn player_act(&mut self, action: TurnAction) -> Result<u32, &'static str> {
+        if self.phase != TurnPhase::PlayerTurn {
+            return Err("It is not your turn");
+        }
+        if action == TurnAction::Skill && self.skill_cooldown > 0 {
+            return Err("Your skill is still recharging");
+        }
+
+        self.player.defending = false;
+        let mut dealt = 0;
+        match action {
+            TurnAction::Attack | TurnAction::Skill => {
+                let (base, guarded, name) = if action == TurnAction::Skill {
+                    self.skill_cooldown = SKILL_COOLDOWN_TURNS;
+                    (self.player.damage * SKILL_MULTIPLIER, false, "Soul Strike")
+                } else {
+                    (self.player.damage, self.boss.defending, "You strike")
+                };
+                let (amount, critical) = self.roll_damage(base, guarded);
+                dealt = amount.min(self.boss.health);
+                self.boss.health -= dealt;
+                let critical = if critical { " Critical!" } else { "" };
+                self.record(format!("{} for {} damage.{}", name, dealt, critical));
+            }
+            TurnAction::Defend => {
+                self.player.defending = true;
+                self.record("You brace yourself.".to_string());
+            }
+            TurnAction::UseItem => {
+                let healed = HEALTH_POTION_HEAL.min(self.player.max_health - self.player.health);
+                self.player.health += healed;
+                self.record(format!("You drink a potion and recover {} health.", healed));
+            }
+        }
+
+        // The boss's guard only covers the player's next action
+        self.boss.defending = false;
+        if self.boss.is_down() {
+            self.phase = TurnPhase::Victory;
+            self.record("The boss falls!".to_string());
+        } else {
+            self.phase = TurnPhase::BossTurn;
+        }
+        Ok(dealt)
+    }
+
+    /// Carries out the announced intent, then reveals the next one
+    pub fn boss_act(&mut self) {
+        if self.phase != TurnPhase::BossTurn {
+            return;
+        }
+
+        let intent = self.intent;
+        if intent == BossIntent::Guard {
+            self.boss.defending = true;
+            self.record("The boss guards.".to_string());
+        } else {
+            let mut base = self.boss.damage * intent.damage_multiplier();
+            if self.enraged {
+                base *= BOSS_ENRAGE_MULTIPLIER;
+            }
+            let (amount, critical) = self.roll_damage(base, self.player.defending);
+            let dealt = amount.min(self.player.health);
+            self.player.health -= dealt;
+            let critical = if critical { " Critical!" } else { "" };
+            self.record(format!("The boss hits you for {} damage.{}", dealt, critical));
+
+            if intent == BossIntent::Drain {
+                let healed = (dealt / 2).min(self.boss.max_health - self.boss.health);
+                self.boss.health += healed;
+                self.record(format!("The boss drains {} health.", healed));
+            }
+        }
+
+        if self.player.is_down() {
+            self.phase = TurnPhase::Defeat;
+            self.record("You have fallen.".to_string());
+            return;
+        }
+
+        self.turn += 1;
+        self.skill_cooldown = self.skill_cooldown.saturating_sub(1);
+        if !self.enraged && self.boss.health_fraction() <= BOSS_ENRAGE_THRESHOLD {
+            self.enraged = true;
+            self.pattern_step = 0;
+            self.record("The boss flies into a rage!".to_string());
+        } else {
+            self.pattern_step += 1;
+        }
+        let pattern: &[BossIntent] = if self.enraged {
+            &ENRAGED_PATTERN
+        } else {
+            &CALM_PATTERN
+        };
+        self.intent = pattern[self.pattern_step % pattern.len()];
+        self.record(self.intent.describe().to_string());
+        self.phase = TurnPhase::PlayerTurn;
+    }
+}
+
+// ===== EVENTS =====
+
+/// The player picked an action from the combat menu
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TurnActionEvent(pub TurnAction);
+
+// ===== PLUGIN =====
+
+pub struct TurnCombatPlugin;
+
+impl Plugin for TurnCombatPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TurnActionEvent>()
+            .add_systems(OnExit(Screen::FightScene), end_battle)
+            .add_systems(
+                Update,
+                (
+                    start_battle
+                        .after(derive_player_stats)
+                        .run_if(not(resource_exists::<TurnBattle>)),
+                    redirect_item_hotkeys,
+                    resolve_player_actions,
+                    run_boss_turn,
+                    sync_player_health,
+                    crate::ui::turn_combat::sync_turn_combat_ui,
+                )
+                    .chain()
+                    .run_if(in_state(Screen::FightScene)),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+/// Starts the battle once the player and boss are spawned and the player's stats derived
+fn start_battle(
+    mut commands: Commands,
+    player_query: Query<(&Stats, &Health), With<CharacterController>>,
+    boss_query: Query<&Health, (With<Enemy>, Without<CharacterController>)>,
+    offering: Res<SacrificeOffering>,
+) {
+    let (Ok((stats, player_health)), Ok(boss_health)) = (player_query.single(), boss_query.single())
+    else {
+        return;
+    };
+
+    let seed = battle_seed(offering.value);
+    info!("Starting turn-based battle with seed {:#x}", seed);
+    commands.insert_resource(TurnBattle::new(
+        seed,
+        Combatant::new(player_health.current, stats.max_health, stats.damage),
+        Combatant::new(boss_health.current, boss_health.max, BOSS_BASE_DAMAGE),
+    ));
+}
+
+pub fn end_battle(mut commands: Commands) {
+    commands.remove_resource::<TurnBattle>();
+}
+
+/// The potion hotkey drinks a potion as this turn's action
+fn redirect_item_hotkeys(
+    mut use_item_events: EventReader<UseItemEvent>,
+    mut action_events: EventWriter<TurnActionEvent>,
+) {
+    for event in use_item_events.read() {
+        if event.item_type == CollectibleType::HealthPotion {
+            action_events.write(TurnActionEvent(TurnAction::UseItem));
+        }
+    }
+}
+
+fn resolve_player_actions(
+    mut action_events: EventReader<TurnActionEvent>,
+    battle: Option<ResMut<TurnBattle>>,
+    inventory: Res<Inventory>,
+    boss_query: Query<Entity, (With<Enemy>, Without<Defeated>)>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut inventory_events: EventWriter<InventoryEvent>,
+    mut item_used_events: EventWriter<ItemUsedEvent>,
+    mut notifications: EventWriter<HudNotificationEvent>,
+) {
+    let Some(mut battle) = battle else {
+        action_events.clear();
+        return;
+    };
+
+    for TurnActionEvent(action) in action_events.read().copied() {
+        if action == TurnAction::UseItem && inventory.count(CollectibleType::HealthPotion) == 0 {
+            notifications.write(HudNotificationEvent::new(
+                "You have no health potions",
+                NotificationKind::Warning,
+            ));
+            continue;
+        }
+
+        match battle.player_act(action) {
+            Ok(dealt) => {
+                if action == TurnAction::UseItem {
+                    inventory_events.write(InventoryEvent::Remove {
+                        item_type: CollectibleType::HealthPotion,
+                        count: 1,
+                    });
+                    item_used_events.write(ItemUsedEvent {
+                        item_type: CollectibleType::HealthPotion,
+                    });
+                }
+                // The boss entity's health follows the battle, which also
+                // raises `EnemyDefeatedEvent` for loot and objectives
+                if let (1.., Ok(boss)) = (dealt, boss_query.single()) {
+                    damage_events.write(DamageEvent {
+                        target: boss,
+                        amount: dealt,
+                        source: None,
+                    });
+                }
+            }
+            Err(reason) => {
+                notifications.write(HudNotificationEvent::new(reason, NotificationKind::Warning));
+            }
+        }
+    }
+}
+
+fn run_boss_turn(time: Res<Time>, battle: Option<ResMut<TurnBattle>>, mut waited: Local<f32>) {
+    let Some(mut battle) = battle else {
+        return;
+    };
+    if battle.phase != TurnPhase::BossTurn {
+        *waited = 0.0;
+        return;
+    }
+
+    *waited += time.delta_secs();
+    if *waited >= BOSS_TURN_DELAY {
+        *waited = 0.0;
+        battle.boss_act();
+    }
+}
+
+fn sync_player_health(
+    battle: Option<Res<TurnBattle>>,
+    mut player_query: Query<&mut Health, With<CharacterController>>,
+) {
+    let Some(battle) = battle.filter(|battle| battle.is_changed()) else {
+        return;
+    };
+    for mut health in &mut player_query {
+        health.current = battle.player.health;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn battle(seed: u64) -> TurnBattle {
+        TurnBattle::new(
+            seed,
+            Combatant::new(100, 100, 10.0),
+            Combatant::new(150, 150, BOSS_BASE_DAMAGE),
+        )
+    }
+
+    fn play(battle: &mut TurnBattle, actions: &[TurnAction]) {
+        for action in actions {
+            if battle.player_act(*action).is_ok() {
+                battle.boss_act();
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_and_actions_replay_identically() {
+        let actions = [
+            TurnAction::Attack,
+            TurnAction::Skill,
+            TurnAction::Defend,
+            TurnAction::Attack,
+            TurnAction::UseItem,
+            TurnAction::Attack,
+        ];
+        let mut first = battle(7);
+        let mut second = battle(7);
+        play(&mut first, &actions);
+        play(&mut second, &actions);
+
+        assert_eq!(first.player, second.player);
+        assert_eq!(first.boss, second.boss);
+        assert_eq!(first.log, second.log);
+    }
+
+    #[test]
+    fn boss_follows_its_intent_pattern() {
+        let mut battle = battle(1);
+        assert_eq!(battle.intent, BossIntent::Strike);
+        play(&mut battle, &[TurnAction::Defend]);
+        assert_eq!(battle.intent, BossIntent::Guard);
+        play(&mut battle, &[TurnAction::Defend]);
+        assert!(battle.boss.defending);
+        assert_eq!(battle.turn, 3);
+    }
+
+    #[test]
+    fn skill_has_a_cooldown() {
+        let mut battle = battle(3);
+        assert!(battle.player_act(TurnAction::Skill).is_ok());
+        battle.boss_act();
+        assert!(battle.player_act(TurnAction::Skill).is_err());
+        assert_eq!(battle.phase, TurnPhase::PlayerTurn);
+    }
+
+    #[test]
+    fn defeating_the_boss_ends_the_battle() {
+        let mut battle = TurnBattle::new(
+            5,
+            Combatant::new(100, 100, 500.0),
+            Combatant::new(10, 10, BOSS_BASE_DAMAGE),
+        );
+        assert_eq!(battle.player_act(TurnAction::Attack), Ok(10));
+        assert_eq!(battle.phase, TurnPhase::Victory);
+        battle.boss_act();
+        assert_eq!(battle.turn, 1);
+    }
+}
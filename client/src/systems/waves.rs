@@ -0,0 +1,485 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+use crate::constants::waves::{
+    DIFFICULTY_PER_MINUTE, FIRST_WAVE_DELAY, MAX_ALIVE_WAVE_ENEMIES, SPAWN_ATTEMPTS,
+    SPAWN_RING_TOLERANCE, SURVIVAL_DURATION, WAVE_BREAK, WAVE_SEED,
+};
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::boundary::BoundaryConstraint;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::collectibles::NavigationBasedSpawner;
+use crate::systems::combat::Defeated;
+use crate::systems::death::{PlayerDiedEvent, RespawnEvent};
+use crate::systems::enemy_archetypes::Bestiary;
+use crate::systems::perception::Perception;
+use crate::ui::hud::{HudNotificationEvent, NotificationKind};
+
+/// Height the enemy AI keeps enemies at
+const ENEMY_GROUND_Y: f32 = -1.65;
+
+// ===== WAVE DEFINITIONS =====
+
+/// A number of enemies of one archetype within a wave
+#[derive(Clone, Debug, PartialEq)]
+pub struct WaveSpawn {
+    /// Name of the archetype in `campaign.enemies.json`
+    pub archetype: String,
+    pub count: u32,
+}
+
+impl WaveSpawn {
+    pub fn new(archetype: impl Into<String>, count: u32) -> Self {
+        Self {
+            archetype: archetype.into(),
+            count,
+        }
+    }
+}
+
+/// One wave: which enemies come, how far from the player and how quickly
+#[derive(Clone, Debug, PartialEq)]
+pub struct WaveDefinition {
+    pub enemies: Vec<WaveSpawn>,
+    /// Distance from the player at which enemies appear
+    pub spawn_radius: f32,
+    /// Seconds between two spawns before difficulty scaling
+    pub interval: f32,
+}
+
+impl WaveDefinition {
+    pub fn total(&self) -> u32 {
+        self.enemies.iter().map(|spawn| spawn.count).sum()
+    }
+
+    /// The archetype of the `index`-th enemy of the wave
+    pub fn archetype_at(&self, index: u32) -> Option<&str> {
+        let mut remaining = index;
+        for spawn in &self.enemies {
+            if remaining < spawn.count {
+                return Some(&spawn.archetype);
+            }
+            remaining -= spawn.count;
+        }
+        None
+    }
+}
+
+fn default_waves() -> Vec<WaveDefinition> {
+    vec![
+        WaveDefinition {
+            enemies: vec![WaveSpawn::new("husk", 3)],
+            spawn_radius: 24.0,
+            interval: 3.0,
+        },
+        WaveDefinition {
+            enemies: vec![WaveSpawn::new("husk", 4), WaveSpawn::new("spitter", 2)],
+            spawn_radius: 26.0,
+            interval: 2.5,
+        },
+        WaveDefinition {
+            enemies: vec![
+                WaveSpawn::new("husk", 3),
+                WaveSpawn::new("charger", 3),
+                WaveSpawn::new("spitter", 2),
+            ],
+            spawn_radius: 28.0,
+            interval: 2.0,
+        },
+        WaveDefinition {
+            enemies: vec![
+                WaveSpawn::new("brute", 2),
+                WaveSpawn::new("charger", 3),
+                WaveSpawn::new("warden", 1),
+            ],
+            spawn_radius: 30.0,
+            interval: 2.0,
+        },
+        // Repeats until the timer runs out, getting harder with time
+        WaveDefinition {
+            enemies: vec![
+                WaveSpawn::new("husk", 4),
+                WaveSpawn::new("spitter", 3),
+                WaveSpawn::new("charger", 3),
+                WaveSpawn::new("brute", 1),
+            ],
+            spawn_radius: 30.0,
+            interval: 1.5,
+        },
+    ]
+}
+
+// ===== WAVE DIRECTOR =====
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SurvivalOutcome {
+    Survived,
+    Died,
+}
+
+/// Schedules wave spawns and tracks the survival timer of the level
+#[derive(Resource)]
+pub struct WaveDirector {
+    pub waves: Vec<WaveDefinition>,
+    /// Seconds survived so far
+    pub elapsed: f32,
+    /// Index of the current wave; the last definition repeats once all ran
+    pub wave: usize,
+    /// Enemies of the current wave spawned so far
+    pub spawned: u32,
+    /// Seconds until the next spawn is due
+    pub next_spawn: f32,
+    pub outcome: Option<SurvivalOutcome>,
+    rng: StdRng,
+}
+
+impl Default for WaveDirector {
+    fn default() -> Self {
+        Self::new(default_waves())
+    }
+}
+
+impl WaveDirector {
+    pub fn new(waves: Vec<WaveDefinition>) -> Self {
+        Self {
+            waves,
+            elapsed: 0.0,
+            wave: 0,
+            spawned: 0,
+            next_spawn: FIRST_WAVE_DELAY,
+            outcome: None,
+            rng: StdRng::seed_from_u64(WAVE_SEED),
+        }
+    }
+
+    pub fn remaining(&self) -> f32 {
+        (SURVIVAL_DURATION - self.elapsed).max(0.0)
+    }
+
+    /// Multiplier on enemy health, damage and spawn rate
+    pub fn difficulty(&self) -> f32 {
+        1.0 + DIFFICULTY_PER_MINUTE * self.elapsed / 60.0
+    }
+
+    /// Loot level of wave enemies, one higher per minute survived
+    pub fn loot_level(&self) -> u32 {
+        1 + (self.elapsed / 60.0) as u32
+    }
+
+    /// 1-based number of the current wave, for display
+    pub fn wave_number(&self) -> usize {
+        self.wave + 1
+    }
+
+    pub fn current_wave(&self) -> Option<&WaveDefinition> {
+        self.waves.get(self.wave.min(self.waves.len().saturating_sub(1)))
+    }
+
+    /// Advances the timer and returns the archetype of the enemy to spawn, if one is due.
+    /// While `can_spawn` is false a due spawn waits instead of being dropped.
+    pub fn tick(&mut self, delta: f32, can_spawn: bool) -> Option<String> {
+        if self.outcome.is_some() {
+            return None;
+        }
+
+        self.elapsed += delta;
+        if self.elapsed >= SURVIVAL_DURATION {
+            self.outcome = Some(SurvivalOutcome::Survived);
+            return None;
+        }
+
+        self.next_spawn -= delta;
+        if self.next_spawn > 0.0 || !can_spawn {
+            return None;
+        }
+
+        let difficulty = self.difficulty();
+        let wave = self.current_wave()?;
+        let (total, interval) = (wave.total(), wave.interval);
+        let archetype = wave.archetype_at(self.spawned)?.to_string();
+
+        self.spawned += 1;
+        if self.spawned >= total {
+            self.wave += 1;
+            self.spawned = 0;
+            self.next_spawn = WAVE_BREAK;
+        } else {
+            self.next_spawn = interval / difficulty;
+        }
+        Some(archetype)
+    }
+
+    pub fn fail(&mut self) {
+        if self.outcome.is_none() {
+            self.outcome = Some(SurvivalOutcome::Died);
+        }
+    }
+
+    /// Picks the waves back up after the player respawns
+    pub fn resume(&mut self) {
+        if self.outcome == Some(SurvivalOutcome::Died) {
+            self.outcome = None;
+        }
+    }
+}
+
+// ===== COMPONENTS =====
+
+/// An enemy spawned by the wave director
+#[derive(Component)]
+pub struct WaveEnemy;
+
+// ===== PLUGIN =====
+
+pub struct WavesPlugin;
+
+impl Plugin for WavesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WaveDirector>()
+            .add_systems(OnEnter(Screen::PreGameLoading), reset_wave_director)
+            .add_systems(OnEnter(Screen::GamePlay), crate::ui::waves::spawn_survival_hud)
+            .add_systems(
+                Update,
+                (
+                    follow_player_death,
+                    direct_waves,
+                    retreat_on_survival,
+                    crate::ui::waves::sync_survival_hud,
+                    crate::ui::waves::show_survival_result,
+                )
+                    .chain()
+                    .run_if(in_state(Screen::GamePlay)),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn reset_wave_director(mut director: ResMut<WaveDirector>) {
+    *director = WaveDirector::default();
+}
+
+/// Waves stop while the player is dead and carry on once they respawn
+fn follow_player_death(
+    mut died_events: EventReader<PlayerDiedEvent>,
+    mut respawn_events: EventReader<RespawnEvent>,
+    mut director: ResMut<WaveDirector>,
+) {
+    if died_events.read().count() > 0 {
+        director.fail();
+    }
+    if respawn_events.read().count() > 0 {
+        director.resume();
+    }
+}
+
+fn direct_waves(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut director: ResMut<WaveDirector>,
+    bestiary: Bestiary,
+    nav_spawner: Option<Res<NavigationBasedSpawner>>,
+    boundary: Option<Res<BoundaryConstraint>>,
+    player_query: Query<&Transform, With<CharacterController>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<PlayingScene>>,
+    alive_query: Query<(), (With<WaveEnemy>, Without<Defeated>)>,
+    mut notifications: EventWriter<HudNotificationEvent>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+
+    // Read before ticking, which may move on to the next wave
+    let wave_before = director.wave_number();
+    let Some(radius) = director.current_wave().map(|wave| wave.spawn_radius) else {
+        return;
+    };
+    let can_spawn = alive_query.iter().count() < MAX_ALIVE_WAVE_ENEMIES;
+    let Some(archetype) = director.tick(time.delta_secs(), can_spawn) else {
+        return;
+    };
+    // The first spawn of a wave announces it
+    if director.spawned == 1 || director.wave_number() != wave_before {
+        notifications.write(HudNotificationEvent::new(
+            format!("Wave {} approaches", wave_before),
+            NotificationKind::Warning,
+        ));
+    }
+
+    let position = pick_spawn_point(
+        &mut director.rng,
+        player_transform.translation,
+        radius,
+        nav_spawner.as_deref(),
+        camera_query.single().ok(),
+        boundary.as_deref(),
+    );
+
+    let facing = (player_transform.translation - position).with_y(0.0).normalize_or(Vec3::Z);
+    let transform = Transform::from_translation(position).with_rotation(Quat::from_rotation_arc(Vec3::Z, facing));
+    if let Some(mut enemy) = bestiary.spawn(
+        &mut commands,
+        &archetype,
+        transform,
+        director.difficulty(),
+        director.loot_level(),
+    ) {
+        // The horde is drawn to the player rather than waiting to stumble on them
+        enemy.insert((WaveEnemy, PlayingScene, Perception::alerted(player_transform.translation)));
+    }
+}
+
+/// Once the timer runs out the remaining wave enemies withdraw
+fn retreat_on_survival(
+    mut commands: Commands,
+    director: Res<WaveDirector>,
+    enemies: Query<Entity, (With<WaveEnemy>, Without<Defeated>)>,
+) {
+    if director.outcome != Some(SurvivalOutcome::Survived) {
+        return;
+    }
+    for entity in &enemies {
+        commands.entity(entity).despawn();
+    }
+}
+
+// ===== SPAWN POINTS =====
+
+/// Picks a point on the ring around the player that the camera cannot see,
+/// preferring navigation points so enemies appear on walkable ground
+fn pick_spawn_point(
+    rng: &mut StdRng,
+    player: Vec3,
+    radius: f32,
+    nav_spawner: Option<&NavigationBasedSpawner>,
+    camera: Option<(&Camera, &GlobalTransform)>,
+    boundary: Option<&BoundaryConstraint>,
+) -> Vec3 {
+    let visible = |point: Vec3| {
+        camera.is_some_and(|(camera, camera_transform)| {
+            camera
+                .world_to_ndc(camera_transform, point + Vec3::Y)
+                .is_some_and(|ndc| ndc.x.abs() <= 1.0 && ndc.y.abs() <= 1.0 && (0.0..=1.0).contains(&ndc.z))
+        })
+    };
+    let in_bounds = |point: Vec3| {
+        boundary.is_none_or(|bounds| {
+            (bounds.min_x..=bounds.max_x).contains(&point.x) && (bounds.min_z..=bounds.max_z).contains(&point.z)
+        })
+    };
+    let on_ground = |point: Vec3| point.with_y(ENEMY_GROUND_Y);
+
+    let ring = radius * (1.0 - SPAWN_RING_TOLERANCE)..=radius * (1.0 + SPAWN_RING_TOLERANCE);
+    let candidates: Vec<Vec3> = nav_spawner
+        .filter(|spawner| spawner.loaded)
+        .map(|spawner| {
+            spawner
+                .nav_positions
+                .iter()
+                .map(|&position| on_ground(position))
+                .filter(|&position| {
+                    ring.contains(&position.with_y(0.0).distance(player.with_y(0.0)))
+                        && in_bounds(position)
+                        && !visible(position)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    if let Some(&position) = candidates.choose(rng) {
+        return position;
+    }
+
+    for _ in 0..SPAWN_ATTEMPTS {
+        let angle = rng.random_range(0.0..std::f32::consts::TAU);
+        let position = on_ground(player + Vec3::new(angle.cos(), 0.0, angle.sin()) * radius);
+        if in_bounds(position) && !visible(position) {
+            return position;
+        }
+    }
+
+    // Behind the camera is never in view
+    let behind = camera
+        .map(|(_, camera_transform)| -camera_transform.forward().as_vec3().with_y(0.0))
+        .unwrap_or(Vec3::NEG_Z)
+        .normalize_or(Vec3::NEG_Z);
+    let mut position = on_ground(player + behind * radius);
+    if let Some(bounds) = boundary {
+        position.x = position.x.clamp(bounds.min_x, bounds.max_x);
+        position.z = position.z.clamp(bounds.min_z, bounds.max_z);
+    }
+    position
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn director() -> WaveDirector {
+        WaveDirector::new(vec![
+            WaveDefinition {
+                enemies: vec![WaveSpawn::new("husk", 2), WaveSpawn::new("warden", 1)],
+                spawn_radius: 20.0,
+                interval: 1.0,
+            },
+            WaveDefinition {
+                enemies: vec![WaveSpawn::new("charger", 1)],
+                spawn_radius: 20.0,
+                interval: 1.0,
+            },
+        ])
+    }
+
+    #[test]
+    fn spawns_the_wave_mix_in_order_then_moves_to_the_next_wave() {
+        let mut director = director();
+        assert_eq!(director.tick(FIRST_WAVE_DELAY, true).as_deref(), Some("husk"));
+        assert_eq!(director.tick(0.5, true), None);
+        assert_eq!(director.tick(0.5, true).as_deref(), Some("husk"));
+        assert_eq!(director.tick(1.0, true).as_deref(), Some("warden"));
+        assert_eq!(director.wave_number(), 2);
+        assert_eq!(director.tick(WAVE_BREAK, true).as_deref(), Some("charger"));
+        // The last wave repeats
+        assert_eq!(director.tick(WAVE_BREAK, true).as_deref(), Some("charger"));
+    }
+
+    #[test]
+    fn due_spawns_wait_while_the_cap_is_reached() {
+        let mut director = director();
+        assert_eq!(director.tick(FIRST_WAVE_DELAY, false), None);
+        assert_eq!(director.tick(0.0, true).as_deref(), Some("husk"));
+    }
+
+    #[test]
+    fn difficulty_grows_with_time() {
+        let mut director = director();
+        assert_eq!(director.difficulty(), 1.0);
+        director.elapsed = 120.0;
+        assert!(director.difficulty() > 1.0);
+        assert_eq!(director.loot_level(), 3);
+    }
+
+    #[test]
+    fn the_first_outcome_is_final() {
+        let mut director = director();
+        director.tick(SURVIVAL_DURATION, true);
+        assert_eq!(director.outcome, Some(SurvivalOutcome::Survived));
+        director.fail();
+        director.resume();
+        assert_eq!(director.outcome, Some(SurvivalOutcome::Survived));
+        assert_eq!(director.tick(1.0, true), None);
+    }
+
+    #[test]
+    fn waves_pause_while_the_player_is_dead() {
+        let mut director = director();
+        director.fail();
+        assert_eq!(director.tick(FIRST_WAVE_DELAY + 1.0, true), None);
+        director.resume();
+        assert_eq!(director.outcome, None);
+        assert!(director.tick(FIRST_WAVE_DELAY + 1.0, true).is_some());
+    }
+}
@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+
+use crate::assets::FontAssets;
+use crate::game::Health;
+use crate::systems::boss::Boss;
+use crate::systems::combat::Defeated;
+use crate::ui::widgets::{BOSS_BAR_WIDTH, BossBarFill, BossPhaseText, boss_bar_widget};
+
+// ===== COMPONENTS =====
+
+/// Root of the boss bar, pointing at the boss it shows
+#[derive(Component)]
+pub struct BossBarUI(pub Entity);
+
+// ===== SYSTEMS =====
+
+/// Shows a bar for the first living boss and keeps its health and phase in sync
+pub fn sync_boss_bar(
+    mut commands: Commands,
+    font_assets: Option<Res<FontAssets>>,
+    bosses: Query<(Entity, &Boss, &Health), Without<Defeated>>,
+    bars: Query<(Entity, &BossBarUI)>,
+    mut fill_query: Query<&mut Node, With<BossBarFill>>,
+    mut phase_query: Query<&mut Text, With<BossPhaseText>>,
+) {
+    let boss = bosses.iter().next();
+
+    // Drop bars of bosses that are gone or no longer shown
+    let mut shown = false;
+    for (bar, BossBarUI(target)) in &bars {
+        if boss.is_some_and(|(entity, _, _)| entity == *target) {
+            shown = true;
+        } else {
+            commands.entity(bar).despawn();
+        }
+    }
+
+    let Some((entity, boss, health)) = boss else {
+        return;
+    };
+    let phase = boss.current().map_or("", |phase| phase.name.as_str());
+    if !shown {
+        let Some(font_assets) = font_assets else {
+            return;
+        };
+        commands.spawn((
+            boss_bar_widget(&boss.title, phase, font_assets.rajdhani_bold.clone()),
+            BossBarUI(entity),
+        ));
+        return;
+    }
+
+    let width = Val::Px(BOSS_BAR_WIDTH * health.current as f32 / health.max.max(1) as f32);
+    for mut node in &mut fill_query {
+        if node.width != width {
+            node.width = width;
+        }
+    }
+    for mut text in &mut phase_query {
+        if text.0 != phase {
+            text.0 = phase.to_string();
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use bevy::prelude::*;
+
+use crate::assets::FontAssets;
+use crate::constants::player::DEATH_SEQUENCE_DURATION;
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::death::{Checkpoint, DeathSequence, RespawnEvent, RunEndedEvent, RunStats};
+use crate::ui::focus::{Activate, Focusable};
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== COMPONENTS =====
+
+/// Full-screen "You Died" panel with the run summary
+#[derive(Component)]
+pub struct DeathScreenUI;
+
+// ===== SYSTEMS =====
+
+/// Shows the run summary once the death sequence has played out
+pub fn show_death_screen(
+    mut commands: Commands,
+    mut death: ResMut<DeathSequence>,
+    stats: Res<RunStats>,
+    checkpoint: Res<Checkpoint>,
+    font_assets: Option<Res<FontAssets>>,
+) {
+    if death.summary_shown || death.elapsed < DEATH_SEQUENCE_DURATION {
+        return;
+    }
+    let Some(font_assets) = font_assets else {
+        return;
+    };
+    death.summary_shown = true;
+
+    let font = font_assets.rajdhani_bold.clone();
+    let elapsed = stats.elapsed as u32;
+    let summary = [
+        ("TIME", format!("{:02}:{:02}", elapsed / 60, elapsed % 60)),
+        ("COINS", stats.coins.to_string()),
+        ("KILLS", stats.kills.to_string()),
+        ("DEATHS", stats.deaths.to_string()),
+        ("SCORE", stats.score().to_string()),
+    ];
+
+    commands
+        .spawn((
+            Name::new("Death Screen"),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.0, 0.0, 0.7)),
+            ZIndex(95),
+            DeathScreenUI,
+            PlayingScene,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(40.0)),
+                        row_gap: Val::Px(12.0),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.10, 0.14, 0.95)),
+                    BorderColor(Color::DANGER_RED),
+                    BorderRadius::all(Val::Px(12.0)),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new("YOU DIED"),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 64.0,
+                            ..default()
+                        },
+                        TextColor(Color::DANGER_RED),
+                    ));
+                    panel.spawn((
+                        Text::new("The descent claims another soul"),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+
+                    // Run summary
+                    panel
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Px(32.0),
+                            margin: UiRect::vertical(Val::Px(16.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            for (label, value) in summary {
+                                row.spawn(Node {
+                                    flex_direction: FlexDirection::Column,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                })
+                                .with_children(|stat| {
+                                    stat.spawn((
+                                        Text::new(value),
+                                        TextFont {
+                                            font: font.clone(),
+                                            font_size: 32.0,
+                                            ..default()
+                                        },
+                                        TextColor(Color::ELYSIUM_GOLD),
+                                    ));
+                                    stat.spawn((
+                                        Text::new(label),
+                                        TextFont {
+                                            font: font.clone(),
+                                            font_size: 16.0,
+                                            ..default()
+                                        },
+                                        TextColor(Color::WHITE.with_alpha(0.7)),
+                                    ));
+                                });
+                            }
+                        });
+
+                    // Choices
+                    panel
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Px(16.0),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            if checkpoint.0.is_some() {
+                                row.spawn((death_button("RESPAWN AT CHECKPOINT", font.clone()), Focusable::new(0, 0))).observe(
+                                    |_: Trigger<Activate>, mut respawn_events: EventWriter<RespawnEvent>| {
+                                        respawn_events.write(RespawnEvent);
+                                    },
+                                );
+                            }
+                            row.spawn((death_button("RESTART RUN", font.clone()), Focusable::new(0, 1))).observe(
+                                |_: Trigger<Activate>,
+                                 stats: Res<RunStats>,
+                                 mut ended_events: EventWriter<RunEndedEvent>,
+                                 mut next_state: ResMut<NextState<Screen>>| {
+                                    ended_events.write(RunEndedEvent { stats: *stats });
+                                    next_state.set(Screen::PreGameLoading);
+                                },
+                            );
+                            row.spawn((death_button("MAIN MENU", font.clone()), Focusable::new(0, 2))).observe(
+                                |_: Trigger<Activate>,
+                                 stats: Res<RunStats>,
+                                 mut ended_events: EventWriter<RunEndedEvent>,
+                                 mut next_state: ResMut<NextState<Screen>>| {
+                                    ended_events.write(RunEndedEvent { stats: *stats });
+                                    next_state.set(Screen::MainMenu);
+                                },
+                            );
+                        });
+                });
+        });
+}
+
+fn death_button(label: &str, font: Handle<Font>) -> impl Bundle {
+    (
+        Node {
+            padding: UiRect::axes(Val::Px(20.0), Val::Px(8.0)),
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        },
+        BackgroundColor(Color::ELYSIUM_GOLD_DIM),
+        BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.6)),
+        BorderRadius::all(Val::Px(8.0)),
+        children![(
+            Text::new(label),
+            TextFont {
+                font,
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Pickable::IGNORE,
+        )],
+    )
+}
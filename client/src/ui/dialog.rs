@@ -7,11 +7,9 @@ use bevy::prelude::*;
 #[derive(Component)]
 pub struct Dialog;
 
-#[derive(Component)]
-pub struct DialogProximity {
-    pub target_position: Vec3,
-    pub proximity_threshold: f32,
-}
+/// Text shown by a dialog; edit it to change the label of a spawned dialog
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct DialogText(pub String);
 
 #[derive(Resource, Clone)]
 pub struct DialogConfig {
@@ -33,7 +31,7 @@ pub enum DialogPosition {
 impl Default for DialogConfig {
     fn default() -> Self {
         Self {
-            text: "Press E to enter".to_string(),
+            text: String::new(),
             width: 26.67, // Reduced from 40.0 by 1.5x (40.0 / 1.5)
             height: 5.33, // Reduced from 8.0 by 1.5x (8.0 / 1.5)
             position: DialogPosition::BottomCenter { bottom_margin: 4.0 },
@@ -46,24 +44,14 @@ impl Default for DialogConfig {
 
 // ===== DIALOG SYSTEMS =====
 
+/// Spawns a hidden dialog; callers decide when to show it
 pub fn spawn_dialog(
     commands: &mut Commands,
     font_assets: &Res<FontAssets>,
     windows: Query<&Window>,
     config: DialogConfig,
     scene_marker: impl Component + Clone,
-) {
-    spawn_dialog_with_proximity(commands, font_assets, windows, config, scene_marker, None);
-}
-
-pub fn spawn_dialog_with_proximity(
-    commands: &mut Commands,
-    font_assets: &Res<FontAssets>,
-    windows: Query<&Window>,
-    config: DialogConfig,
-    scene_marker: impl Component + Clone,
-    proximity: Option<DialogProximity>,
-) {
+) -> Entity {
     let window = windows.single().expect("No primary window");
     let window_height = window.height();
     let _window_width = window.width();
@@ -122,16 +110,12 @@ pub fn spawn_dialog_with_proximity(
         BackgroundColor(config.background_color),
         BorderColor(config.border_color),
         Dialog,
+        DialogText(config.text.clone()),
         scene_marker.clone(),
         Name::new(format!("Dialog: {}", config.text)),
         Visibility::Hidden, // Start hidden
     ));
 
-    // Add proximity component if provided
-    if let Some(proximity) = proximity {
-        entity_commands.insert(proximity);
-    }
-
     entity_commands.with_children(|parent| {
         parent.spawn(label_widget(
             responsive_font_size,
@@ -139,6 +123,7 @@ pub fn spawn_dialog_with_proximity(
             config.text.clone(),
         ));
     });
+    entity_commands.id()
 }
 
 pub fn animate_dialog(time: Res<Time>, mut query: Query<&mut BackgroundColor, With<Dialog>>) {
@@ -154,29 +139,17 @@ pub fn animate_dialog(time: Res<Time>, mut query: Query<&mut BackgroundColor, Wi
     }
 }
 
-pub fn check_dialog_proximity(
-    player_query: Query<
-        &Transform,
-        With<crate::systems::character_controller::CharacterController>,
-    >,
-    mut dialog_query: Query<(&mut Visibility, Option<&DialogProximity>), With<Dialog>>,
+/// Copies a changed `DialogText` into the dialog's label
+pub fn sync_dialog_text(
+    dialog_query: Query<(Entity, &DialogText), (With<Dialog>, Changed<DialogText>)>,
+    children_query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
 ) {
-    let Ok(player_transform) = player_query.single() else {
-        return;
-    };
-
-    for (mut visibility, proximity) in dialog_query.iter_mut() {
-        if let Some(proximity) = proximity {
-            // Check distance to target position
-            let distance = player_transform.translation.distance(proximity.target_position);
-            if distance <= proximity.proximity_threshold {
-                *visibility = Visibility::Visible;
-            } else {
-                *visibility = Visibility::Hidden;
+    for (dialog, dialog_text) in &dialog_query {
+        for descendant in children_query.iter_descendants(dialog) {
+            if let Ok(mut text) = text_query.get_mut(descendant) {
+                text.0.clone_from(&dialog_text.0);
             }
-        } else {
-            // For dialogs without proximity, keep them hidden
-            *visibility = Visibility::Hidden;
         }
     }
 }
@@ -189,6 +162,6 @@ pub struct DialogPlugin;
 
 impl Plugin for DialogPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (animate_dialog, check_dialog_proximity));
+        app.add_systems(Update, (animate_dialog, sync_dialog_text));
     }
 }
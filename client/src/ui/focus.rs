@@ -0,0 +1,301 @@
+use bevy::prelude::*;
+
+use crate::screens::Screen;
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== COMPONENTS =====
+
+/// A menu element the keyboard and gamepad can focus. Elements sit on a grid: Up and
+/// Down move between rows, Left and Right along a row. Rows and columns only need to
+/// be ordered, not consecutive. Only the highest layer on screen can take the focus,
+/// so overlays keep it away from the menu underneath.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Focusable {
+    pub row: i32,
+    pub column: i32,
+    pub layer: u8,
+}
+
+impl Focusable {
+    pub fn new(row: i32, column: i32) -> Self {
+        Self {
+            row,
+            column,
+            layer: 0,
+        }
+    }
+
+    pub fn on_layer(mut self, layer: u8) -> Self {
+        self.layer = layer;
+        self
+    }
+}
+
+// ===== EVENTS =====
+
+/// Triggered on a focusable element when it is clicked, or confirmed while focused
+#[derive(Event, Clone, Copy, Debug)]
+pub struct Activate;
+
+/// Triggered on an element when it takes the focus. Lunex elements observe it with
+/// `hover_set` to play their hover state.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct Focused;
+
+/// Triggered on an element when it loses the focus
+#[derive(Event, Clone, Copy, Debug)]
+pub struct Unfocused;
+
+/// Focus input from the menu actions
+#[derive(Event, Clone, Copy, Debug)]
+pub enum FocusRequestEvent {
+    /// One step on the grid, with +y going down a row
+    Navigate(IVec2),
+    Confirm,
+}
+
+// ===== RESOURCES =====
+
+/// The focused element. Its grid position is kept too, so the focus survives menus
+/// that respawn their elements when a value changes.
+#[derive(Resource, Default)]
+struct MenuFocus {
+    entity: Option<Entity>,
+    position: Option<Focusable>,
+    highlighted: Option<Entity>,
+}
+
+/// While set, focus input is ignored, e.g. while the Controls tab waits for a key
+#[derive(Resource, Default)]
+pub struct FocusLock(pub bool);
+
+// ===== PLUGIN =====
+
+pub struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MenuFocus>()
+            .init_resource::<FocusLock>()
+            .add_event::<FocusRequestEvent>()
+            .add_observer(activate_on_click)
+            .add_systems(
+                Update,
+                (
+                    clear_focus_on_screen_change,
+                    handle_focus_requests,
+                    highlight_focus,
+                )
+                    .chain(),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+/// Clicks go through `Activate` too, so mouse and gamepad share one handler
+fn activate_on_click(
+    trigger: Trigger<Pointer<Click>>,
+    focusables: Query<(), With<Focusable>>,
+    mut commands: Commands,
+) {
+    if focusables.contains(trigger.target()) {
+        commands.trigger_targets(Activate, trigger.target());
+    }
+}
+
+/// A new screen starts without a focus, until the player navigates
+fn clear_focus_on_screen_change(
+    mut transitions: EventReader<StateTransitionEvent<Screen>>,
+    mut focus: ResMut<MenuFocus>,
+) {
+    if transitions.read().count() > 0 {
+        focus.entity = None;
+        focus.position = None;
+    }
+}
+
+fn handle_focus_requests(
+    mut requests: EventReader<FocusRequestEvent>,
+    mut focus: ResMut<MenuFocus>,
+    lock: Res<FocusLock>,
+    focusables: Query<(Entity, &Focusable)>,
+    mut commands: Commands,
+) {
+    if lock.0 {
+        requests.clear();
+        return;
+    }
+    let candidates = top_layer(&focusables);
+    let mut current = current_focus(&focus, &candidates);
+
+    for request in requests.read() {
+        match *request {
+            FocusRequestEvent::Navigate(step) => {
+                if let Some((entity, position)) = next_focus(current, &candidates, step) {
+                    focus.entity = Some(entity);
+                    focus.position = Some(position);
+                    current = Some((entity, position));
+                }
+            }
+            FocusRequestEvent::Confirm => {
+                if let Some((entity, _)) = current {
+                    commands.trigger_targets(Activate, entity);
+                }
+            }
+        }
+    }
+}
+
+/// Outlines the focused UI node and tells the element it gained the focus
+fn highlight_focus(
+    mut focus: ResMut<MenuFocus>,
+    focusables: Query<(Entity, &Focusable)>,
+    nodes: Query<(), With<Node>>,
+    mut commands: Commands,
+) {
+    let candidates = top_layer(&focusables);
+    let current = current_focus(&focus, &candidates).map(|(entity, _)| entity);
+    if current != focus.entity {
+        focus.entity = current;
+    }
+    if focus.highlighted == current {
+        return;
+    }
+
+    // The previous element may have been despawned with its menu
+    if let Some(previous) = focus
+        .highlighted
+        .filter(|&previous| focusables.contains(previous))
+    {
+        commands.entity(previous).remove::<Outline>();
+        commands.trigger_targets(Unfocused, previous);
+    }
+    if let Some(entity) = current {
+        commands.trigger_targets(Focused, entity);
+        if nodes.contains(entity) {
+            commands.entity(entity).insert(Outline::new(
+                Val::Px(3.0),
+                Val::Px(2.0),
+                Color::ELYSIUM_DESCENT_YELLOW,
+            ));
+        }
+    }
+    focus.highlighted = current;
+}
+
+// ===== HELPERS =====
+
+fn top_layer(focusables: &Query<(Entity, &Focusable)>) -> Vec<(Entity, Focusable)> {
+    let top = focusables
+        .iter()
+        .map(|(_, focusable)| focusable.layer)
+        .max();
+    focusables
+        .iter()
+        .filter(|(_, focusable)| Some(focusable.layer) == top)
+        .map(|(entity, focusable)| (entity, *focusable))
+        .collect()
+}
+
+/// The focused element if it is still on screen, or its respawned replacement
+fn current_focus(
+    focus: &MenuFocus,
+    candidates: &[(Entity, Focusable)],
+) -> Option<(Entity, Focusable)> {
+    candidates
+        .iter()
+        .find(|(entity, _)| Some(*entity) == focus.entity)
+        .or_else(|| {
+            candidates
+                .iter()
+                .find(|(_, focusable)| Some(*focusable) == focus.position)
+        })
+        .copied()
+}
+
+/// Where one step from `current` lands. Without a focus, any step focuses the first element.
+fn next_focus(
+    current: Option<(Entity, Focusable)>,
+    candidates: &[(Entity, Focusable)],
+    step: IVec2,
+) -> Option<(Entity, Focusable)> {
+    let Some((_, from)) = current else {
+        return candidates
+            .iter()
+            .min_by_key(|(_, focusable)| (focusable.row, focusable.column))
+            .copied();
+    };
+
+    if step.y != 0 {
+        // The nearest row in that direction, then the closest column on it
+        let row = candidates
+            .iter()
+            .map(|(_, focusable)| focusable.row)
+            .filter(|&row| (row - from.row).signum() == step.y.signum())
+            .min_by_key(|&row| (row - from.row).abs())?;
+        candidates
+            .iter()
+            .filter(|(_, focusable)| focusable.row == row)
+            .min_by_key(|(_, focusable)| (focusable.column - from.column).abs())
+            .copied()
+    } else {
+        candidates
+            .iter()
+            .filter(|(_, focusable)| {
+                focusable.row == from.row
+                    && (focusable.column - from.column).signum() == step.x.signum()
+            })
+            .min_by_key(|(_, focusable)| (focusable.column - from.column).abs())
+            .copied()
+    }
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Vec<(Entity, Focusable)> {
+        // Two tabs above three rows of lower/raise buttons
+        let mut grid = vec![
+            (Entity::from_raw(0), Focusable::new(-1, 0)),
+            (Entity::from_raw(1), Focusable::new(-1, 1)),
+        ];
+        for row in 0..3 {
+            for column in 0..2 {
+                let index = 2 + row as u32 * 2 + column as u32;
+                grid.push((Entity::from_raw(index), Focusable::new(row * 14, column)));
+            }
+        }
+        grid
+    }
+
+    #[test]
+    fn first_step_focuses_the_first_element() {
+        let grid = grid();
+        let first = next_focus(None, &grid, IVec2::Y).unwrap();
+        assert_eq!(first.1, Focusable::new(-1, 0));
+    }
+
+    #[test]
+    fn vertical_steps_keep_the_closest_column() {
+        let grid = grid();
+        let raise = grid[3];
+        let below = next_focus(Some(raise), &grid, IVec2::Y).unwrap();
+        assert_eq!(below.1, Focusable::new(14, 1));
+        let above = next_focus(Some(raise), &grid, IVec2::NEG_Y).unwrap();
+        assert_eq!(above.1, Focusable::new(-1, 1));
+    }
+
+    #[test]
+    fn steps_off_the_grid_keep_the_focus() {
+        let grid = grid();
+        let last = grid[grid.len() - 1];
+        assert_eq!(next_focus(Some(last), &grid, IVec2::Y), None);
+        assert_eq!(next_focus(Some(last), &grid, IVec2::X), None);
+        let left = next_focus(Some(last), &grid, IVec2::NEG_X).unwrap();
+        assert_eq!(left.1, Focusable::new(28, 0));
+    }
+}
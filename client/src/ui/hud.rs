@@ -0,0 +1,247 @@
+use bevy::prelude::*;
+
+use crate::assets::FontAssets;
+use crate::game::Health;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::enemy_ai::Enemy;
+use crate::ui::styles::ElysiumDescentColorPalette;
+use crate::constants::player::XP_PER_LEVEL;
+use crate::systems::progression::Experience;
+use crate::ui::widgets::{
+    HudHealthFill, HudHealthText, HudLevelText, HudPosition, HudXpFill, HudXpText,
+};
+
+/// Maximum toasts shown at once; older ones are dropped first
+const MAX_VISIBLE_NOTIFICATIONS: usize = 4;
+const NOTIFICATION_LIFETIME: f32 = 2.5;
+/// Inner width of the HUD bars in `player_hud_widget`
+const HUD_BAR_WIDTH: f32 = 417.0;
+
+// ===== EVENTS =====
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NotificationKind {
+    Info,
+    Success,
+    Warning,
+}
+
+impl NotificationKind {
+    fn color(&self) -> Color {
+        match self {
+            NotificationKind::Info => Color::WHITE,
+            NotificationKind::Success => Color::HEALTH_GREEN,
+            NotificationKind::Warning => Color::WARNING_ORANGE,
+        }
+    }
+}
+
+/// Short message shown as a fading toast under the player HUD
+#[derive(Event, Debug, Clone)]
+pub struct HudNotificationEvent {
+    pub message: String,
+    pub kind: NotificationKind,
+}
+
+impl HudNotificationEvent {
+    pub fn new(message: impl Into<String>, kind: NotificationKind) -> Self {
+        Self {
+            message: message.into(),
+            kind,
+        }
+    }
+}
+
+// ===== COMPONENTS =====
+
+/// Column that holds the notification toasts
+#[derive(Component)]
+pub struct NotificationFeed;
+
+#[derive(Component)]
+struct HudToast {
+    timer: Timer,
+    color: Color,
+}
+
+// ===== SPAWNING =====
+
+pub fn spawn_notification_feed<T: Component + Default>(commands: &mut Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(230.0), // Just below the player HUD
+            left: Val::Px(32.0),
+            width: Val::Px(630.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::FlexStart,
+            ..default()
+        },
+        Name::new("Notification Feed"),
+        Pickable::IGNORE,
+        NotificationFeed,
+        T::default(),
+    ));
+}
+
+// ===== SYSTEMS =====
+
+fn spawn_notifications(
+    mut commands: Commands,
+    mut events: EventReader<HudNotificationEvent>,
+    feed_query: Query<Entity, With<NotificationFeed>>,
+    toasts: Query<Entity, With<HudToast>>,
+    font_assets: Option<Res<FontAssets>>,
+) {
+    let (Ok(feed), Some(font_assets)) = (feed_query.single(), font_assets) else {
+        events.clear();
+        return;
+    };
+
+    let mut visible: Vec<Entity> = toasts.iter().collect();
+    for event in events.read() {
+        if visible.len() >= MAX_VISIBLE_NOTIFICATIONS {
+            commands.entity(visible.remove(0)).despawn();
+        }
+
+        let color = event.kind.color();
+        let toast = commands
+            .spawn((
+                Node {
+                    margin: UiRect::bottom(Val::Px(8.0)),
+                    padding: UiRect::axes(Val::Px(18.0), Val::Px(8.0)),
+                    border: UiRect::left(Val::Px(4.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::DARK_GLASS),
+                BorderColor(color),
+                BorderRadius::all(Val::Px(8.0)),
+                HudToast {
+                    timer: Timer::from_seconds(NOTIFICATION_LIFETIME, TimerMode::Once),
+                    color,
+                },
+                children![(
+                    Text::new(event.message.clone()),
+                    TextFont {
+                        font: font_assets.rajdhani_extra_bold.clone(),
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(color),
+                )],
+            ))
+            .id();
+        commands.entity(feed).add_child(toast);
+        visible.push(toast);
+    }
+}
+
+fn fade_notifications(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toasts: Query<(Entity, &mut HudToast, &mut BorderColor, &Children)>,
+    mut texts: Query<&mut TextColor>,
+) {
+    for (entity, mut toast, mut border, children) in &mut toasts {
+        toast.timer.tick(time.delta());
+        if toast.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        // Fade out over the last third of the lifetime
+        let alpha = (toast.timer.remaining_secs() / (NOTIFICATION_LIFETIME / 3.0)).min(1.0);
+        border.0 = toast.color.with_alpha(alpha);
+        for child in children.iter() {
+            if let Ok(mut text_color) = texts.get_mut(child) {
+                text_color.0 = toast.color.with_alpha(alpha);
+            }
+        }
+    }
+}
+
+/// Keeps the HUD health readouts in sync: the left HUD shows the player,
+/// the right HUD the first enemy in the scene
+fn update_health_hud(
+    player_query: Query<&Health, With<CharacterController>>,
+    enemy_query: Query<&Health, With<Enemy>>,
+    mut text_query: Query<(&HudHealthText, &mut Text)>,
+    mut fill_query: Query<(&HudHealthFill, &mut Node)>,
+) {
+    let readouts = [
+        (HudPosition::Left, player_query.single().ok()),
+        (HudPosition::Right, enemy_query.iter().next()),
+    ];
+
+    for (position, health) in readouts {
+        let Some(health) = health else {
+            continue;
+        };
+
+        let label = format!("{}/{}", health.current, health.max);
+        for (marker, mut text) in &mut text_query {
+            if marker.0 == position && text.0 != label {
+                text.0 = label.clone();
+            }
+        }
+
+        let percent = health.current as f32 / health.max.max(1) as f32;
+        let width = Val::Px(HUD_BAR_WIDTH * percent);
+        for (marker, mut node) in &mut fill_query {
+            if marker.0 == position && node.width != width {
+                node.width = width;
+            }
+        }
+    }
+}
+
+/// Keeps the left (player) HUD level badge and experience bar in sync with `Experience`
+fn update_experience_hud(
+    player_query: Query<&Experience, With<CharacterController>>,
+    mut level_query: Query<(&HudLevelText, &mut Text), Without<HudXpText>>,
+    mut xp_text_query: Query<(&HudXpText, &mut Text), Without<HudLevelText>>,
+    mut fill_query: Query<(&HudXpFill, &mut Node)>,
+) {
+    let Ok(experience) = player_query.single() else {
+        return;
+    };
+
+    let level = experience.level.to_string();
+    for (marker, mut text) in &mut level_query {
+        if marker.0 == HudPosition::Left && text.0 != level {
+            text.0 = level.clone();
+        }
+    }
+
+    let label = format!("{}/{}", experience.progress(), XP_PER_LEVEL);
+    for (marker, mut text) in &mut xp_text_query {
+        if marker.0 == HudPosition::Left && text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+
+    let width = Val::Px(HUD_BAR_WIDTH * experience.progress() as f32 / XP_PER_LEVEL as f32);
+    for (marker, mut node) in &mut fill_query {
+        if marker.0 == HudPosition::Left && node.width != width {
+            node.width = width;
+        }
+    }
+}
+
+// ===== PLUGIN =====
+
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<HudNotificationEvent>().add_systems(
+            Update,
+            (
+                spawn_notifications,
+                fade_notifications,
+                update_health_hud,
+                update_experience_hud,
+            ),
+        );
+    }
+}
@@ -2,7 +2,10 @@ use bevy::prelude::*;
 
 use crate::assets::FontAssets;
 use crate::assets::UiAssets;
-use crate::systems::collectibles::{CollectibleType, NextItemToAdd};
+use crate::systems::collectibles::CollectibleType;
+use crate::systems::inventory::{Inventory, Wallet};
+use crate::systems::items::ItemCooldowns;
+use crate::ui::styles::ElysiumDescentColorPalette;
 
 // Inventory UI marker
 #[derive(Component)]
@@ -13,14 +16,19 @@ pub struct InventorySlot {
     pub index: usize,
 }
 
+/// Marker for the icon + count node rendered inside a slot for its current stack
 #[derive(Component)]
-pub struct InventoryItem {
-    pub item_type: CollectibleType,
-    pub count: usize,
-}
+pub struct InventorySlotContent;
+
+/// Gold counter shown on the hotbar's top edge
+#[derive(Component)]
+pub struct WalletText;
 
+/// Dimmed overlay with a countdown shown over a slot while its item is cooling down
 #[derive(Component)]
-pub struct CountText;
+pub struct SlotCooldownOverlay {
+    pub item_type: CollectibleType,
+}
 
 #[derive(Resource)]
 pub struct InventoryVisibilityState {
@@ -73,7 +81,6 @@ pub fn adjust_inventory_for_dialogs(
 }
 
 pub fn spawn_inventory_ui<T: Component + Default>(commands: &mut Commands) {
-    use crate::ui::styles::ElysiumDescentColorPalette;
     commands
         .spawn((
             Node {
@@ -98,6 +105,21 @@ pub fn spawn_inventory_ui<T: Component + Default>(commands: &mut Commands) {
             T::default(),
         ))
         .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(-30.0),
+                    right: Val::Px(24.0),
+                    ..default()
+                },
+                Text::new("0 Gold"),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::ELYSIUM_GOLD),
+                WalletText,
+            ));
             for i in 0..6 {
                 parent.spawn((
                     Node {
@@ -146,137 +168,201 @@ pub fn toggle_inventory_visibility(
     state.visible = true;
 }
 
-pub fn add_item_to_inventory(
+/// Spawns the icon for an item type. Coins use their sprite; item types without
+/// artwork yet get a tinted badge with a short label.
+pub fn spawn_item_icon(
+    parent: &mut ChildSpawnerCommands,
+    item_type: CollectibleType,
+    size: f32,
+    ui_assets: &UiAssets,
+    font: Handle<Font>,
+) {
+    let (label, color) = match item_type {
+        CollectibleType::Coin => {
+            parent.spawn((
+                Node {
+                    width: Val::Px(size),
+                    height: Val::Px(size),
+                    ..default()
+                },
+                ImageNode {
+                    image: ui_assets.coin.clone(),
+                    ..default()
+                },
+                ZIndex(1),
+            ));
+            return;
+        }
+        CollectibleType::HealthPotion => ("HP", Color::DANGER_RED),
+        CollectibleType::SurvivalKit => ("KIT", Color::SUCCESS_GREEN),
+        CollectibleType::Book => ("TOME", Color::ELYSIUM_PURPLE),
+    };
+
+    parent.spawn((
+        Node {
+            width: Val::Px(size * 0.7),
+            height: Val::Px(size * 0.7),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(color.with_alpha(0.85)),
+        BorderColor(Color::ELYSIUM_GOLD),
+        BorderRadius::all(Val::Px(size * 0.2)),
+        ZIndex(1),
+        children![(
+            Text::new(label),
+            TextFont {
+                font,
+                font_size: size * 0.22,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        )],
+    ));
+}
+
+/// Re-renders the hotbar slots from the [`Inventory`] resource whenever it changes
+/// (or when the hotbar has just been spawned).
+pub fn sync_inventory_ui(
     mut commands: Commands,
-    mut slot_query: Query<(Entity, &InventorySlot)>,
-    children_query: Query<&Children>,
-    mut item_query: Query<&mut InventoryItem>,
-    mut text_query: Query<&mut Text>,
+    inventory: Res<Inventory>,
+    slot_query: Query<(Entity, &InventorySlot)>,
+    added_slots: Query<(), Added<InventorySlot>>,
+    content_query: Query<Entity, With<InventorySlotContent>>,
     font_assets: Res<FontAssets>,
     ui_assets: Res<UiAssets>,
-    collectible_type: Option<Res<NextItemToAdd>>,
     mut visibility_state: ResMut<InventoryVisibilityState>,
-    mut ui_query: Query<&mut Visibility, With<InventoryUI>>,
 ) {
-    let Some(collectible_type) = collectible_type else {
+    if !inventory.is_changed() && added_slots.is_empty() {
         return;
-    };
+    }
 
-    if let Ok(mut visibility) = ui_query.single_mut() {
-        *visibility = Visibility::Visible;
-        visibility_state.visible = true;
-        visibility_state.timer.reset();
+    visibility_state.visible = true;
+    visibility_state.timer.reset();
+
+    for entity in &content_query {
+        commands.entity(entity).despawn();
     }
 
-    // First: look for a slot that already has this item
-    for (slot_entity, _) in slot_query.iter_mut() {
-        if let Ok(children) = children_query.get(slot_entity) {
-            for child in children.iter() {
-                if let Ok(mut item) = item_query.get_mut(child) {
-                    if item.item_type == collectible_type.0 {
-                        // Found matching item → increase count
-                        item.count += 1;
+    for (slot_entity, slot) in &slot_query {
+        let Some(Some(stack)) = inventory.slots().get(slot.index) else {
+            continue;
+        };
 
-                        // Now do some iterations to update the text count
-                        if let Ok(grandchildren) = children_query.get(child) {
-                            if let Ok(grand_grandchildren) = children_query.get(grandchildren[1]) {
-                                if let Some(&leaf) = grand_grandchildren.first() {
-                                    if let Ok(mut text) = text_query.get_mut(leaf) {
-                                        text.clear();
-                                        text.push_str(&item.count.to_string());
-                                    }
-                                }
-                            }
-                        };
+        commands.entity(slot_entity).with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    ZIndex(-1),
+                    InventorySlotContent,
+                ))
+                .with_children(|item_parent| {
+                    spawn_item_icon(
+                        item_parent,
+                        stack.item_type,
+                        133.0,
+                        &ui_assets,
+                        font_assets.rajdhani_extra_bold.clone(),
+                    );
 
-                        commands.remove_resource::<NextItemToAdd>();
-                        return;
-                    }
-                }
-            }
-        }
-    }
+                    // Count badge (perfect circle, bottom right of the icon)
+                    item_parent.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            width: Val::Px(33.0),
+                            height: Val::Px(33.0),
+                            right: Val::Px(-4.0),
+                            bottom: Val::Px(13.0),
+                            align_items: AlignItems::Center,
+                            justify_content: JustifyContent::Center,
+                            border: UiRect::all(Val::Px(1.0)),
+                            ..default()
+                        },
+                        BorderRadius::all(Val::Px(16.5)), // Half of width/height for perfect circle
+                        BorderColor(Color::srgba(0.0, 0.0, 0.0, 0.4)),
+                        BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.98)),
+                        ZIndex(2),
+                        children![(
+                            TextFont {
+                                font_size: 19.0,
+                                font: font_assets.rajdhani_extra_bold.clone(),
+                                ..default()
+                            },
+                            Text::new(stack.count.to_string()),
+                            TextColor(Color::srgb(0.1, 0.1, 0.1)),
+                        )],
+                    ));
 
-    // Otherwise: find first empty slot and add the item
-    let mut sorted_slots: Vec<(Entity, &InventorySlot)> = slot_query.iter_mut().collect();
-    sorted_slots.sort_by_key(|(_, slot)| slot.index);
-    for (slot_entity, _) in sorted_slots {
-        // Check if slot has any InventoryItem children (not just if it has any children)
-        let has_item = children_query
-            .get(slot_entity)
-            .map_or(false, |children| {
-                children.iter().any(|child| item_query.get(child).is_ok())
-            });
-        
-        if !has_item {
-            commands.entity(slot_entity).with_children(|parent| {
-                parent
-                    .spawn((
+                    // Cooldown overlay, shown by `update_slot_cooldowns`
+                    item_parent.spawn((
                         Node {
+                            position_type: PositionType::Absolute,
                             width: Val::Percent(100.0),
                             height: Val::Percent(100.0),
                             align_items: AlignItems::Center,
                             justify_content: JustifyContent::Center,
                             ..default()
                         },
-                        ZIndex(-1),
-                        InventoryItem {
-                            item_type: collectible_type.0,
-                            count: 1,
+                        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+                        BorderRadius::all(Val::Px(12.0)),
+                        Visibility::Hidden,
+                        ZIndex(3),
+                        SlotCooldownOverlay {
+                            item_type: stack.item_type,
                         },
-                    ))
-                    .with_children(|item_parent| {
-                        // spawn the image (larger, centered)
-                        item_parent.spawn((
-                            Node {
-                                width: Val::Px(133.0),
-                                height: Val::Px(133.0),
-                                justify_content: JustifyContent::Center,
-                                align_items: AlignItems::Center,
-                                ..default()
-                            },
-                            ImageNode {
-                                image: ui_assets.coin.clone(),
+                        children![(
+                            TextFont {
+                                font_size: 32.0,
+                                font: font_assets.rajdhani_extra_bold.clone(),
                                 ..default()
                             },
-                            ZIndex(1),
-                        ));
-
-                        // Spawn count text (perfect circle badge, bottom right of image)
-                        item_parent
-                            .spawn((
-                                Node {
-                                    position_type: PositionType::Absolute,
-                                    width: Val::Px(33.0),  // Slightly smaller for better proportion
-                                    height: Val::Px(33.0), // Same as width for circle
-                                    right: Val::Px(-20.0),  // Positioned relative to image edge
-                                    bottom: Val::Px(13.0), // Positioned relative to image edge
-                                    align_items: AlignItems::Center,
-                                    justify_content: JustifyContent::Center,
-                                    border: UiRect::all(Val::Px(1.0)), // Add border for definition
-                                    ..default()
-                                },
-                                BorderRadius::all(Val::Px(16.5)), // Half of width/height for perfect circle
-                                BorderColor(Color::srgba(0.0, 0.0, 0.0, 0.4)), // Slightly darker border
-                                BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.98)), // More opaque
-                                ZIndex(2),
-                            ))
-                            .with_children(|text_parent| {
-                                text_parent.spawn((
-                                    TextFont {
-                                        font_size: 19.0, // Adjusted for smaller badge
-                                        font: font_assets.rajdhani_extra_bold.clone(),
-                                        ..default()
-                                    },
-                                    Text::new("1"),
-                                    TextColor(Color::srgb(0.1, 0.1, 0.1)), // Dark gray instead of pure black
-                                ));
-                            });
-                    });
-            });
+                            Text::new(""),
+                            TextColor(Color::WHITE),
+                        )],
+                    ));
+                });
+        });
+    }
+}
 
-            commands.remove_resource::<NextItemToAdd>();
-            return;
+pub fn update_slot_cooldowns(
+    cooldowns: Res<ItemCooldowns>,
+    mut overlay_query: Query<(&SlotCooldownOverlay, &mut Visibility, &Children)>,
+    mut text_query: Query<&mut Text>,
+) {
+    for (overlay, mut visibility, children) in &mut overlay_query {
+        match cooldowns.remaining(overlay.item_type) {
+            Some(remaining) => {
+                *visibility = Visibility::Inherited;
+                for child in children.iter() {
+                    if let Ok(mut text) = text_query.get_mut(child) {
+                        text.0 = format!("{:.1}", remaining);
+                    }
+                }
+            }
+            None => *visibility = Visibility::Hidden,
         }
     }
 }
+
+pub fn sync_wallet_ui(
+    wallet: Res<Wallet>,
+    added_text: Query<(), Added<WalletText>>,
+    mut text_query: Query<&mut Text, With<WalletText>>,
+) {
+    if !wallet.is_changed() && added_text.is_empty() {
+        return;
+    }
+    for mut text in &mut text_query {
+        text.0 = format!("{} Gold", wallet.gold);
+    }
+}
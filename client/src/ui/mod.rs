@@ -1,5 +1,13 @@
+pub mod boss;
+pub mod death;
 pub mod dialog;
+pub mod focus;
+pub mod hud;
 pub mod inventory;
 pub mod modal;
+pub mod sacrifice;
+pub mod saves;
 pub mod styles;
+pub mod turn_combat;
+pub mod waves;
 pub mod widgets;
@@ -339,12 +339,18 @@ pub fn spawn_objectives_modal(commands: &mut Commands, font_assets: &Res<FontAss
 
 pub fn update_quest_list(
     mut commands: Commands,
-    _objective_manager: Res<ObjectiveManager>,
+    objective_manager: Res<ObjectiveManager>,
     font_assets: Option<Res<FontAssets>>,
     ui_assets: Option<Res<UiAssets>>,
     quest_container_query: Query<Entity, With<QuestEntriesContainer>>,
+    new_containers: Query<(), Added<QuestEntriesContainer>>,
     existing_quests: Query<Entity, With<QuestEntry>>,
 ) {
+    // Rebuild only when objectives change or the modal was just spawned
+    if !objective_manager.is_changed() && new_containers.is_empty() {
+        return;
+    }
+
     let Some(font_assets) = font_assets else { return; };
     let Some(ui_assets) = ui_assets else { return; };
 
@@ -355,46 +361,12 @@ pub fn update_quest_list(
         commands.entity(entity).despawn();
     }
 
-    // Create 10 quests total - use existing objectives plus additional ones
-    let quest_titles = [
-        "COLLECT HEALTH POTIONS",
-        "FIND SURVIVAL KITS", 
-        "GATHER ANCIENT BOOKS",
-        "COLLECT GOLDEN COINS",
-        "EXPLORE ANCIENT RUINS",
-        "DEFEAT DARK CREATURES",
-        "RETRIEVE LOST ARTIFACTS",
-        "MASTER THE ELEMENTS",
-        "UNLOCK HIDDEN PASSAGES",
-        "RESTORE THE TEMPLE"
-    ];
-    
-    let quest_descriptions = [
-        "Collect 5 health potions scattered throughout the realm.",
-        "Find 3 survival kits hidden in the wilderness.",
-        "Gather 7 ancient books from the library ruins.",
-        "Collect 10 golden coins from fallen enemies.",
-        "Explore 4 ancient ruins and discover their secrets.",
-        "Defeat 8 dark creatures that roam the shadows.",
-        "Retrieve 6 lost artifacts from the depths.",
-        "Master the four elements: fire, water, earth, and air.",
-        "Unlock 5 hidden passages throughout the realm.",
-        "Restore the ancient temple to its former glory."
-    ];
+    // Active quests first, completed ones at the bottom
+    let mut objectives: Vec<_> = objective_manager.objectives.iter().collect();
+    objectives.sort_by_key(|objective| objective.completed);
 
-    // Spawn 10 quests
-    for i in 0..10 {
-        let quest_objective = crate::systems::objectives::Objective {
-            id: i,
-            title: quest_titles[i].to_string(),
-            description: quest_descriptions[i].to_string(),
-            item_type: crate::systems::collectibles::CollectibleType::Coin,
-            required_count: ((i + 1) * 2) as u32,
-            current_count: if i < 2 { ((i + 1) * 2) as u32 } else { 0 }, // First 2 are completed
-            completed: i < 2,
-        };
-        
-        let quest_entity = spawn_quest_entry(&mut commands, &quest_objective, &font_assets, &ui_assets, i);
+    for objective in objectives {
+        let quest_entity = spawn_quest_entry(&mut commands, objective, &font_assets, &ui_assets);
         commands.entity(quest_container_entity).add_child(quest_entity);
     }
 }
@@ -404,9 +376,8 @@ fn spawn_quest_entry(
     objective: &crate::systems::objectives::Objective,
     font_assets: &Res<FontAssets>,
     ui_assets: &Res<UiAssets>,
-    index: usize,
 ) -> Entity {
-    let is_active = index < 2; // First two quests are active (lighter background)
+    let is_active = !objective.completed; // Active quests get a lighter background
     
     commands.spawn((
         Node {
@@ -459,7 +430,7 @@ fn spawn_quest_entry(
                 children![
                     // Quest title
                     (
-                        Text::new(&objective.title),
+                        Text::new(objective.title.to_uppercase()),
                         TextFont {
                             font: font_assets.rajdhani_bold.clone(),
                             font_size: 27.0, // Scaled up by 1.5x from 18
@@ -473,7 +444,10 @@ fn spawn_quest_entry(
                     ),
                     // Quest description
                     (
-                        Text::new(&objective.description),
+                        Text::new(format!(
+                            "{} ({}/{})",
+                            objective.description, objective.current_count, objective.required_count
+                        )),
                         TextFont {
                             font: font_assets.rajdhani_medium.clone(),
                             font_size: 21.0, // Scaled up by 1.5x from 14
@@ -535,7 +509,7 @@ fn spawn_quest_entry(
                             ),
                             // Reward amount
                             (
-                                Text::new(format!("{} Gold", (objective.id + 1) * 250)),
+                                Text::new(objective.reward_label()),
                                 TextFont {
                                     font: font_assets.rajdhani_medium.clone(),
                                     font_size: 24.0, // Scaled up by 1.5x from 16
@@ -552,21 +526,14 @@ fn spawn_quest_entry(
 }
 
 pub fn toggle_modal_visibility(
-    mut modal_state: ResMut<ModalState>,
+    modal_state: Res<ModalState>,
     mut background_query: Query<&mut Visibility, With<ModalBackground>>,
-    keyboard: Res<ButtonInput<KeyCode>>,
 ) {
-    // Handle Escape key - close modal if open, otherwise let the global handler deal with it
-    if keyboard.just_pressed(KeyCode::Escape) {
-        if modal_state.visible {
-            // Close the modal
-            modal_state.visible = false;
-            
-            for mut visibility in &mut background_query {
-                *visibility = Visibility::Hidden;
-            }
+    // The ReturnToMainMenu action closes the modal by clearing `visible`
+    if modal_state.is_changed() && !modal_state.visible {
+        for mut visibility in &mut background_query {
+            *visibility = Visibility::Hidden;
         }
-        // If modal is not visible, let the global ESC handler (ReturnToMainMenu) take over
     }
 }
 
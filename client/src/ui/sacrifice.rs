@@ -0,0 +1,266 @@
+use bevy::prelude::*;
+
+use crate::assets::FontAssets;
+use crate::constants::sacrifice::SACRIFICE_REQUIRED_VALUE;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::collectibles::CollectibleType;
+use crate::systems::inventory::Inventory;
+use crate::systems::sacrifice::{ConfirmSacrificeEvent, SacrificeMenu, sacrifice_value};
+use crate::ui::focus::{Activate, Focusable};
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== COMPONENTS =====
+
+/// Root of the sacrifice menu shown at the book
+#[derive(Component)]
+pub struct SacrificeMenuUI;
+
+// ===== SYSTEMS =====
+
+/// Spawns, rebuilds or removes the sacrifice menu to match `SacrificeMenu`
+pub fn sync_sacrifice_menu(
+    mut commands: Commands,
+    menu: Res<SacrificeMenu>,
+    inventory: Res<Inventory>,
+    font_assets: Option<Res<FontAssets>>,
+    existing: Query<Entity, With<SacrificeMenuUI>>,
+) {
+    if !menu.is_changed() && !inventory.is_changed() {
+        return;
+    }
+
+    for entity in &existing {
+        commands.entity(entity).despawn();
+    }
+    if !menu.open {
+        return;
+    }
+    let Some(font_assets) = font_assets else {
+        return;
+    };
+
+    // One row per item type the player carries, in inventory order
+    let mut item_types: Vec<CollectibleType> = Vec::new();
+    for stack in inventory.slots().iter().flatten() {
+        if !item_types.contains(&stack.item_type) {
+            item_types.push(stack.item_type);
+        }
+    }
+
+    let font = font_assets.rajdhani_bold.clone();
+    let value = menu.value();
+    let meets_cost = menu.meets_cost();
+
+    commands
+        .spawn((
+            Name::new("Sacrifice Menu"),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            ZIndex(90),
+            SacrificeMenuUI,
+            PlayingScene,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(640.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Stretch,
+                        padding: UiRect::all(Val::Px(32.0)),
+                        row_gap: Val::Px(12.0),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.10, 0.14, 0.95)),
+                    BorderColor(Color::ELYSIUM_GOLD),
+                    BorderRadius::all(Val::Px(12.0)),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new("BURN ITEMS TO ENTER THE REALM"),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 32.0,
+                            ..default()
+                        },
+                        TextColor(Color::ELYSIUM_GOLD),
+                    ));
+
+                    if item_types.is_empty() {
+                        panel.spawn((
+                            Text::new("You carry nothing the book would accept."),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: 22.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE.with_alpha(0.7)),
+                        ));
+                    }
+
+                    let row_count = item_types.len() as i32;
+                    for (row, item_type) in item_types.into_iter().enumerate() {
+                        spawn_item_row(
+                            panel,
+                            row as i32,
+                            font.clone(),
+                            item_type,
+                            inventory.count(item_type),
+                            menu.selected(item_type),
+                        );
+                    }
+
+                    panel.spawn((
+                        Text::new(format!("Offering: {} / {}", value, SACRIFICE_REQUIRED_VALUE)),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(if meets_cost {
+                            Color::SUCCESS_GREEN
+                        } else {
+                            Color::WARNING_ORANGE
+                        }),
+                    ));
+
+                    panel
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::FlexEnd,
+                            column_gap: Val::Px(12.0),
+                            ..default()
+                        })
+                        .with_children(|buttons| {
+                            buttons
+                                .spawn((
+                                    menu_button(font.clone(), "CANCEL", Color::LIGHT_GLASS),
+                                    Focusable::new(row_count, 0),
+                                ))
+                                .observe(|_: Trigger<Activate>, mut menu: ResMut<SacrificeMenu>| {
+                                    menu.close();
+                                });
+                            let burn_color = if meets_cost {
+                                Color::ELYSIUM_GOLD_DIM
+                            } else {
+                                Color::DARKER_GLASS
+                            };
+                            buttons
+                                .spawn((
+                                    menu_button(font.clone(), "BURN", burn_color),
+                                    Focusable::new(row_count, 1),
+                                ))
+                                .observe(
+                                    |_: Trigger<Activate>,
+                                     mut confirm_events: EventWriter<ConfirmSacrificeEvent>| {
+                                        confirm_events.write(ConfirmSacrificeEvent);
+                                    },
+                                );
+                        });
+                });
+        });
+}
+
+fn spawn_item_row(
+    panel: &mut ChildSpawnerCommands,
+    row_index: i32,
+    font: Handle<Font>,
+    item_type: CollectibleType,
+    owned: u32,
+    selected: u32,
+) {
+    panel
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(12.0),
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(Color::DARKER_GLASS),
+            BorderRadius::all(Val::Px(8.0)),
+        ))
+        .with_children(|row| {
+            row.spawn((
+                Node {
+                    flex_grow: 1.0,
+                    ..default()
+                },
+                Text::new(format!(
+                    "{} x{}  ({} each)",
+                    item_type.display_name(),
+                    owned,
+                    sacrifice_value(item_type)
+                )),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            row.spawn((
+                menu_button(font.clone(), "-", Color::LIGHT_GLASS),
+                Focusable::new(row_index, 0),
+            ))
+                .observe(
+                    move |_: Trigger<Activate>, mut menu: ResMut<SacrificeMenu>, inventory: Res<Inventory>| {
+                        menu.adjust(item_type, -1, inventory.count(item_type));
+                    },
+                );
+            row.spawn((
+                Text::new(selected.to_string()),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::ELYSIUM_GOLD),
+            ));
+            row.spawn((
+                menu_button(font, "+", Color::LIGHT_GLASS),
+                Focusable::new(row_index, 1),
+            ))
+                .observe(
+                    move |_: Trigger<Activate>, mut menu: ResMut<SacrificeMenu>, inventory: Res<Inventory>| {
+                        menu.adjust(item_type, 1, inventory.count(item_type));
+                    },
+                );
+        });
+}
+
+fn menu_button(font: Handle<Font>, label: &str, background: Color) -> impl Bundle {
+    (
+        Name::new(format!("Sacrifice Button {}", label)),
+        Node {
+            min_width: Val::Px(48.0),
+            padding: UiRect::axes(Val::Px(16.0), Val::Px(6.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        },
+        BackgroundColor(background),
+        BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.6)),
+        BorderRadius::all(Val::Px(8.0)),
+        children![(
+            Text::new(label.to_string()),
+            TextFont {
+                font,
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Pickable::IGNORE,
+        )],
+    )
+}
@@ -0,0 +1,128 @@
+use bevy::prelude::*;
+
+use crate::screens::Screen;
+use crate::systems::save::{ActiveSaveSlot, PendingLoad, SaveGame};
+use crate::ui::focus::{Activate, Focusable};
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== COMPONENTS =====
+
+/// Overlay listing the save slots, opened from the main menu's Load Game
+#[derive(Component)]
+pub struct LoadMenuUI;
+
+// ===== SPAWNING =====
+
+pub fn spawn_load_menu(commands: &mut Commands, font: Handle<Font>, slots: Vec<Option<SaveGame>>) {
+    commands
+        .spawn((
+            Name::new("Load Menu"),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            ZIndex(100),
+            LoadMenuUI,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Stretch,
+                        padding: UiRect::all(Val::Px(32.0)),
+                        row_gap: Val::Px(12.0),
+                        min_width: Val::Px(520.0),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.10, 0.14, 0.95)),
+                    BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.6)),
+                    BorderRadius::all(Val::Px(12.0)),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new("LOAD GAME"),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 40.0,
+                            ..default()
+                        },
+                        TextColor(Color::ELYSIUM_GOLD),
+                        Node {
+                            align_self: AlignSelf::Center,
+                            margin: UiRect::bottom(Val::Px(8.0)),
+                            ..default()
+                        },
+                    ));
+
+                    // Layer 1 keeps the focus off the main menu underneath
+                    let slot_count = slots.len() as i32;
+                    for (slot, save) in slots.into_iter().enumerate() {
+                        let label = match &save {
+                            Some(save) => format!("SLOT {}  -  {}", slot + 1, save.label()),
+                            None => format!("SLOT {}  -  Empty", slot + 1),
+                        };
+                        let mut button = panel.spawn(slot_button(&label, save.is_some(), font.clone()));
+                        if let Some(save) = save {
+                            button.insert(Focusable::new(slot as i32, 0).on_layer(1));
+                            button.observe(
+                                move |_: Trigger<Activate>,
+                                      mut commands: Commands,
+                                      mut next_state: ResMut<NextState<Screen>>| {
+                                    commands.insert_resource(ActiveSaveSlot(slot));
+                                    commands.insert_resource(PendingLoad(save.clone()));
+                                    next_state.set(Screen::PreGameLoading);
+                                },
+                            );
+                        }
+                    }
+
+                    panel
+                        .spawn((
+                            slot_button("BACK", true, font.clone()),
+                            Focusable::new(slot_count, 0).on_layer(1),
+                        ))
+                        .observe(
+                            |_: Trigger<Activate>, mut commands: Commands, menus: Query<Entity, With<LoadMenuUI>>| {
+                                for menu in &menus {
+                                    commands.entity(menu).despawn();
+                                }
+                            },
+                        );
+                });
+        });
+}
+
+fn slot_button(label: &str, enabled: bool, font: Handle<Font>) -> impl Bundle {
+    let (background, text) = if enabled {
+        (Color::ELYSIUM_GOLD_DIM, Color::WHITE)
+    } else {
+        (Color::DARKER_GLASS, Color::WHITE.with_alpha(0.5))
+    };
+    (
+        Node {
+            padding: UiRect::axes(Val::Px(20.0), Val::Px(10.0)),
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        },
+        BackgroundColor(background),
+        BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.6)),
+        BorderRadius::all(Val::Px(8.0)),
+        children![(
+            Text::new(label),
+            TextFont {
+                font,
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(text),
+            Pickable::IGNORE,
+        )],
+    )
+}
@@ -0,0 +1,262 @@
+use bevy::prelude::*;
+
+use crate::assets::FontAssets;
+use crate::constants::items::HEALTH_POTION_HEAL;
+use crate::screens::Screen;
+use crate::screens::fight::FightScene;
+use crate::systems::collectibles::CollectibleType;
+use crate::systems::death::{RunEndedEvent, RunStats};
+use crate::systems::inventory::Inventory;
+use crate::systems::turn_combat::{TurnAction, TurnActionEvent, TurnBattle, TurnPhase};
+use crate::ui::focus::{Activate, Focusable};
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== COMPONENTS =====
+
+/// Root of the action menu, or of the result screen once the battle is over
+#[derive(Component)]
+pub struct TurnCombatUI;
+
+// ===== SYSTEMS =====
+
+/// Rebuilds the combat menu whenever the battle or the potion count changes
+pub fn sync_turn_combat_ui(
+    mut commands: Commands,
+    battle: Option<Res<TurnBattle>>,
+    inventory: Res<Inventory>,
+    font_assets: Option<Res<FontAssets>>,
+    existing: Query<Entity, With<TurnCombatUI>>,
+) {
+    let (Some(battle), Some(font_assets)) = (battle, font_assets) else {
+        return;
+    };
+    if !battle.is_changed() && !inventory.is_changed() {
+        return;
+    }
+
+    for entity in &existing {
+        commands.entity(entity).despawn();
+    }
+
+    let font = font_assets.rajdhani_bold.clone();
+    match battle.phase {
+        TurnPhase::Victory | TurnPhase::Defeat => spawn_result_screen(&mut commands, &battle, font),
+        TurnPhase::PlayerTurn | TurnPhase::BossTurn => {
+            spawn_action_menu(
+                &mut commands,
+                &battle,
+                inventory.count(CollectibleType::HealthPotion),
+                font,
+            );
+        }
+    }
+}
+
+fn spawn_action_menu(commands: &mut Commands, battle: &TurnBattle, potions: u32, font: Handle<Font>) {
+    let player_turn = battle.phase == TurnPhase::PlayerTurn;
+    let header = if player_turn {
+        format!("TURN {} - YOUR MOVE", battle.turn)
+    } else {
+        format!("TURN {} - THE BOSS ACTS", battle.turn)
+    };
+    let skill_label = if battle.skill_cooldown > 0 {
+        format!("SKILL ({})", battle.skill_cooldown)
+    } else {
+        "SKILL".to_string()
+    };
+
+    commands
+        .spawn((
+            Name::new("Turn Combat Menu"),
+            Node {
+                width: Val::Px(833.0),
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(32.0),
+                left: Val::Percent(50.0),
+                margin: UiRect::left(Val::Px(-416.5)),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(20.0)),
+                row_gap: Val::Px(8.0),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.12, 0.14, 0.18, 0.85)),
+            BorderColor(Color::ELYSIUM_GOLD),
+            BorderRadius::all(Val::Px(21.0)),
+            TurnCombatUI,
+            FightScene,
+        ))
+        .with_children(|menu| {
+            menu.spawn(menu_text(header, font.clone(), 26.0, Color::ELYSIUM_GOLD));
+            let intent_color = if battle.enraged {
+                Color::DANGER_RED
+            } else {
+                Color::WARNING_ORANGE
+            };
+            menu.spawn(menu_text(
+                battle.intent.describe().to_uppercase(),
+                font.clone(),
+                20.0,
+                intent_color,
+            ));
+            for line in &battle.log {
+                menu.spawn(menu_text(line.clone(), font.clone(), 18.0, Color::WHITE.with_alpha(0.8)));
+            }
+
+            menu.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(12.0),
+                margin: UiRect::top(Val::Px(8.0)),
+                ..default()
+            })
+            .with_children(|row| {
+                let actions = [
+                    (TurnAction::Attack, "ATTACK".to_string(), true),
+                    (TurnAction::Defend, "DEFEND".to_string(), true),
+                    (
+                        TurnAction::UseItem,
+                        format!("POTION x{} (+{})", potions, HEALTH_POTION_HEAL),
+                        potions > 0,
+                    ),
+                    (TurnAction::Skill, skill_label, battle.skill_cooldown == 0),
+                ];
+                for (column, (action, label, available)) in actions.into_iter().enumerate() {
+                    let enabled = player_turn && available;
+                    row.spawn((
+                        action_button(label, font.clone(), enabled),
+                        Focusable::new(0, column as i32),
+                    ))
+                        .observe(
+                            move |_: Trigger<Activate>,
+                                  mut action_events: EventWriter<TurnActionEvent>| {
+                                if enabled {
+                                    action_events.write(TurnActionEvent(action));
+                                }
+                            },
+                        );
+                }
+            });
+        });
+}
+
+fn spawn_result_screen(commands: &mut Commands, battle: &TurnBattle, font: Handle<Font>) {
+    let victory = battle.phase == TurnPhase::Victory;
+    let (title, color, button, destination) = if victory {
+        ("VICTORY", Color::SUCCESS_GREEN, "RETURN TO THE SURFACE", Screen::GamePlay)
+    } else {
+        ("DEFEATED", Color::DANGER_RED, "RETURN TO MAIN MENU", Screen::MainMenu)
+    };
+
+    commands
+        .spawn((
+            Name::new("Turn Combat Result"),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            ZIndex(90),
+            TurnCombatUI,
+            FightScene,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(40.0)),
+                        row_gap: Val::Px(12.0),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.10, 0.14, 0.95)),
+                    BorderColor(Color::ELYSIUM_GOLD),
+                    BorderRadius::all(Val::Px(12.0)),
+                ))
+                .with_children(|panel| {
+                    panel.spawn(menu_text(title.to_string(), font.clone(), 56.0, color));
+                    panel.spawn(menu_text(
+                        format!(
+                            "{} turns - {} health left",
+                            battle.turn, battle.player.health
+                        ),
+                        font.clone(),
+                        22.0,
+                        Color::WHITE,
+                    ));
+                    // Lets a run be replayed and verified later
+                    panel.spawn(menu_text(
+                        format!("Battle seed {:#x}", battle.seed),
+                        font.clone(),
+                        16.0,
+                        Color::WHITE.with_alpha(0.5),
+                    ));
+                    panel
+                        .spawn((
+                            action_button(button.to_string(), font.clone(), true),
+                            Focusable::new(0, 0).on_layer(1),
+                        ))
+                        .observe(
+                            move |_: Trigger<Activate>,
+                                  stats: Res<RunStats>,
+                                  mut ended_events: EventWriter<RunEndedEvent>,
+                                  mut next_state: ResMut<NextState<Screen>>| {
+                                // A lost battle ends the run like dying on the surface does
+                                if !victory {
+                                    ended_events.write(RunEndedEvent { stats: *stats });
+                                }
+                                next_state.set(destination.clone());
+                            },
+                        );
+                });
+        });
+}
+
+fn menu_text(text: String, font: Handle<Font>, font_size: f32, color: Color) -> impl Bundle {
+    (
+        Text::new(text),
+        TextFont {
+            font,
+            font_size,
+            ..default()
+        },
+        TextColor(color),
+    )
+}
+
+fn action_button(label: String, font: Handle<Font>, enabled: bool) -> impl Bundle {
+    let (background, text_color) = if enabled {
+        (Color::ELYSIUM_GOLD_DIM, Color::WHITE)
+    } else {
+        (Color::DARKER_GLASS, Color::WHITE.with_alpha(0.4))
+    };
+    (
+        Name::new(format!("Combat Button {}", label)),
+        Node {
+            padding: UiRect::axes(Val::Px(20.0), Val::Px(8.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        },
+        BackgroundColor(background),
+        BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.6)),
+        BorderRadius::all(Val::Px(8.0)),
+        children![(
+            Text::new(label),
+            TextFont {
+                font,
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(text_color),
+            Pickable::IGNORE,
+        )],
+    )
+}
@@ -0,0 +1,179 @@
+use bevy::prelude::*;
+
+use crate::assets::FontAssets;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::waves::{SurvivalOutcome, WaveDirector};
+use crate::ui::focus::{Activate, Focusable};
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== COMPONENTS =====
+
+/// Countdown of the survival timer with the current wave
+#[derive(Component)]
+pub struct SurvivalTimerText;
+
+/// Full-screen panel shown when the timer runs out
+#[derive(Component)]
+pub struct SurvivalResultUI;
+
+// ===== SYSTEMS =====
+
+pub fn spawn_survival_hud(mut commands: Commands, font_assets: Res<FontAssets>) {
+    commands.spawn((
+        Name::new("Survival Timer"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(32.0),
+            left: Val::Percent(50.0),
+            width: Val::Px(240.0),
+            margin: UiRect::left(Val::Px(-120.0)),
+            padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
+            justify_content: JustifyContent::Center,
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        },
+        BackgroundColor(Color::DARK_GLASS),
+        BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.6)),
+        BorderRadius::all(Val::Px(12.0)),
+        PlayingScene,
+        children![(
+            Text::new(""),
+            TextFont {
+                font: font_assets.rajdhani_bold.clone(),
+                font_size: 26.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            SurvivalTimerText,
+        )],
+    ));
+}
+
+pub fn sync_survival_hud(
+    director: Res<WaveDirector>,
+    mut text_query: Query<(&mut Text, &mut TextColor), With<SurvivalTimerText>>,
+) {
+    let remaining = director.remaining().ceil() as u32;
+    let label = format!(
+        "WAVE {}  {:02}:{:02}",
+        director.wave_number(),
+        remaining / 60,
+        remaining % 60
+    );
+    // The last half minute is shown in orange
+    let color = if remaining <= 30 {
+        Color::WARNING_ORANGE
+    } else {
+        Color::WHITE
+    };
+
+    for (mut text, mut text_color) in &mut text_query {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+        text_color.set_if_neq(TextColor(color));
+    }
+}
+
+/// Shows the result panel once the player survives; dying is handled by `ui::death`
+pub fn show_survival_result(
+    mut commands: Commands,
+    director: Res<WaveDirector>,
+    font_assets: Option<Res<FontAssets>>,
+    mut shown: Local<Option<SurvivalOutcome>>,
+) {
+    if director.outcome == *shown {
+        return;
+    }
+    *shown = director.outcome;
+    let (Some(SurvivalOutcome::Survived), Some(font_assets)) = (director.outcome, font_assets) else {
+        return;
+    };
+
+    let font = font_assets.rajdhani_bold.clone();
+
+    commands
+        .spawn((
+            Name::new("Survival Result"),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            ZIndex(90),
+            SurvivalResultUI,
+            PlayingScene,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(40.0)),
+                        row_gap: Val::Px(12.0),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.10, 0.14, 0.95)),
+                    BorderColor(Color::ELYSIUM_GOLD),
+                    BorderRadius::all(Val::Px(12.0)),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new("YOU SURVIVED"),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 56.0,
+                            ..default()
+                        },
+                        TextColor(Color::SUCCESS_GREEN),
+                    ));
+                    panel.spawn((
+                        Text::new(format!("The horde retreats into the dark - wave {}", director.wave_number())),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                    panel
+                        .spawn((
+                            Node {
+                                padding: UiRect::axes(Val::Px(20.0), Val::Px(8.0)),
+                                margin: UiRect::top(Val::Px(12.0)),
+                                border: UiRect::all(Val::Px(1.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::ELYSIUM_GOLD_DIM),
+                            BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.6)),
+                            BorderRadius::all(Val::Px(8.0)),
+                            Focusable::new(0, 0),
+                            children![(
+                                Text::new("CONTINUE"),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 22.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                                Pickable::IGNORE,
+                            )],
+                        ))
+                        .observe(
+                            |_: Trigger<Activate>,
+                             mut commands: Commands,
+                             result: Query<Entity, With<SurvivalResultUI>>| {
+                                for entity in &result {
+                                    commands.entity(entity).despawn();
+                                }
+                            },
+                        );
+                });
+        });
+}
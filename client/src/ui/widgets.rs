@@ -1,3 +1,4 @@
+use crate::ui::focus::Focusable;
 use crate::ui::styles::ElysiumDescentColorPalette;
 use bevy::ecs::relationship::{RelatedSpawnerCommands, Relationship};
 use bevy::ecs::system::IntoObserverSystem;
@@ -30,14 +31,15 @@ pub fn label_widget(
     )
 }
 
-fn volume_display_widget(
+fn value_display_widget(
     window_height: f32,
+    width: f32,
     font: Handle<Font>,
     text: impl Into<String> + Clone,
 ) -> impl Bundle {
     (
         Node {
-            width: Val::Percent(8.0),
+            width: Val::Percent(width),
             height: Val::Percent(20.0),
             justify_content: JustifyContent::Center,
             align_items: AlignItems::Center,
@@ -107,6 +109,69 @@ where
     R: Relationship,
     IL: IntoObserverSystem<E, B, M>,
     IR: IntoObserverSystem<E, B, M>,
+{
+    settings_row_widget(
+        parent,
+        window_height,
+        font,
+        text,
+        volume_value,
+        top,
+        8.0,
+        ("-", "+"),
+        lower_volume_system,
+        raise_volume_system,
+    );
+}
+
+/// A settings row that steps through a list of named choices with `<` and `>`
+pub(crate) fn choice_widget<R, E, B, M, IL, IR>(
+    parent: &mut RelatedSpawnerCommands<'_, R>,
+    window_height: f32,
+    font: Handle<Font>,
+    text: impl Into<String> + Clone,
+    choice: impl Into<String> + Clone,
+    top: f32,
+    previous_system: IL,
+    next_system: IR,
+) where
+    E: Event,
+    B: Bundle,
+    R: Relationship,
+    IL: IntoObserverSystem<E, B, M>,
+    IR: IntoObserverSystem<E, B, M>,
+{
+    settings_row_widget(
+        parent,
+        window_height,
+        font,
+        text,
+        choice,
+        top,
+        18.0,
+        ("<", ">"),
+        previous_system,
+        next_system,
+    );
+}
+
+fn settings_row_widget<R, E, B, M, IL, IR>(
+    parent: &mut RelatedSpawnerCommands<'_, R>,
+    window_height: f32,
+    font: Handle<Font>,
+    text: impl Into<String> + Clone,
+    value: impl Into<String> + Clone,
+    top: f32,
+    display_width: f32,
+    (lower_label, raise_label): (&str, &str),
+    lower_system: IL,
+    raise_system: IR,
+) where
+    E: Event,
+    B: Bundle,
+    R: Relationship,
+    IL: IntoObserverSystem<E, B, M>,
+    IR: IntoObserverSystem<E, B, M>,
 {
     parent
         .spawn((
@@ -117,25 +182,30 @@ where
                 top: Val::Percent(top),
                 ..default()
             },
-            Name::new("Sound settings row"),
+            Name::new("Settings row"),
             Pickable::IGNORE,
         ))
         .with_children(|content| {
             content.spawn(label_widget(window_height * 0.04, font.clone(), text));
 
+            // Rows are focused in the order of their offset from the top
             content
-                .spawn(button_widget(window_height, font.clone(), "-"))
-                .observe(lower_volume_system);
+                .spawn((
+                    button_widget(window_height, font.clone(), lower_label),
+                    Focusable::new(top as i32, 0),
+                ))
+                .observe(lower_system);
 
             content.spawn((Node {
                 margin: UiRect::all(Val::Percent(0.5)),
                 ..default()
             },));
 
-            content.spawn((volume_display_widget(
+            content.spawn((value_display_widget(
                 window_height,
+                display_width,
                 font.clone(),
-                volume_value,
+                value,
             ),));
 
             content.spawn((Node {
@@ -144,16 +214,209 @@ where
             },));
 
             content
-                .spawn(button_widget(window_height, font.clone(), "+"))
-                .observe(raise_volume_system);
+                .spawn((
+                    button_widget(window_height, font.clone(), raise_label),
+                    Focusable::new(top as i32, 1),
+                ))
+                .observe(raise_system);
         });
 }
 
+/// Column titles above the Controls tab's binding rows
+pub(crate) fn binding_header_widget<R: Relationship>(
+    parent: &mut RelatedSpawnerCommands<'_, R>,
+    window_height: f32,
+    font: Handle<Font>,
+    titles: [&str; 4],
+    top: f32,
+) {
+    parent
+        .spawn(binding_row_node(top, "Binding header"))
+        .with_children(|row| {
+            for (column, title) in titles.into_iter().enumerate() {
+                row.spawn((
+                    binding_cell_node(column),
+                    Pickable::IGNORE,
+                    children![(
+                        Text::new(title),
+                        TextFont {
+                            font_size: window_height * 0.022,
+                            font: font.clone(),
+                            ..default()
+                        },
+                        TextColor(Color::ELYSIUM_DESCENT_YELLOW),
+                    )],
+                ));
+            }
+        });
+}
+
+/// A Controls tab row: the action's name, then one clickable cell per bound input.
+/// `on_click` builds the observer for the cell in the given input column.
+pub(crate) fn binding_row_widget<R, E, B, M, F, I>(
+    parent: &mut RelatedSpawnerCommands<'_, R>,
+    window_height: f32,
+    font: Handle<Font>,
+    text: impl Into<String> + Clone,
+    inputs: [String; 3],
+    top: f32,
+    conflicted: bool,
+    on_click: F,
+) where
+    E: Event,
+    B: Bundle,
+    R: Relationship,
+    F: Fn(usize) -> I,
+    I: IntoObserverSystem<E, B, M>,
+{
+    let text_font = TextFont {
+        font_size: window_height * 0.022,
+        font,
+        ..default()
+    };
+    parent
+        .spawn(binding_row_node(top, "Binding row"))
+        .with_children(|row| {
+            row.spawn((
+                binding_cell_node(0),
+                Pickable::IGNORE,
+                children![(
+                    Text::new(text.into()),
+                    text_font.clone(),
+                    // Actions sharing an input with another action stand out until fixed
+                    TextColor(if conflicted {
+                        Color::ELYSIUM_DESCENT_RED
+                    } else {
+                        Color::WHITE
+                    }),
+                )],
+            ));
+
+            for (column, input) in inputs.into_iter().enumerate() {
+                row.spawn((
+                    binding_cell_node(column + 1),
+                    Button,
+                    Focusable::new(top as i32, column as i32),
+                    BorderColor(Color::ELYSIUM_DESCENT_BLUE),
+                    BorderRadius::MAX,
+                    children![(
+                        Text::new(input),
+                        text_font.clone(),
+                        TextColor::WHITE,
+                        Pickable::IGNORE,
+                    )],
+                ))
+                .observe(on_click(column));
+            }
+        });
+}
+
+fn binding_row_node(top: f32, name: &'static str) -> impl Bundle {
+    (
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(5.0),
+            top: Val::Percent(top),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Percent(1.0),
+            ..default()
+        },
+        Name::new(name),
+        Pickable::IGNORE,
+    )
+}
+
+/// The first column holds the action names, the others the bound inputs
+fn binding_cell_node(column: usize) -> Node {
+    Node {
+        width: Val::Percent(if column == 0 { 24.0 } else { 18.0 }),
+        height: Val::Percent(90.0),
+        justify_content: if column == 0 {
+            JustifyContent::FlexStart
+        } else {
+            JustifyContent::Center
+        },
+        align_items: AlignItems::Center,
+        border: UiRect::all(Val::Px(if column == 0 { 0.0 } else { 2.0 })),
+        ..default()
+    }
+}
+
+/// A single wide button centred in its own row
+pub(crate) fn text_button_widget<R, E, B, M, I>(
+    parent: &mut RelatedSpawnerCommands<'_, R>,
+    window_height: f32,
+    font: Handle<Font>,
+    text: impl Into<String> + Clone,
+    top: f32,
+    system: I,
+) where
+    E: Event,
+    B: Bundle,
+    R: Relationship,
+    I: IntoObserverSystem<E, B, M>,
+{
+    parent
+        .spawn(binding_row_node(top, "Button row"))
+        .with_children(|row| {
+            row.spawn((
+                Node {
+                    width: Val::Percent(24.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(3.0)),
+                    ..default()
+                },
+                Button,
+                Focusable::new(top as i32, 0),
+                Name::new(text.clone().into()),
+                BackgroundColor(Color::ELYSIUM_DESCENT_RED),
+                BorderColor(Color::BLACK),
+                BorderRadius::MAX,
+                children![(
+                    Text::new(text.into()),
+                    TextFont {
+                        font_size: window_height * 0.025,
+                        font,
+                        ..default()
+                    },
+                    TextColor(Color::BLACK),
+                    Pickable::IGNORE,
+                )],
+            ))
+            .observe(system);
+        });
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum HudPosition {
     Left,
     Right,
 }
 
+/// Marks the "current/max" health text of the HUD on the given side
+#[derive(Component)]
+pub struct HudHealthText(pub HudPosition);
+
+/// Marks the inner health bar fill of the HUD on the given side
+#[derive(Component)]
+pub struct HudHealthFill(pub HudPosition);
+
+/// Marks the level badge text of the HUD on the given side
+#[derive(Component)]
+pub struct HudLevelText(pub HudPosition);
+
+/// Marks the "current/needed" experience text of the HUD on the given side
+#[derive(Component)]
+pub struct HudXpText(pub HudPosition);
+
+/// Marks the inner experience bar fill of the HUD on the given side
+#[derive(Component)]
+pub struct HudXpFill(pub HudPosition);
+
 pub fn player_hud_widget(
     avatar: Handle<Image>,
     name: &str,
@@ -242,6 +505,7 @@ pub fn player_hud_widget(
                                 ..default()
                             },
                             TextColor(Color::srgb(0.1, 0.1, 0.1)),
+                            HudLevelText(position),
                         )]
                     )
                 ]
@@ -309,6 +573,7 @@ pub fn player_hud_widget(
                                             ..default()
                                         },
                                         TextColor::WHITE,
+                                        HudHealthText(position),
                                     )
                                 ]
                             ),
@@ -333,6 +598,7 @@ pub fn player_hud_widget(
                                         },
                                         BackgroundColor(Color::HEALTH_GREEN),
                                         BorderRadius::all(Val::Px(9.0)),
+                                        HudHealthFill(position),
                                     )
                                 ]
                             )
@@ -376,6 +642,7 @@ pub fn player_hud_widget(
                                             ..default()
                                         },
                                         TextColor::WHITE,
+                                        HudXpText(position),
                                     )
                                 ]
                             ),
@@ -400,6 +667,7 @@ pub fn player_hud_widget(
                                         },
                                         BackgroundColor(Color::XP_PURPLE),
                                         BorderRadius::all(Val::Px(9.0)),
+                                        HudXpFill(position),
                                     )
                                 ]
                             )
@@ -410,3 +678,94 @@ pub fn player_hud_widget(
         ],
     )
 }
+
+/// Marks the inner health bar fill of the boss bar
+#[derive(Component)]
+pub struct BossBarFill;
+
+/// Marks the current phase label of the boss bar
+#[derive(Component)]
+pub struct BossPhaseText;
+
+/// Width of the boss bar's health fill at full health
+pub const BOSS_BAR_WIDTH: f32 = 517.0;
+
+/// Health bar of a boss across the top of the screen, styled like the player HUD
+pub fn boss_bar_widget(title: &str, phase: &str, font: Handle<Font>) -> impl Bundle {
+    (
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(110.0),
+            left: Val::Percent(50.0),
+            width: Val::Px(560.0),
+            margin: UiRect::left(Val::Px(-280.0)),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            padding: UiRect::axes(Val::Px(20.0), Val::Px(12.0)),
+            border: UiRect::all(Val::Px(3.0)),
+            ..default()
+        },
+        BackgroundColor(Color::DARK_GLASS),
+        BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.6)),
+        BorderRadius::all(Val::Px(24.0)),
+        Name::new("Boss Bar"),
+        Pickable::IGNORE,
+        children![
+            // Title Row
+            (
+                Node {
+                    width: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    margin: UiRect::bottom(Val::Px(6.0)),
+                    ..default()
+                },
+                children![
+                    (
+                        Text::new(title),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(Color::ELYSIUM_GOLD),
+                    ),
+                    (
+                        Text::new(phase),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 16.5,
+                            ..default()
+                        },
+                        TextColor(Color::DANGER_RED),
+                        BossPhaseText,
+                    )
+                ]
+            ),
+            // Health Bar
+            (
+                Node {
+                    width: Val::Px(520.0),
+                    height: Val::Px(21.0),
+                    border: UiRect::all(Val::Px(1.5)),
+                    ..default()
+                },
+                BackgroundColor(Color::DARKER_GLASS),
+                BorderColor(Color::ELYSIUM_DESCENT_RED_DIM.with_alpha(0.6)),
+                BorderRadius::all(Val::Px(10.5)),
+                children![(
+                    Node {
+                        width: Val::Px(BOSS_BAR_WIDTH),
+                        height: Val::Px(18.0),
+                        margin: UiRect::all(Val::Px(1.5)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::DANGER_RED),
+                    BorderRadius::all(Val::Px(9.0)),
+                    BossBarFill,
+                )]
+            )
+        ],
+    )
+}
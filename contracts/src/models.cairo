@@ -56,6 +56,9 @@ pub struct GameCounter {
 // Constants for special identifiers
 pub const GAME_COUNTER_ID: u32 = 999999999;
 
+// Health a potion restores, the same as the client's HEALTH_POTION_HEAL
+pub const HEALTH_POTION_HEAL: u32 = 35;
+
 // Inventory models
 #[derive(Serde, Copy, Drop, Introspect, PartialEq)]
 pub enum ItemType {
@@ -1,6 +1,6 @@
 use elysium_descent::models::{
-    GAME_COUNTER_ID, Game, GameCounter, GameStatus, ItemType, LevelItems, PlayerInventory,
-    PlayerStats, WorldItem,
+    GAME_COUNTER_ID, Game, GameCounter, GameStatus, HEALTH_POTION_HEAL, ItemType, LevelItems,
+    PlayerInventory, PlayerStats, WorldItem,
 };
 use starknet::{ContractAddress, get_block_timestamp};
 
@@ -9,12 +9,14 @@ use starknet::{ContractAddress, get_block_timestamp};
 pub trait IActions<T> {
     fn create_game(ref self: T) -> u32;
     fn start_level(ref self: T, game_id: u32, level: u32);
-    fn pickup_item(ref self: T) -> bool;
+    fn pickup_item(ref self: T, game_id: u32, item_type: ItemType) -> bool;
+    fn use_item(ref self: T, item_type: ItemType);
+    fn sacrifice_items(ref self: T, items: Array<(ItemType, u32)>);
+    fn end_game(ref self: T, game_id: u32, score: u32);
     fn get_player_stats(self: @T, player: ContractAddress) -> PlayerStats;
     fn get_player_inventory(self: @T, player: ContractAddress) -> PlayerInventory;
     fn get_level_items(self: @T, game_id: u32, level: u32) -> LevelItems;
 }
-//fn _pickup_item(ref self: T, game_id: u32, item_id: u32) -> bool;
 
 // dojo decorator
 #[dojo::contract]
@@ -24,8 +26,8 @@ pub mod actions {
     use dojo::model::ModelStorage;
     use starknet::{ContractAddress, get_caller_address};
     use super::{
-        GAME_COUNTER_ID, Game, GameCounter, GameStatus, IActions, ItemType, LevelItems,
-        PlayerInventory, PlayerStats, WorldItem, get_block_timestamp,
+        GAME_COUNTER_ID, Game, GameCounter, GameStatus, HEALTH_POTION_HEAL, IActions, ItemType,
+        LevelItems, PlayerInventory, PlayerStats, WorldItem, get_block_timestamp,
     };
 
     #[derive(Copy, Drop, Serde)]
@@ -53,11 +55,36 @@ pub mod actions {
         #[key]
         pub player: ContractAddress,
         pub game_id: u32,
-        pub item_id: u32,
         pub item_type: ItemType,
         pub level: u32,
     }
 
+    #[derive(Copy, Drop, Serde)]
+    #[dojo::event]
+    pub struct ItemUsed {
+        #[key]
+        pub player: ContractAddress,
+        pub item_type: ItemType,
+    }
+
+    #[derive(Copy, Drop, Serde)]
+    #[dojo::event]
+    pub struct ItemsSacrificed {
+        #[key]
+        pub player: ContractAddress,
+        pub items_burned: u32,
+    }
+
+    #[derive(Copy, Drop, Serde)]
+    #[dojo::event]
+    pub struct GameEnded {
+        #[key]
+        pub player: ContractAddress,
+        pub game_id: u32,
+        pub score: u32,
+        pub ended_at: u64,
+    }
+
     #[abi(embed_v0)]
     impl ActionsImpl of IActions<ContractState> {
         fn create_game(ref self: ContractState) -> u32 {
@@ -222,72 +249,130 @@ pub mod actions {
             world.emit_event(@LevelStarted { player, game_id, level, items_spawned: total_items });
         }
 
-        fn pickup_item(ref self: ContractState) -> bool {
+        fn pickup_item(ref self: ContractState, game_id: u32, item_type: ItemType) -> bool {
             let mut world = self.world_default();
             let player = get_caller_address();
 
             // Verify game exists and player owns it
-            //let game: Game = world.read_model(game_id);
-            //assert(game.player == player, 'Not your game');
-            //assert(game.status == GameStatus::InProgress, 'Game not in progress');
-
-            //// Get the item
-            //let mut world_item: WorldItem = world.read_model((game_id, item_id));
-            //assert(!world_item.is_collected, 'Item already collected');
-            //assert(world_item.level == game.current_level, 'Item not in current level');
-
-            //// Mark item as collected
-            //world_item.is_collected = true;
-            //world.write_model(@world_item);
-
-            //// Update player inventory
-            //let mut inventory: PlayerInventory = world.read_model(player);
-            //match world_item.item_type {
-            //    ItemType::HealthPotion => { inventory.health_potions += 1; },
-            //    ItemType::SurvivalKit => { inventory.survival_kits += 1; },
-            //    ItemType::Book => { inventory.books += 1; },
-            //};
-            //world.write_model(@inventory);
-
-            //// Update level items collected count
-            //let mut level_items: LevelItems = world.read_model((game_id, world_item.level));
-            //match world_item.item_type {
-            //    ItemType::HealthPotion => { level_items.collected_health_potions += 1; },
-            //    ItemType::SurvivalKit => { level_items.collected_survival_kits += 1; },
-            //    ItemType::Book => { level_items.collected_books += 1; },
-            //};
-            //world.write_model(@level_items);
-
-            //// Update player stats
-            //let mut player_stats: PlayerStats = world.read_model(player);
-            //player_stats.items_collected += 1;
-            //player_stats.experience += 10; // Give experience for collecting items
-
-            //// Simple leveling: every 100 exp = level up
-            //let new_level = (player_stats.experience / 100) + 1;
-            //if new_level > player_stats.level {
-            //    player_stats.level = new_level;
-            //    player_stats.max_health += 10; // Increase max health on level up
-            //    player_stats.health = player_stats.max_health; // Full heal on level up
-            //}
-
-            //world.write_model(@player_stats);
-
-            //// Emit pickup event
-            //world
-            //    .emit_event(
-            //        @ItemPickedUp {
-            //            player,
-            //            game_id,
-            //            item_id,
-            //            item_type: world_item.item_type,
-            //            level: world_item.level,
-            //        },
-            //    );
+            let game: Game = world.read_model(game_id);
+            assert(game.player == player, 'Not your game');
+            assert(game.status == GameStatus::InProgress, 'Game not in progress');
+
+            // Items are spawned and collected in the client, which mirrors each pickup here
+            let mut inventory: PlayerInventory = world.read_model(player);
+            match item_type {
+                ItemType::HealthPotion => { inventory.health_potions += 1; },
+                ItemType::SurvivalKit => { inventory.survival_kits += 1; },
+                ItemType::Book => { inventory.books += 1; },
+            };
+            world.write_model(@inventory);
+
+            // Experience is earned in the client; the chain only counts items
+            let mut player_stats: PlayerStats = world.read_model(player);
+            player_stats.items_collected += 1;
+            world.write_model(@player_stats);
+
+            world
+                .emit_event(
+                    @ItemPickedUp { player, game_id, item_type, level: game.current_level },
+                );
 
             true
         }
 
+        fn use_item(ref self: ContractState, item_type: ItemType) {
+            let mut world = self.world_default();
+            let player = get_caller_address();
+
+            // Consume one item from the player's inventory
+            let mut inventory: PlayerInventory = world.read_model(player);
+            match item_type {
+                ItemType::HealthPotion => {
+                    assert(inventory.health_potions > 0, 'No health potion left');
+                    inventory.health_potions -= 1;
+                },
+                ItemType::SurvivalKit => {
+                    assert(inventory.survival_kits > 0, 'No survival kit left');
+                    inventory.survival_kits -= 1;
+                },
+                ItemType::Book => { panic_with_felt252('Books cannot be used'); },
+            };
+            world.write_model(@inventory);
+
+            // Potions heal on chain too; the survival kit buff only exists in the client
+            if item_type == ItemType::HealthPotion {
+                let mut player_stats: PlayerStats = world.read_model(player);
+                let healed = player_stats.health + HEALTH_POTION_HEAL;
+                player_stats.health = if healed > player_stats.max_health {
+                    player_stats.max_health
+                } else {
+                    healed
+                };
+                world.write_model(@player_stats);
+            }
+
+            world.emit_event(@ItemUsed { player, item_type });
+        }
+
+        fn sacrifice_items(ref self: ContractState, mut items: Array<(ItemType, u32)>) {
+            let mut world = self.world_default();
+            let player = get_caller_address();
+
+            // Burn every offered stack, failing the whole offering if one is short
+            let mut inventory: PlayerInventory = world.read_model(player);
+            let mut items_burned = 0_u32;
+            loop {
+                match items.pop_front() {
+                    Option::Some(item) => {
+                        let (item_type, count) = item;
+                        match item_type {
+                            ItemType::HealthPotion => {
+                                assert(
+                                    inventory.health_potions >= count, 'Not enough health potions',
+                                );
+                                inventory.health_potions -= count;
+                            },
+                            ItemType::SurvivalKit => {
+                                assert(
+                                    inventory.survival_kits >= count, 'Not enough survival kits',
+                                );
+                                inventory.survival_kits -= count;
+                            },
+                            ItemType::Book => {
+                                assert(inventory.books >= count, 'Not enough books');
+                                inventory.books -= count;
+                            },
+                        };
+                        items_burned += count;
+                    },
+                    Option::None => { break; },
+                };
+            };
+            world.write_model(@inventory);
+
+            world.emit_event(@ItemsSacrificed { player, items_burned });
+        }
+
+        fn end_game(ref self: ContractState, game_id: u32, score: u32) {
+            let mut world = self.world_default();
+            let player = get_caller_address();
+
+            // Verify game exists and player owns it
+            let mut game: Game = world.read_model(game_id);
+            assert(game.player == player, 'Not your game');
+            assert(game.status == GameStatus::InProgress, 'Game not in progress');
+
+            // The run is over, whether the player won or died
+            game.status = GameStatus::Completed;
+            game.score = score;
+            world.write_model(@game);
+
+            world
+                .emit_event(
+                    @GameEnded { player, game_id, score, ended_at: get_block_timestamp() },
+                );
+        }
+
         fn get_player_stats(self: @ContractState, player: ContractAddress) -> PlayerStats {
             let world = self.world_default();
             world.read_model(player)
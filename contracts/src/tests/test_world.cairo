@@ -1 +1,155 @@
+use dojo::model::{ModelStorage, ModelStorageTest};
+use dojo::world::{WorldStorage, WorldStorageTrait};
+use dojo_cairo_test::{
+    ContractDef, ContractDefTrait, NamespaceDef, TestResource, WorldStorageTestTrait,
+    spawn_test_world,
+};
+use elysium_descent::models::{
+    Game, GameStatus, HEALTH_POTION_HEAL, ItemType, PlayerInventory, PlayerStats, m_Game,
+    m_GameCounter, m_LevelItems, m_PlayerInventory, m_PlayerStats, m_WorldItem,
+};
+use elysium_descent::systems::actions::{IActionsDispatcher, IActionsDispatcherTrait, actions};
+use starknet::ContractAddress;
 
+fn namespace_def() -> NamespaceDef {
+    NamespaceDef {
+        namespace: "elysium_001",
+        resources: [
+            TestResource::Model(m_Game::TEST_CLASS_HASH),
+            TestResource::Model(m_GameCounter::TEST_CLASS_HASH),
+            TestResource::Model(m_PlayerStats::TEST_CLASS_HASH),
+            TestResource::Model(m_PlayerInventory::TEST_CLASS_HASH),
+            TestResource::Model(m_LevelItems::TEST_CLASS_HASH),
+            TestResource::Model(m_WorldItem::TEST_CLASS_HASH),
+            TestResource::Event(actions::e_GameCreated::TEST_CLASS_HASH),
+            TestResource::Event(actions::e_LevelStarted::TEST_CLASS_HASH),
+            TestResource::Event(actions::e_ItemPickedUp::TEST_CLASS_HASH),
+            TestResource::Event(actions::e_ItemUsed::TEST_CLASS_HASH),
+            TestResource::Event(actions::e_ItemsSacrificed::TEST_CLASS_HASH),
+            TestResource::Event(actions::e_GameEnded::TEST_CLASS_HASH),
+            TestResource::Contract(actions::TEST_CLASS_HASH),
+        ]
+            .span(),
+    }
+}
+
+fn contract_defs() -> Span<ContractDef> {
+    [
+        ContractDefTrait::new(@"elysium_001", @"actions")
+            .with_writer_of([dojo::utils::bytearray_hash(@"elysium_001")].span())
+    ]
+        .span()
+}
+
+/// Spawns the world with a created game. The player is the test's caller.
+fn setup() -> (WorldStorage, IActionsDispatcher, ContractAddress, u32) {
+    let mut world = spawn_test_world([namespace_def()].span());
+    world.sync_perms_and_inits(contract_defs());
+    let (contract_address, _) = world.dns(@"actions").unwrap();
+    let actions_system = IActionsDispatcher { contract_address };
+    let game_id = actions_system.create_game();
+    (world, actions_system, starknet::contract_address_const::<0x0>(), game_id)
+}
+
+/// Picks up `count` items the way the client mirrors them, so no model is seeded by hand
+fn pick_up(actions_system: IActionsDispatcher, game_id: u32, item_type: ItemType, count: u32) {
+    let mut picked = 0_u32;
+    loop {
+        if picked >= count {
+            break;
+        }
+        actions_system.pickup_item(game_id, item_type);
+        picked += 1;
+    };
+}
+
+#[test]
+fn pickup_item_fills_the_inventory() {
+    let (world, actions_system, player, game_id) = setup();
+
+    pick_up(actions_system, game_id, ItemType::HealthPotion, 2);
+    pick_up(actions_system, game_id, ItemType::Book, 1);
+
+    let inventory: PlayerInventory = world.read_model(player);
+    assert(inventory.health_potions == 2, 'Potions not added');
+    assert(inventory.books == 1, 'Book not added');
+    let stats: PlayerStats = world.read_model(player);
+    assert(stats.items_collected == 3, 'Pickups not counted');
+}
+
+#[test]
+fn picked_up_potions_can_be_used() {
+    let (world, actions_system, player, game_id) = setup();
+
+    pick_up(actions_system, game_id, ItemType::HealthPotion, 2);
+    actions_system.use_item(ItemType::HealthPotion);
+
+    let inventory: PlayerInventory = world.read_model(player);
+    assert(inventory.health_potions == 1, 'Potion not consumed');
+    let stats: PlayerStats = world.read_model(player);
+    assert(stats.health == stats.max_health, 'Healed past max health');
+}
+
+#[test]
+fn use_item_heals_a_wounded_player() {
+    let (mut world, actions_system, player, game_id) = setup();
+    pick_up(actions_system, game_id, ItemType::HealthPotion, 1);
+    // Damage is only dealt in the client, so the wound is set up directly
+    let mut stats: PlayerStats = world.read_model(player);
+    stats.health = 50;
+    world.write_model_test(@stats);
+
+    actions_system.use_item(ItemType::HealthPotion);
+
+    let stats: PlayerStats = world.read_model(player);
+    assert(stats.health == 50 + HEALTH_POTION_HEAL, 'Potion did not heal');
+}
+
+#[test]
+#[should_panic(expected: ('No survival kit left', 'ENTRYPOINT_FAILED'))]
+fn use_item_without_stock_fails() {
+    let (_, actions_system, _, game_id) = setup();
+    pick_up(actions_system, game_id, ItemType::HealthPotion, 1);
+    actions_system.use_item(ItemType::SurvivalKit);
+}
+
+#[test]
+fn sacrifice_items_burns_every_offered_stack() {
+    let (world, actions_system, player, game_id) = setup();
+    pick_up(actions_system, game_id, ItemType::HealthPotion, 3);
+    pick_up(actions_system, game_id, ItemType::Book, 1);
+
+    actions_system.sacrifice_items(array![(ItemType::HealthPotion, 2), (ItemType::Book, 1)]);
+
+    let inventory: PlayerInventory = world.read_model(player);
+    assert(inventory.health_potions == 1, 'Potions not burned');
+    assert(inventory.books == 0, 'Book not burned');
+}
+
+#[test]
+#[should_panic(expected: ('Not enough books', 'ENTRYPOINT_FAILED'))]
+fn sacrifice_items_fails_when_a_stack_is_short() {
+    let (_, actions_system, _, game_id) = setup();
+    pick_up(actions_system, game_id, ItemType::HealthPotion, 3);
+
+    actions_system.sacrifice_items(array![(ItemType::HealthPotion, 2), (ItemType::Book, 1)]);
+}
+
+#[test]
+fn end_game_completes_the_game_with_its_score() {
+    let (world, actions_system, _, game_id) = setup();
+
+    actions_system.end_game(game_id, 420);
+
+    let game: Game = world.read_model(game_id);
+    assert(game.status == GameStatus::Completed, 'Game not completed');
+    assert(game.score == 420, 'Score not recorded');
+}
+
+#[test]
+#[should_panic(expected: ('Game not in progress', 'ENTRYPOINT_FAILED'))]
+fn end_game_only_ends_a_game_once() {
+    let (_, actions_system, _, game_id) = setup();
+    actions_system.end_game(game_id, 420);
+    actions_system.end_game(game_id, 420);
+}